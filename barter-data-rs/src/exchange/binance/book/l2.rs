@@ -78,6 +78,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -112,12 +113,12 @@ mod tests {
                     expected: BinanceOrderBookL2Snapshot {
                         last_update_id: 1027024,
                         bids: vec![BinanceLevel {
-                            price: 4.0,
-                            amount: 431.0,
+                            price: dec!(4.0),
+                            amount: dec!(431.0),
                         }],
                         asks: vec![BinanceLevel {
-                            price: 4.00000200,
-                            amount: 12.0,
+                            price: dec!(4.00000200),
+                            amount: dec!(12.0),
                         }],
                     },
                 },
@@ -145,12 +146,12 @@ mod tests {
                     expected: BinanceOrderBookL2Snapshot {
                         last_update_id: 1027024,
                         bids: vec![BinanceLevel {
-                            price: 4.0,
-                            amount: 431.0,
+                            price: dec!(4.0),
+                            amount: dec!(431.0),
                         }],
                         asks: vec![BinanceLevel {
-                            price: 4.00000200,
-                            amount: 12.0,
+                            price: dec!(4.00000200),
+                            amount: dec!(12.0),
                         }],
                     },
                 },
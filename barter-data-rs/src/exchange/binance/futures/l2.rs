@@ -231,6 +231,7 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -269,12 +270,12 @@ mod tests {
                     last_update_id: 160,
                     prev_last_update_id: 149,
                     bids: vec![BinanceLevel {
-                        price: 0.0024,
-                        amount: 10.0
+                        price: dec!(0.0024),
+                        amount: dec!(10.0)
                     },],
                     asks: vec![BinanceLevel {
-                        price: 0.0026,
-                        amount: 100.0
+                        price: dec!(0.0026),
+                        amount: dec!(100.0)
                     },]
                 }
             );
@@ -541,25 +542,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: dec!(80.0),
+                                amount: dec!(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: dec!(90.0),
+                                amount: dec!(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: dec!(200.0),
+                                amount: dec!(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: dec!(500.0),
+                                amount: dec!(0.0),
                             },
                         ],
                     },
@@ -0,0 +1,305 @@
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot};
+use crate::{
+    exchange::{Connector, ExchangeId},
+    instrument::{InstrumentClient, InstrumentDiscovery, InstrumentListing, Instruments},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`BinanceSpot`] REST API base Url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#general-api-information>
+pub const HTTP_BASE_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3";
+
+/// [`BinanceFuturesUsd`] REST API base Url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#general-info>
+pub const HTTP_BASE_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1";
+
+/// [`RestRequest`] that lists the exchange information of every symbol of a
+/// [`Binance`](super::Binance) `Server`.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+#[derive(Debug)]
+pub struct BinanceExchangeInfoRequest<Server>(PhantomData<Server>);
+
+impl<Server> Default for BinanceExchangeInfoRequest<Server> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Server> RestRequest for BinanceExchangeInfoRequest<Server>
+where
+    Server: Connector,
+{
+    type Response = Instruments<Server, BinanceExchangeInfo>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/exchangeInfo"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "binance_exchange_info")
+    }
+}
+
+/// [`Binance`](super::Binance) exchange information response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "symbols": [
+///         {
+///             "symbol": "BTCUSDT",
+///             "status": "TRADING",
+///             "baseAsset": "BTC",
+///             "quoteAsset": "USDT",
+///             "filters": [
+///                 {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+///                 {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+///                 {"filterType": "NOTIONAL", "minNotional": "5.00", "maxNotional": "9000000.00"}
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbol>,
+}
+
+/// [`Binance`](super::Binance) exchange information for a single symbol.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbol {
+    pub symbol: String,
+    pub status: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    /// Only present for [`BinanceFuturesUsd`] symbols (eg/ "PERPETUAL", "CURRENT_QUARTER").
+    #[serde(default)]
+    pub contract_type: Option<String>,
+    pub filters: Vec<BinanceFilter>,
+}
+
+/// [`Binance`](super::Binance) symbol trading rule filter.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#filters>
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceFilter {
+    PriceFilter {
+        #[serde(rename = "tickSize")]
+        tick_size: Decimal,
+    },
+    LotSize {
+        #[serde(rename = "stepSize")]
+        step_size: Decimal,
+        #[serde(rename = "minQty")]
+        min_quantity: Decimal,
+        #[serde(rename = "maxQty")]
+        max_quantity: Decimal,
+    },
+    MinNotional {
+        #[serde(rename = "minNotional", alias = "notional")]
+        min_notional: Decimal,
+    },
+    Notional {
+        #[serde(rename = "minNotional")]
+        min_notional: Decimal,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl BinanceSymbol {
+    /// Normalise a [`BinanceSymbol`] into an [`InstrumentSpec`].
+    ///
+    /// Returns `None` for symbols that cannot be subscribed to with the
+    /// [`BinanceMarket`](super::market::BinanceMarket) "{base}{quote}" format (eg/ dated futures).
+    pub fn spec(&self, exchange: ExchangeId) -> Option<InstrumentSpec> {
+        let kind = match self.contract_type.as_deref() {
+            None => InstrumentKind::Spot,
+            Some("PERPETUAL") => InstrumentKind::Perpetual,
+            Some(_) => return None,
+        };
+
+        if self.symbol != format!("{}{}", self.base_asset, self.quote_asset) {
+            return None;
+        }
+
+        let mut spec = InstrumentSpec {
+            market: Market::new(
+                exchange,
+                (self.base_asset.as_str(), self.quote_asset.as_str(), kind),
+            ),
+            tick_size: Decimal::ZERO,
+            lot_size: Decimal::ZERO,
+            min_quantity: Decimal::ZERO,
+            max_quantity: None,
+            min_notional: None,
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: match self.status.as_str() {
+                "TRADING" => InstrumentStatus::Trading,
+                "PRE_TRADING" | "PENDING_TRADING" => InstrumentStatus::PreTrading,
+                "DELIVERED" | "CLOSE" => InstrumentStatus::Delisted,
+                _ => InstrumentStatus::Halted,
+            },
+        };
+
+        for filter in &self.filters {
+            match *filter {
+                BinanceFilter::PriceFilter { tick_size } => spec.tick_size = tick_size,
+                BinanceFilter::LotSize {
+                    step_size,
+                    min_quantity,
+                    max_quantity,
+                } => {
+                    spec.lot_size = step_size;
+                    spec.min_quantity = min_quantity;
+                    spec.max_quantity = Some(max_quantity);
+                }
+                BinanceFilter::MinNotional { min_notional }
+                | BinanceFilter::Notional { min_notional } => {
+                    spec.min_notional = Some(min_notional)
+                }
+                BinanceFilter::Other => {}
+            }
+        }
+
+        Some(spec)
+    }
+}
+
+impl InstrumentListing for BinanceExchangeInfo {
+    fn specs(self, exchange: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self
+            .symbols
+            .iter()
+            .filter_map(|symbol| symbol.spec(exchange))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for BinanceSpot {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_BINANCE_SPOT;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, BinanceExchangeInfoRequest::<Self>::default())
+            .await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for BinanceFuturesUsd {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_BINANCE_FUTURES_USD;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, BinanceExchangeInfoRequest::<Self>::default())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_binance_exchange_info_specs() {
+        let input = r#"
+        {
+            "timezone": "UTC",
+            "symbols": [
+                {
+                    "symbol": "BTCUSDT",
+                    "status": "TRADING",
+                    "baseAsset": "BTC",
+                    "quoteAsset": "USDT",
+                    "contractType": "PERPETUAL",
+                    "filters": [
+                        {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+                        {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001"},
+                        {"filterType": "MARKET_LOT_SIZE", "minQty": "0.001", "maxQty": "120", "stepSize": "0.001"},
+                        {"filterType": "MIN_NOTIONAL", "notional": "100"}
+                    ]
+                },
+                {
+                    "symbol": "BTCUSDT_240329",
+                    "status": "TRADING",
+                    "baseAsset": "BTC",
+                    "quoteAsset": "USDT",
+                    "contractType": "CURRENT_QUARTER",
+                    "filters": []
+                },
+                {
+                    "symbol": "ETHUSDT",
+                    "status": "SETTLING",
+                    "baseAsset": "ETH",
+                    "quoteAsset": "USDT",
+                    "contractType": "PERPETUAL",
+                    "filters": []
+                }
+            ]
+        }
+        "#;
+
+        let actual =
+            serde_json::from_str::<Instruments<BinanceFuturesUsd, BinanceExchangeInfo>>(input)
+                .unwrap()
+                .specs;
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual[0],
+            InstrumentSpec {
+                market: Market::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::Perpetual)
+                ),
+                tick_size: dec!(0.10),
+                lot_size: dec!(0.001),
+                min_quantity: dec!(0.001),
+                max_quantity: Some(dec!(1000)),
+                min_notional: Some(dec!(100)),
+                contract_multiplier: Decimal::ONE,
+                settlement: Settlement::Linear,
+                status: InstrumentStatus::Trading,
+            }
+        );
+        assert_eq!(actual[1].status, InstrumentStatus::Halted);
+    }
+}
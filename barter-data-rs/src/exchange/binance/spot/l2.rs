@@ -228,6 +228,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -257,12 +258,12 @@ mod tests {
                     last_update_id: 22611425151,
                     bids: vec![
                         BinanceLevel {
-                            price: 1209.67000000,
-                            amount: 85.48210000
+                            price: dec!(1209.67000000),
+                            amount: dec!(85.48210000)
                         },
                         BinanceLevel {
-                            price: 1209.66000000,
-                            amount: 20.68790000
+                            price: dec!(1209.66000000),
+                            amount: dec!(20.68790000)
                         },
                     ],
                     asks: vec![]
@@ -532,25 +533,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: dec!(80.0),
+                                amount: dec!(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: dec!(90.0),
+                                amount: dec!(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: dec!(200.0),
+                                amount: dec!(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: dec!(500.0),
+                                amount: dec!(0.0),
                             },
                         ],
                     },
@@ -5,6 +5,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for an [`BitmexTrade`](BitmexTradeInner) real-time trades WebSocket message.
@@ -42,8 +43,8 @@ pub struct BitmexTradeInner {
 
     pub side: Side,
     #[serde(rename = "size")]
-    pub amount: f64,
-    pub price: f64,
+    pub amount: Decimal,
+    pub price: Decimal,
 
     #[serde(rename = "trdMatchID")]
     pub id: String,
@@ -77,6 +78,7 @@ impl From<(ExchangeId, Instrument, BitmexTrade)> for MarketIter<PublicTrade> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -113,8 +115,8 @@ mod tests {
                             + Duration::milliseconds(701),
                         symbol: "XBTUSD".to_string(),
                         side: Side::Sell,
-                        amount: 200.0,
-                        price: 24564.5,
+                        amount: dec!(200.0),
+                        price: dec!(24564.5),
                         id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_string(),
                     }),
                 },
@@ -175,8 +177,8 @@ mod tests {
                                 + Duration::milliseconds(701),
                             symbol: "XBTUSD".to_string(),
                             side: Side::Sell,
-                            amount: 200.0,
-                            price: 24564.5,
+                            amount: dec!(200.0),
+                            price: dec!(24564.5),
                             id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_string(),
                         }],
                     }),
@@ -0,0 +1,357 @@
+use super::{futures::BybitPerpetualsUsd, spot::BybitSpot};
+use crate::{
+    exchange::{Connector, ExchangeId},
+    instrument::{InstrumentClient, InstrumentDiscovery},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`](super::Bybit) market data REST API base Url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+pub const HTTP_BASE_URL_BYBIT: &str = "https://api.bybit.com/v5/market";
+
+/// Maximum number of instruments [`Bybit`](super::Bybit) returns per instruments info page.
+const HTTP_INSTRUMENTS_INFO_PAGE_LIMIT_BYBIT: usize = 1000;
+
+/// [`RestRequest`] that lists one page of [`BybitInstrument`]s for the provided `category`
+/// (eg/ "spot", "linear"), starting from the provided pagination `cursor`.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct BybitInstrumentsRequest {
+    pub category: &'static str,
+    pub limit: usize,
+    pub cursor: String,
+}
+
+impl BybitInstrumentsRequest {
+    /// Construct a [`BybitInstrumentsRequest`] for the page of the provided `category` that
+    /// starts at the provided pagination `cursor`.
+    pub fn new(category: &'static str, cursor: String) -> Self {
+        Self {
+            category,
+            limit: HTTP_INSTRUMENTS_INFO_PAGE_LIMIT_BYBIT,
+            cursor,
+        }
+    }
+}
+
+impl RestRequest for BybitInstrumentsRequest {
+    type Response = BybitInstrumentsResponse;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/instruments-info"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "bybit_instruments_info")
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+/// [`Bybit`](super::Bybit) instruments info response.
+///
+/// A non zero `retCode` communicates that the request failed.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrumentsResponse {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: BybitInstrumentsResult,
+}
+
+/// Page of [`BybitInstrument`]s. An empty `next_page_cursor` communicates the last page.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrumentsResult {
+    #[serde(default)]
+    pub list: Vec<BybitInstrument>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+/// [`Bybit`](super::Bybit) instrument.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "contractType": "LinearPerpetual",
+///     "status": "Trading",
+///     "baseCoin": "BTC",
+///     "quoteCoin": "USDT",
+///     "priceFilter": {"minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10"},
+///     "lotSizeFilter": {"maxOrderQty": "100.000", "minOrderQty": "0.001", "qtyStep": "0.001", "minNotionalValue": "5"}
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrument {
+    pub symbol: String,
+    /// Not present for spot instruments.
+    #[serde(default)]
+    pub contract_type: Option<String>,
+    pub status: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub price_filter: BybitPriceFilter,
+    pub lot_size_filter: BybitLotSizeFilter,
+}
+
+/// [`BybitInstrument`] price trading rules.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPriceFilter {
+    pub tick_size: Decimal,
+}
+
+/// [`BybitInstrument`] quantity trading rules. Spot instruments communicate the quantity step as
+/// `basePrecision`, and the minimum notional as `minOrderAmt`.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitLotSizeFilter {
+    #[serde(alias = "basePrecision")]
+    pub qty_step: Decimal,
+    pub min_order_qty: Decimal,
+    pub max_order_qty: Decimal,
+    #[serde(default, alias = "minOrderAmt")]
+    pub min_notional_value: Option<Decimal>,
+}
+
+impl BybitInstrument {
+    /// Normalise a [`BybitInstrument`] into an [`InstrumentSpec`].
+    ///
+    /// Returns `None` for instruments that cannot be subscribed to with the
+    /// [`BybitMarket`](super::market::BybitMarket) "{base}{quote}" format (eg/ dated futures).
+    pub fn spec(&self, exchange: ExchangeId) -> Option<InstrumentSpec> {
+        let kind = match self.contract_type.as_deref() {
+            None => InstrumentKind::Spot,
+            Some("LinearPerpetual") => InstrumentKind::Perpetual,
+            Some(_) => return None,
+        };
+
+        if self.symbol != format!("{}{}", self.base_coin, self.quote_coin) {
+            return None;
+        }
+
+        Some(InstrumentSpec {
+            market: Market::new(
+                exchange,
+                (self.base_coin.as_str(), self.quote_coin.as_str(), kind),
+            ),
+            tick_size: self.price_filter.tick_size,
+            lot_size: self.lot_size_filter.qty_step,
+            min_quantity: self.lot_size_filter.min_order_qty,
+            max_quantity: Some(self.lot_size_filter.max_order_qty),
+            min_notional: self.lot_size_filter.min_notional_value,
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: match self.status.as_str() {
+                "Trading" => InstrumentStatus::Trading,
+                "PreLaunch" => InstrumentStatus::PreTrading,
+                "Delivering" | "Closed" => InstrumentStatus::Delisted,
+                _ => InstrumentStatus::Halted,
+            },
+        })
+    }
+}
+
+/// Fetch every page of [`BybitInstrument`]s for the provided `category` (eg/ "spot", "linear"),
+/// and insert their normalised [`InstrumentSpec`]s into the [`InstrumentRegistry`].
+///
+/// Pages are executed directly rather than via [`InstrumentRegistry::fetch`] since the next
+/// pagination cursor is only available on the raw [`BybitInstrumentsResponse`].
+async fn fetch_bybit_instruments_into(
+    client: &InstrumentClient<'_>,
+    registry: &mut InstrumentRegistry,
+    exchange: ExchangeId,
+    category: &'static str,
+) -> Result<usize, SocketError> {
+    let len_before = registry.len();
+    let mut cursor = String::new();
+
+    loop {
+        let response = client
+            .execute(BybitInstrumentsRequest::new(category, cursor.clone()))
+            .await?;
+
+        if response.ret_code != 0 {
+            return Err(SocketError::Exchange(format!(
+                "Bybit retCode: {}, retMsg: {}",
+                response.ret_code, response.ret_msg
+            )));
+        }
+
+        registry.extend(
+            response
+                .result
+                .list
+                .iter()
+                .filter_map(|instrument| instrument.spec(exchange)),
+        );
+
+        match response.result.next_page_cursor {
+            next_cursor if next_cursor.is_empty() || next_cursor == cursor => {
+                break Ok(registry.len() - len_before)
+            }
+            next_cursor => cursor = next_cursor,
+        }
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for BybitSpot {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_BYBIT;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        fetch_bybit_instruments_into(client, registry, Self::ID, "spot").await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for BybitPerpetualsUsd {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_BYBIT;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        fetch_bybit_instruments_into(client, registry, Self::ID, "linear").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_bybit_instruments_specs() {
+        let input = r#"
+        {
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "spot",
+                "list": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "baseCoin": "BTC",
+                        "quoteCoin": "USDT",
+                        "innovation": "0",
+                        "status": "Trading",
+                        "marginTrading": "both",
+                        "lotSizeFilter": {
+                            "basePrecision": "0.000001",
+                            "quotePrecision": "0.00000001",
+                            "minOrderQty": "0.000048",
+                            "maxOrderQty": "71.73956243",
+                            "minOrderAmt": "1",
+                            "maxOrderAmt": "2000000"
+                        },
+                        "priceFilter": {"tickSize": "0.01"}
+                    }
+                ]
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<BybitInstrumentsResponse>(input)
+            .unwrap()
+            .result
+            .list
+            .iter()
+            .filter_map(|instrument| instrument.spec(ExchangeId::BybitSpot))
+            .collect::<Vec<_>>();
+
+        let expected = vec![InstrumentSpec {
+            market: Market::new(ExchangeId::BybitSpot, ("btc", "usdt", InstrumentKind::Spot)),
+            tick_size: dec!(0.01),
+            lot_size: dec!(0.000001),
+            min_quantity: dec!(0.000048),
+            max_quantity: Some(dec!(71.73956243)),
+            min_notional: Some(dec!(1)),
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: InstrumentStatus::Trading,
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_bybit_linear_instruments_skip_unsupported_contracts() {
+        let input = r#"
+        {
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "BTCUSDT", "contractType": "LinearPerpetual", "status": "Trading",
+                        "baseCoin": "BTC", "quoteCoin": "USDT", "priceFilter": {"tickSize": "0.10"},
+                        "lotSizeFilter": {"maxOrderQty": "100.000", "minOrderQty": "0.001", "qtyStep": "0.001", "minNotionalValue": "5"}
+                    },
+                    {
+                        "symbol": "BTCPERP", "contractType": "LinearPerpetual", "status": "Trading",
+                        "baseCoin": "BTC", "quoteCoin": "USDC", "priceFilter": {"tickSize": "0.50"},
+                        "lotSizeFilter": {"maxOrderQty": "100.000", "minOrderQty": "0.001", "qtyStep": "0.001"}
+                    },
+                    {
+                        "symbol": "BTC-29MAR24", "contractType": "LinearFutures", "status": "Trading",
+                        "baseCoin": "BTC", "quoteCoin": "USDC", "priceFilter": {"tickSize": "0.50"},
+                        "lotSizeFilter": {"maxOrderQty": "100.000", "minOrderQty": "0.001", "qtyStep": "0.001"}
+                    }
+                ],
+                "nextPageCursor": ""
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<BybitInstrumentsResponse>(input)
+            .unwrap()
+            .result
+            .list
+            .iter()
+            .filter_map(|instrument| instrument.spec(ExchangeId::BybitPerpetualsUsd))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(
+            actual[0].market,
+            Market::new(
+                ExchangeId::BybitPerpetualsUsd,
+                ("btc", "usdt", InstrumentKind::Perpetual)
+            )
+        );
+        assert_eq!(actual[0].min_notional, Some(dec!(5)));
+    }
+}
@@ -28,6 +28,10 @@ pub mod channel;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod futures;
 
+/// [`InstrumentDiscovery`](crate::instrument::InstrumentDiscovery) implementations for
+/// [`BybitSpot`](spot::BybitSpot) and [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
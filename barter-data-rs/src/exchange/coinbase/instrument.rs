@@ -0,0 +1,164 @@
+use super::Coinbase;
+use crate::{
+    exchange::ExchangeId,
+    instrument::{InstrumentClient, InstrumentDiscovery, InstrumentListing, Instruments},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`] REST API base Url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/rest-requests>
+pub const HTTP_BASE_URL_COINBASE: &str = "https://api.exchange.coinbase.com";
+
+/// [`RestRequest`] that lists every [`Coinbase`] product.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducts>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct CoinbaseProductsRequest;
+
+impl RestRequest for CoinbaseProductsRequest {
+    type Response = Instruments<Coinbase, Vec<CoinbaseProduct>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/products"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "coinbase_products")
+    }
+}
+
+/// [`Coinbase`] product.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducts>
+/// ```json
+/// {
+///     "id": "BTC-USD",
+///     "base_currency": "BTC",
+///     "quote_currency": "USD",
+///     "quote_increment": "0.01",
+///     "base_increment": "0.00000001",
+///     "min_market_funds": "1",
+///     "status": "online",
+///     "trading_disabled": false
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseProduct {
+    pub id: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub quote_increment: Decimal,
+    pub base_increment: Decimal,
+    #[serde(default)]
+    pub min_market_funds: Option<Decimal>,
+    pub status: String,
+    #[serde(default)]
+    pub trading_disabled: bool,
+}
+
+impl CoinbaseProduct {
+    /// Normalise a [`CoinbaseProduct`] into an [`InstrumentSpec`].
+    pub fn spec(&self) -> InstrumentSpec {
+        InstrumentSpec {
+            market: Market::new(
+                ExchangeId::Coinbase,
+                (
+                    self.base_currency.as_str(),
+                    self.quote_currency.as_str(),
+                    InstrumentKind::Spot,
+                ),
+            ),
+            tick_size: self.quote_increment,
+            lot_size: self.base_increment,
+            min_quantity: Decimal::ZERO,
+            max_quantity: None,
+            min_notional: self.min_market_funds,
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: match (self.status.as_str(), self.trading_disabled) {
+                ("delisted", _) => InstrumentStatus::Delisted,
+                ("online", false) => InstrumentStatus::Trading,
+                _ => InstrumentStatus::Halted,
+            },
+        }
+    }
+}
+
+impl InstrumentListing for Vec<CoinbaseProduct> {
+    fn specs(self, _: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self.iter().map(CoinbaseProduct::spec).collect())
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for Coinbase {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_COINBASE;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry.fetch(client, CoinbaseProductsRequest).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_coinbase_product_spec() {
+        let input = r#"
+        {
+            "id": "BTC-USD", "base_currency": "BTC", "quote_currency": "USD",
+            "quote_increment": "0.01", "base_increment": "0.00000001", "display_name": "BTC/USD",
+            "min_market_funds": "1", "margin_enabled": false, "post_only": false,
+            "limit_only": false, "cancel_only": false, "status": "online",
+            "status_message": "", "trading_disabled": false, "fx_stablecoin": false,
+            "max_slippage_percentage": "0.02000000", "auction_mode": false,
+            "high_bid_limit_percentage": ""
+        }
+        "#;
+
+        let actual = serde_json::from_str::<CoinbaseProduct>(input)
+            .unwrap()
+            .spec();
+
+        let expected = InstrumentSpec {
+            market: Market::new(ExchangeId::Coinbase, ("btc", "usd", InstrumentKind::Spot)),
+            tick_size: dec!(0.01),
+            lot_size: dec!(0.00000001),
+            min_quantity: Decimal::ZERO,
+            max_quantity: None,
+            min_notional: Some(dec!(1)),
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: InstrumentStatus::Trading,
+        };
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,643 @@
+use super::{
+    future::{GateioFuturesBtc, GateioFuturesUsd},
+    option::GateioOptions,
+    perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+    spot::GateioSpot,
+};
+use crate::{
+    exchange::{Connector, ExchangeId},
+    instrument::{
+        increment_from_decimal_places, InstrumentClient, InstrumentDiscovery, InstrumentListing,
+        Instruments,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::{FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind},
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`Gateio`](super::Gateio) Http REST API base url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/>
+pub const HTTP_BASE_URL_GATEIO: &str = "https://api.gateio.ws/api/v4";
+
+/// [`GateioSpot`] currency pair.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-currency-pairs-supported>
+/// ```json
+/// {
+///     "id": "ETH_USDT",
+///     "base": "ETH",
+///     "quote": "USDT",
+///     "fee": "0.2",
+///     "min_base_amount": "0.001",
+///     "min_quote_amount": "1.0",
+///     "amount_precision": 3,
+///     "precision": 6,
+///     "trade_status": "tradable"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioCurrencyPair {
+    pub id: String,
+    pub base: String,
+    pub quote: String,
+    #[serde(default)]
+    pub min_base_amount: Option<Decimal>,
+    #[serde(default)]
+    pub max_base_amount: Option<Decimal>,
+    #[serde(default)]
+    pub min_quote_amount: Option<Decimal>,
+    pub amount_precision: u32,
+    pub precision: u32,
+    pub trade_status: String,
+}
+
+impl GateioCurrencyPair {
+    /// Normalise a [`GateioCurrencyPair`] into an [`InstrumentSpec`].
+    pub fn spec(&self) -> InstrumentSpec {
+        InstrumentSpec {
+            market: Market::new(
+                ExchangeId::GateioSpot,
+                (
+                    self.base.as_str(),
+                    self.quote.as_str(),
+                    InstrumentKind::Spot,
+                ),
+            ),
+            tick_size: increment_from_decimal_places(self.precision),
+            lot_size: increment_from_decimal_places(self.amount_precision),
+            min_quantity: self.min_base_amount.unwrap_or_default(),
+            max_quantity: self.max_base_amount,
+            min_notional: self.min_quote_amount,
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: match self.trade_status.as_str() {
+                "tradable" => InstrumentStatus::Trading,
+                _ => InstrumentStatus::Halted,
+            },
+        }
+    }
+}
+
+/// [`Gateio`](super::Gateio) perpetual or delivery futures contract. Delivery contracts contain
+/// an `underlying` & `expire_time`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-futures-contracts>
+/// ```json
+/// {
+///     "name": "BTC_USDT",
+///     "type": "direct",
+///     "quanto_multiplier": "0.0001",
+///     "order_price_round": "0.1",
+///     "order_size_min": 1,
+///     "order_size_max": 1000000,
+///     "in_delisting": false
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesContract {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub underlying: Option<String>,
+    /// Delivery contract expiry in seconds since the epoch.
+    #[serde(default)]
+    pub expire_time: Option<i64>,
+    pub quanto_multiplier: Decimal,
+    pub order_price_round: Decimal,
+    pub order_size_min: Decimal,
+    pub order_size_max: Decimal,
+    #[serde(default)]
+    pub in_delisting: bool,
+}
+
+impl GateioFuturesContract {
+    /// Normalise a [`GateioFuturesContract`] into an [`InstrumentSpec`]. Contracts with an
+    /// `expire_time` are normalised as [`InstrumentKind::Future`], otherwise
+    /// [`InstrumentKind::Perpetual`].
+    pub fn spec(&self, exchange: ExchangeId) -> Option<InstrumentSpec> {
+        let mut symbols = self
+            .underlying
+            .as_deref()
+            .unwrap_or(self.name.as_str())
+            .split('_');
+        let (base, quote) = (symbols.next()?, symbols.next()?);
+
+        let kind = match self.expire_time {
+            None => InstrumentKind::Perpetual,
+            Some(expiry) => InstrumentKind::Future(FutureContract {
+                expiry: Utc.timestamp_opt(expiry, 0).single()?,
+            }),
+        };
+
+        Some(InstrumentSpec {
+            market: Market::new(exchange, (base, quote, kind)),
+            tick_size: self.order_price_round,
+            lot_size: Decimal::ONE,
+            min_quantity: self.order_size_min,
+            max_quantity: Some(self.order_size_max),
+            min_notional: None,
+            contract_multiplier: contract_multiplier(self.quanto_multiplier),
+            settlement: match (self.kind.as_str(), exchange) {
+                ("inverse", _) => Settlement::Inverse,
+                // Direct contracts settled in BTC (eg/ ETH_USD) are quanto
+                ("direct", ExchangeId::GateioPerpetualsBtc | ExchangeId::GateioFuturesBtc) => {
+                    Settlement::Quanto
+                }
+                _ => Settlement::Linear,
+            },
+            status: match self.in_delisting {
+                true => InstrumentStatus::Delisted,
+                false => InstrumentStatus::Trading,
+            },
+        })
+    }
+}
+
+/// [`GateioOptions`] underlying.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioOptionsUnderlying {
+    pub name: String,
+}
+
+/// [`GateioOptions`] contract.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-the-contracts-with-specified-underlying-and-expiration-time>
+/// ```json
+/// {
+///     "name": "BTC_USDT-20211130-65000-C",
+///     "underlying": "BTC_USDT",
+///     "is_call": true,
+///     "strike_price": "65000",
+///     "expiration_time": 1638259200,
+///     "multiplier": "0.0001",
+///     "order_price_round": "0.1",
+///     "order_size_min": 1,
+///     "order_size_max": 100000,
+///     "is_active": true
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioOptionsContract {
+    pub name: String,
+    pub underlying: String,
+    pub is_call: bool,
+    pub strike_price: Decimal,
+    /// Expiry in seconds since the epoch.
+    pub expiration_time: i64,
+    pub multiplier: Decimal,
+    pub order_price_round: Decimal,
+    pub order_size_min: Decimal,
+    pub order_size_max: Decimal,
+    pub is_active: bool,
+}
+
+impl GateioOptionsContract {
+    /// Normalise a [`GateioOptionsContract`] into an [`InstrumentSpec`].
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let (base, quote) = self.underlying.split_once('_')?;
+
+        let kind = InstrumentKind::Option(OptionContract {
+            kind: match self.is_call {
+                true => OptionKind::Call,
+                false => OptionKind::Put,
+            },
+            exercise: OptionExercise::European,
+            expiry: Utc.timestamp_opt(self.expiration_time, 0).single()?,
+            strike: self.strike_price,
+        });
+
+        Some(InstrumentSpec {
+            market: Market::new(ExchangeId::GateioOptions, (base, quote, kind)),
+            tick_size: self.order_price_round,
+            lot_size: Decimal::ONE,
+            min_quantity: self.order_size_min,
+            max_quantity: Some(self.order_size_max),
+            min_notional: None,
+            contract_multiplier: contract_multiplier(self.multiplier),
+            settlement: Settlement::Linear,
+            status: match self.is_active {
+                true => InstrumentStatus::Trading,
+                false => InstrumentStatus::Halted,
+            },
+        })
+    }
+}
+
+/// [`Gateio`](super::Gateio) communicates a multiplier of zero for contracts worth one unit of
+/// the settlement currency (eg/ inverse BTC perpetuals worth 1 USD).
+fn contract_multiplier(multiplier: Decimal) -> Decimal {
+    match multiplier.is_zero() {
+        true => Decimal::ONE,
+        false => multiplier,
+    }
+}
+
+impl InstrumentListing for Vec<GateioCurrencyPair> {
+    fn specs(self, _: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self.iter().map(GateioCurrencyPair::spec).collect())
+    }
+}
+
+impl InstrumentListing for Vec<GateioFuturesContract> {
+    fn specs(self, exchange: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self
+            .iter()
+            .filter_map(|contract| contract.spec(exchange))
+            .collect())
+    }
+}
+
+impl InstrumentListing for Vec<GateioOptionsContract> {
+    fn specs(self, _: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self
+            .iter()
+            .filter_map(GateioOptionsContract::spec)
+            .collect())
+    }
+}
+
+/// [`RestRequest`] that lists every [`GateioSpot`] currency pair.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-currency-pairs-supported>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct GateioCurrencyPairsRequest;
+
+impl RestRequest for GateioCurrencyPairsRequest {
+    type Response = Instruments<GateioSpot, Vec<GateioCurrencyPair>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/spot/currency_pairs"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "gateio_currency_pairs")
+    }
+}
+
+/// [`Gateio`](super::Gateio) perpetual or delivery futures server that lists it's contracts via a
+/// [`GateioFuturesContractsRequest`].
+pub trait GateioFuturesServer
+where
+    Self: Connector,
+{
+    /// Http path of the contracts listing (eg/ "/futures/usdt/contracts").
+    const HTTP_CONTRACTS_PATH: &'static str;
+}
+
+impl GateioFuturesServer for GateioPerpetualsUsd {
+    const HTTP_CONTRACTS_PATH: &'static str = "/futures/usdt/contracts";
+}
+
+impl GateioFuturesServer for GateioPerpetualsBtc {
+    const HTTP_CONTRACTS_PATH: &'static str = "/futures/btc/contracts";
+}
+
+impl GateioFuturesServer for GateioFuturesUsd {
+    const HTTP_CONTRACTS_PATH: &'static str = "/delivery/usdt/contracts";
+}
+
+impl GateioFuturesServer for GateioFuturesBtc {
+    const HTTP_CONTRACTS_PATH: &'static str = "/delivery/btc/contracts";
+}
+
+/// [`RestRequest`] that lists every [`GateioFuturesContract`] of a [`GateioFuturesServer`].
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-futures-contracts>
+#[derive(Debug)]
+pub struct GateioFuturesContractsRequest<Server>(PhantomData<Server>);
+
+impl<Server> Default for GateioFuturesContractsRequest<Server> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Server> RestRequest for GateioFuturesContractsRequest<Server>
+where
+    Server: GateioFuturesServer,
+{
+    type Response = Instruments<Server, Vec<GateioFuturesContract>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        Server::HTTP_CONTRACTS_PATH
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "gateio_futures_contracts")
+    }
+}
+
+/// [`RestRequest`] that lists every [`GateioOptionsUnderlying`].
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-underlyings>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct GateioOptionsUnderlyingsRequest;
+
+impl RestRequest for GateioOptionsUnderlyingsRequest {
+    type Response = Vec<GateioOptionsUnderlying>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/options/underlyings"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "gateio_options_underlyings")
+    }
+}
+
+/// [`RestRequest`] that lists every [`GateioOptionsContract`] of a [`GateioOptionsUnderlying`].
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#list-all-the-contracts-with-specified-underlying-and-expiration-time>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct GateioOptionsContractsRequest {
+    pub underlying: String,
+}
+
+impl RestRequest for GateioOptionsContractsRequest {
+    type Response = Instruments<GateioOptions, Vec<GateioOptionsContract>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/options/contracts"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "gateio_options_contracts")
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioSpot {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry.fetch(client, GateioCurrencyPairsRequest).await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioPerpetualsUsd {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, GateioFuturesContractsRequest::<Self>::default())
+            .await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioPerpetualsBtc {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, GateioFuturesContractsRequest::<Self>::default())
+            .await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioFuturesUsd {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, GateioFuturesContractsRequest::<Self>::default())
+            .await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioFuturesBtc {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry
+            .fetch(client, GateioFuturesContractsRequest::<Self>::default())
+            .await
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for GateioOptions {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_GATEIO;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        // Options contracts must be requested per underlying (eg/ "BTC_USDT")
+        let underlyings = client.execute(GateioOptionsUnderlyingsRequest).await?;
+
+        let mut inserted = 0;
+        for underlying in underlyings {
+            let request = GateioOptionsContractsRequest {
+                underlying: underlying.name,
+            };
+            inserted += registry.fetch(client, request).await?;
+        }
+
+        Ok(inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_gateio_currency_pair_spec() {
+        let input = r#"
+        {
+            "id": "ETH_USDT", "base": "ETH", "quote": "USDT", "fee": "0.2",
+            "min_base_amount": "0.001", "min_quote_amount": "1.0", "amount_precision": 3,
+            "precision": 6, "trade_status": "tradable", "sell_start": 1516378650,
+            "buy_start": 1516378650
+        }
+        "#;
+
+        let actual = serde_json::from_str::<GateioCurrencyPair>(input)
+            .unwrap()
+            .spec();
+
+        assert_eq!(
+            actual,
+            InstrumentSpec {
+                market: Market::new(
+                    ExchangeId::GateioSpot,
+                    ("eth", "usdt", InstrumentKind::Spot)
+                ),
+                tick_size: dec!(0.000001),
+                lot_size: dec!(0.001),
+                min_quantity: dec!(0.001),
+                max_quantity: None,
+                min_notional: Some(dec!(1.0)),
+                contract_multiplier: Decimal::ONE,
+                settlement: Settlement::Linear,
+                status: InstrumentStatus::Trading,
+            }
+        );
+    }
+
+    #[test]
+    fn test_gateio_futures_contract_spec() {
+        let input = r#"
+        [
+            {
+                "name": "BTC_USD", "type": "inverse", "quanto_multiplier": "0",
+                "order_price_round": "0.1", "order_size_min": 1, "order_size_max": 1000000,
+                "in_delisting": false
+            },
+            {
+                "name": "BTC_USDT_20200814", "underlying": "BTC_USDT", "cycle": "WEEKLY",
+                "type": "direct", "quanto_multiplier": "0.0001", "order_price_round": "0.1",
+                "order_size_min": 1, "order_size_max": 1000000, "expire_time": 1597910400,
+                "in_delisting": true
+            },
+            {
+                "name": "ETH_USD", "type": "direct", "quanto_multiplier": "0.000001",
+                "order_price_round": "0.05", "order_size_min": 1, "order_size_max": 1000000,
+                "in_delisting": false
+            }
+        ]
+        "#;
+
+        let actual = serde_json::from_str::<Vec<GateioFuturesContract>>(input)
+            .unwrap()
+            .iter()
+            .filter_map(|contract| contract.spec(ExchangeId::GateioPerpetualsBtc))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual[0].market.instrument,
+            ("btc", "usd", InstrumentKind::Perpetual).into()
+        );
+        assert_eq!(actual[0].settlement, Settlement::Inverse);
+        assert_eq!(actual[0].contract_multiplier, Decimal::ONE);
+
+        assert_eq!(
+            actual[1].market.instrument,
+            (
+                "btc",
+                "usdt",
+                InstrumentKind::Future(FutureContract {
+                    expiry: Utc.timestamp_opt(1597910400, 0).unwrap()
+                })
+            )
+                .into()
+        );
+        assert_eq!(actual[1].contract_multiplier, dec!(0.0001));
+        assert_eq!(actual[1].status, InstrumentStatus::Delisted);
+
+        assert_eq!(
+            actual[2].market.instrument,
+            ("eth", "usd", InstrumentKind::Perpetual).into()
+        );
+        assert_eq!(actual[2].settlement, Settlement::Quanto);
+        assert_eq!(actual[2].contract_multiplier, dec!(0.000001));
+    }
+
+    #[test]
+    fn test_gateio_options_contract_spec() {
+        let input = r#"
+        {
+            "name": "BTC_USDT-20211130-65000-P", "tag": "WEEK", "create_time": 1636702700,
+            "expiration_time": 1638259200, "is_call": false, "strike_price": "65000",
+            "last_price": "13000", "mark_price": "14010", "orderbook_id": 9, "trade_id": 1,
+            "trade_size": 10, "position_size": 10, "underlying": "BTC_USDT",
+            "underlying_price": "70000", "multiplier": "0.0001", "order_price_round": "0.1",
+            "mark_price_round": "0.1", "maker_fee_rate": "0.0004", "taker_fee_rate": "0.0004",
+            "price_limit_fee_rate": "0.1", "ref_discount_rate": "0", "ref_rebate_rate": "0",
+            "order_price_deviate": "0.5", "order_size_min": 1, "order_size_max": 100000,
+            "orders_limit": 50, "is_active": true
+        }
+        "#;
+
+        let actual = serde_json::from_str::<GateioOptionsContract>(input)
+            .unwrap()
+            .spec()
+            .unwrap();
+
+        assert_eq!(
+            actual.market,
+            Market::new(
+                ExchangeId::GateioOptions,
+                (
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Option(OptionContract {
+                        kind: OptionKind::Put,
+                        exercise: OptionExercise::European,
+                        expiry: Utc.timestamp_opt(1638259200, 0).unwrap(),
+                        strike: dec!(65000),
+                    })
+                )
+            )
+        );
+        assert_eq!(actual.contract_multiplier, dec!(0.0001));
+        assert_eq!(actual.tick_size, dec!(0.1));
+    }
+}
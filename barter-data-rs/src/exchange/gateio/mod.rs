@@ -30,6 +30,10 @@ pub mod perpetual;
 /// [`GateioOptions`](option::GateioOptions)
 pub mod option;
 
+/// [`InstrumentDiscovery`](crate::instrument::InstrumentDiscovery) implementations for every
+/// [`Gateio`] spot, perpetual, futures & options [`ExchangeServer`].
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
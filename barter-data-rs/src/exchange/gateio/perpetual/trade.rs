@@ -7,6 +7,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for a
@@ -58,9 +59,9 @@ pub struct GateioFuturesTradeInner {
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "size")]
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 impl Identifier<Option<SubscriptionId>> for GateioFuturesTrades {
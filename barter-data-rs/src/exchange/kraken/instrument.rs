@@ -0,0 +1,206 @@
+use super::Kraken;
+use crate::{
+    exchange::ExchangeId,
+    instrument::{
+        increment_from_decimal_places, InstrumentClient, InstrumentDiscovery, InstrumentListing,
+        Instruments,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// [`Kraken`] public REST API base Url.
+///
+/// See docs: <https://docs.kraken.com/rest/#section/General-Usage>
+pub const HTTP_BASE_URL_KRAKEN: &str = "https://api.kraken.com/0/public";
+
+/// [`RestRequest`] that lists every [`Kraken`] tradable asset pair.
+///
+/// See docs: <https://docs.kraken.com/rest/#tag/Market-Data/operation/getTradableAssetPairs>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct KrakenAssetPairsRequest;
+
+impl RestRequest for KrakenAssetPairsRequest {
+    type Response = Instruments<Kraken, KrakenAssetPairs>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/AssetPairs"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "kraken_asset_pairs")
+    }
+}
+
+/// [`Kraken`] tradable asset pairs response. A non-empty `error` communicates that the request
+/// failed.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenAssetPairs {
+    pub error: Vec<String>,
+    #[serde(default)]
+    pub result: HashMap<String, KrakenAssetPair>,
+}
+
+/// [`Kraken`] tradable asset pair.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/rest/#tag/Market-Data/operation/getTradableAssetPairs>
+/// ```json
+/// {
+///     "altname": "XBTUSDT",
+///     "wsname": "XBT/USDT",
+///     "base": "XXBT",
+///     "quote": "USDT",
+///     "pair_decimals": 1,
+///     "lot_decimals": 8,
+///     "ordermin": "0.0001",
+///     "costmin": "0.5",
+///     "tick_size": "0.1",
+///     "status": "online"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenAssetPair {
+    /// WebSocket pair name (eg/ "XBT/USDT") - absent for pairs not available over WebSocket.
+    #[serde(default)]
+    pub wsname: Option<String>,
+    pub pair_decimals: u32,
+    pub lot_decimals: u32,
+    #[serde(default)]
+    pub ordermin: Option<Decimal>,
+    #[serde(default)]
+    pub costmin: Option<Decimal>,
+    #[serde(default)]
+    pub tick_size: Option<Decimal>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+impl KrakenAssetPair {
+    /// Normalise a [`KrakenAssetPair`] into an [`InstrumentSpec`].
+    ///
+    /// The `wsname` base & quote are used as-is (eg/ "XBT/USDT" -> xbt_usdt), since that is the
+    /// format the [`KrakenMarket`](super::market::KrakenMarket) subscribes with. Returns `None`
+    /// for pairs without a `wsname`.
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let (base, quote) = self.wsname.as_deref()?.split_once('/')?;
+
+        Some(InstrumentSpec {
+            market: Market::new(ExchangeId::Kraken, (base, quote, InstrumentKind::Spot)),
+            tick_size: self
+                .tick_size
+                .unwrap_or_else(|| increment_from_decimal_places(self.pair_decimals)),
+            lot_size: increment_from_decimal_places(self.lot_decimals),
+            min_quantity: self.ordermin.unwrap_or_default(),
+            max_quantity: None,
+            min_notional: self.costmin,
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: match self.status.as_deref() {
+                None | Some("online") => InstrumentStatus::Trading,
+                Some(_) => InstrumentStatus::Halted,
+            },
+        })
+    }
+}
+
+impl InstrumentListing for KrakenAssetPairs {
+    fn specs(self, _: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        if !self.error.is_empty() {
+            return Err(self.error.join(", "));
+        }
+
+        Ok(self
+            .result
+            .values()
+            .filter_map(KrakenAssetPair::spec)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for Kraken {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_KRAKEN;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        registry.fetch(client, KrakenAssetPairsRequest).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_kraken_asset_pairs_specs() {
+        let input = r#"
+        {
+            "error": [],
+            "result": {
+                "XBTUSDT": {
+                    "altname": "XBTUSDT", "wsname": "XBT/USDT", "aclass_base": "currency",
+                    "base": "XXBT", "aclass_quote": "currency", "quote": "USDT",
+                    "lot": "unit", "cost_decimals": 5, "pair_decimals": 1, "lot_decimals": 8,
+                    "lot_multiplier": 1, "leverage_buy": [2, 3], "leverage_sell": [2, 3],
+                    "fees": [[0, 0.26]], "fees_maker": [[0, 0.16]], "fee_volume_currency": "ZUSD",
+                    "margin_call": 80, "margin_stop": 40, "ordermin": "0.0001", "costmin": "0.5",
+                    "tick_size": "0.1", "status": "online"
+                },
+                "XETHZUSD.d": {
+                    "altname": "ETHUSD.d", "base": "XETH", "quote": "ZUSD",
+                    "pair_decimals": 2, "lot_decimals": 8
+                }
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<KrakenAssetPairs>(input)
+            .unwrap()
+            .result
+            .values()
+            .filter_map(KrakenAssetPair::spec)
+            .collect::<Vec<_>>();
+
+        let expected = vec![InstrumentSpec {
+            market: Market::new(ExchangeId::Kraken, ("xbt", "usdt", InstrumentKind::Spot)),
+            tick_size: dec!(0.1),
+            lot_size: dec!(0.00000001),
+            min_quantity: dec!(0.0001),
+            max_quantity: None,
+            min_notional: Some(dec!(0.5)),
+            contract_multiplier: Decimal::ONE,
+            settlement: Settlement::Linear,
+            status: InstrumentStatus::Trading,
+        }];
+
+        assert_eq!(actual, expected);
+
+        // Exchange error payloads are refused
+        let error = r#"{"error": ["EGeneral:Too many requests"]}"#;
+        assert!(serde_json::from_str::<Instruments<Kraken, KrakenAssetPairs>>(error).is_err());
+    }
+}
@@ -21,6 +21,9 @@ pub mod book;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// [`InstrumentDiscovery`](crate::instrument::InstrumentDiscovery) implementation for [`Kraken`].
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`]  specific market used for generating [`Connector::requests`].
 pub mod market;
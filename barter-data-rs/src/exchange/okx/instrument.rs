@@ -0,0 +1,380 @@
+use super::Okx;
+use crate::{
+    exchange::ExchangeId,
+    instrument::{InstrumentClient, InstrumentDiscovery, InstrumentListing, Instruments},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::de_str_optional,
+    error::SocketError,
+    metric::Tag,
+    model::{
+        instrument::{
+            kind::{FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind},
+            spec::{InstrumentRegistry, InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    },
+    protocol::http::rest::RestRequest,
+};
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] public data REST API base Url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api>
+pub const HTTP_BASE_URL_OKX: &str = "https://www.okx.com/api/v5/public";
+
+/// Generic [`Okx`] Http response wrapper.
+///
+/// A non "0" `code` communicates that the request failed.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxResponse<T> {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<T>,
+}
+
+impl<T> OkxResponse<T> {
+    /// Extract the data of the [`OkxResponse`] if the request was successful, otherwise return
+    /// the [`Okx`] error message.
+    pub fn into_data(self) -> Result<Vec<T>, String> {
+        match self.code.as_str() {
+            "0" => Ok(self.data),
+            _ => Err(format!("Okx code: {}, msg: {}", self.code, self.msg)),
+        }
+    }
+}
+
+/// [`Okx`] instrument type query parameter.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OkxInstrumentType {
+    Spot,
+    Margin,
+    Swap,
+    Futures,
+    Option,
+}
+
+impl OkxInstrumentType {
+    /// [`Okx`] `instType` query parameter value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OkxInstrumentType::Spot => "SPOT",
+            OkxInstrumentType::Margin => "MARGIN",
+            OkxInstrumentType::Swap => "SWAP",
+            OkxInstrumentType::Futures => "FUTURES",
+            OkxInstrumentType::Option => "OPTION",
+        }
+    }
+}
+
+/// [`Okx`] instrument. Fields that are not applicable to an [`OkxInstrumentType`] are sent as
+/// empty strings (eg/ `ctVal` for spot).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///     "instType": "SWAP",
+///     "instId": "BTC-USD-SWAP",
+///     "uly": "BTC-USD",
+///     "baseCcy": "",
+///     "quoteCcy": "",
+///     "settleCcy": "BTC",
+///     "ctVal": "100",
+///     "ctMult": "1",
+///     "ctType": "inverse",
+///     "optType": "",
+///     "stk": "",
+///     "expTime": "",
+///     "tickSz": "0.1",
+///     "lotSz": "1",
+///     "minSz": "1",
+///     "maxLmtSz": "10000",
+///     "state": "live"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInstrument {
+    pub inst_type: OkxInstrumentType,
+    pub inst_id: String,
+    #[serde(default)]
+    pub uly: String,
+    #[serde(default)]
+    pub base_ccy: String,
+    #[serde(default)]
+    pub quote_ccy: String,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub ct_val: Option<Decimal>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub ct_mult: Option<Decimal>,
+    #[serde(default)]
+    pub ct_type: String,
+    #[serde(default)]
+    pub opt_type: String,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub stk: Option<Decimal>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub exp_time: Option<i64>,
+    pub tick_sz: Decimal,
+    pub lot_sz: Decimal,
+    pub min_sz: Decimal,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub max_lmt_sz: Option<Decimal>,
+    pub state: String,
+}
+
+impl OkxInstrument {
+    /// Normalise an [`OkxInstrument`] into an [`InstrumentSpec`].
+    ///
+    /// Returns `None` for margin instruments (identical to spot), or instruments with malformed
+    /// contract details.
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let (base, quote) = match self.inst_type {
+            OkxInstrumentType::Spot => (self.base_ccy.as_str(), self.quote_ccy.as_str()),
+            OkxInstrumentType::Margin => return None,
+            _ => self.uly.split_once('-')?,
+        };
+
+        let expiry = || {
+            self.exp_time
+                .and_then(|expiry| Utc.timestamp_millis_opt(expiry).single())
+        };
+
+        let kind = match self.inst_type {
+            OkxInstrumentType::Spot | OkxInstrumentType::Margin => InstrumentKind::Spot,
+            OkxInstrumentType::Swap => InstrumentKind::Perpetual,
+            OkxInstrumentType::Futures => {
+                InstrumentKind::Future(FutureContract { expiry: expiry()? })
+            }
+            OkxInstrumentType::Option => InstrumentKind::Option(OptionContract {
+                kind: match self.opt_type.as_str() {
+                    "C" => OptionKind::Call,
+                    "P" => OptionKind::Put,
+                    _ => return None,
+                },
+                exercise: OptionExercise::European,
+                expiry: expiry()?,
+                strike: self.stk?,
+            }),
+        };
+
+        Some(InstrumentSpec {
+            market: Market::new(ExchangeId::Okx, (base, quote, kind)),
+            tick_size: self.tick_sz,
+            lot_size: self.lot_sz,
+            min_quantity: self.min_sz,
+            max_quantity: self.max_lmt_sz,
+            min_notional: None,
+            contract_multiplier: self.ct_val.unwrap_or(Decimal::ONE)
+                * self.ct_mult.unwrap_or(Decimal::ONE),
+            settlement: match self.ct_type.as_str() {
+                "inverse" => Settlement::Inverse,
+                _ => Settlement::Linear,
+            },
+            status: match self.state.as_str() {
+                "live" => InstrumentStatus::Trading,
+                "preopen" | "test" => InstrumentStatus::PreTrading,
+                "expired" => InstrumentStatus::Delisted,
+                _ => InstrumentStatus::Halted,
+            },
+        })
+    }
+}
+
+impl InstrumentListing for OkxResponse<OkxInstrument> {
+    fn specs(self, _: ExchangeId) -> Result<Vec<InstrumentSpec>, String> {
+        Ok(self
+            .into_data()?
+            .iter()
+            .filter_map(OkxInstrument::spec)
+            .collect())
+    }
+}
+
+/// [`RestRequest`] that lists every [`OkxInstrument`] of an [`OkxInstrumentType`], optionally
+/// filtered by underlying (mandatory for [`OkxInstrumentType::Option`]).
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct OkxInstrumentsRequest {
+    #[serde(rename = "instType")]
+    pub kind: OkxInstrumentType,
+    #[serde(rename = "uly", skip_serializing_if = "Option::is_none")]
+    pub underlying: Option<String>,
+}
+
+impl RestRequest for OkxInstrumentsRequest {
+    type Response = Instruments<Okx, OkxResponse<OkxInstrument>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/instruments"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "okx_instruments")
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+/// [`RestRequest`] that lists every underlying (eg/ "BTC-USD") of an [`OkxInstrumentType`].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-underlying>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct OkxUnderlyingRequest {
+    #[serde(rename = "instType")]
+    pub kind: OkxInstrumentType,
+}
+
+impl RestRequest for OkxUnderlyingRequest {
+    type Response = OkxResponse<Vec<String>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path() -> &'static str {
+        "/underlying"
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn metric_tag() -> Tag {
+        Tag::new("method", "okx_underlying")
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl InstrumentDiscovery for Okx {
+    const HTTP_BASE_URL: &'static str = HTTP_BASE_URL_OKX;
+
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError> {
+        let mut inserted = 0;
+        for kind in [
+            OkxInstrumentType::Spot,
+            OkxInstrumentType::Swap,
+            OkxInstrumentType::Futures,
+        ] {
+            let request = OkxInstrumentsRequest {
+                kind,
+                underlying: None,
+            };
+            inserted += registry.fetch(client, request).await?;
+        }
+
+        // Options must be requested per underlying (eg/ "BTC-USD")
+        let underlyings = client
+            .execute(OkxUnderlyingRequest {
+                kind: OkxInstrumentType::Option,
+            })
+            .await?
+            .into_data()
+            .map_err(SocketError::Exchange)?;
+
+        for underlying in underlyings.into_iter().flatten() {
+            let request = OkxInstrumentsRequest {
+                kind: OkxInstrumentType::Option,
+                underlying: Some(underlying),
+            };
+            inserted += registry.fetch(client, request).await?;
+        }
+
+        Ok(inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_okx_instruments_specs() {
+        let input = r#"
+        {
+            "code": "0",
+            "msg": "",
+            "data": [
+                {
+                    "instType": "SWAP", "instId": "BTC-USD-SWAP", "uly": "BTC-USD", "baseCcy": "",
+                    "quoteCcy": "", "settleCcy": "BTC", "ctVal": "100", "ctMult": "1",
+                    "ctType": "inverse", "optType": "", "stk": "", "expTime": "", "tickSz": "0.1",
+                    "lotSz": "1", "minSz": "1", "maxLmtSz": "10000", "state": "live"
+                },
+                {
+                    "instType": "OPTION", "instId": "BTC-USD-240329-50000-C", "uly": "BTC-USD",
+                    "baseCcy": "", "quoteCcy": "", "settleCcy": "BTC", "ctVal": "0.01",
+                    "ctMult": "1", "ctType": "", "optType": "C", "stk": "50000",
+                    "expTime": "1711699200000", "tickSz": "0.0005", "lotSz": "1", "minSz": "1",
+                    "maxLmtSz": "10000", "state": "suspend"
+                },
+                {
+                    "instType": "SPOT", "instId": "ETH-USDT", "uly": "", "baseCcy": "ETH",
+                    "quoteCcy": "USDT", "settleCcy": "", "ctVal": "", "ctMult": "", "ctType": "",
+                    "optType": "", "stk": "", "expTime": "", "tickSz": "0.01",
+                    "lotSz": "0.000001", "minSz": "0.0001", "maxLmtSz": "", "state": "live"
+                }
+            ]
+        }
+        "#;
+
+        let actual = serde_json::from_str::<Instruments<Okx, OkxResponse<OkxInstrument>>>(input)
+            .unwrap()
+            .specs;
+
+        assert_eq!(actual.len(), 3);
+
+        assert_eq!(
+            actual[0].market,
+            Market::new(ExchangeId::Okx, ("btc", "usd", InstrumentKind::Perpetual))
+        );
+        assert_eq!(actual[0].contract_multiplier, dec!(100));
+        assert_eq!(actual[0].settlement, Settlement::Inverse);
+
+        assert_eq!(
+            actual[1].market,
+            Market::new(
+                ExchangeId::Okx,
+                (
+                    "btc",
+                    "usd",
+                    InstrumentKind::Option(OptionContract {
+                        kind: OptionKind::Call,
+                        exercise: OptionExercise::European,
+                        expiry: Utc.timestamp_millis_opt(1711699200000).unwrap(),
+                        strike: dec!(50000),
+                    })
+                )
+            )
+        );
+        assert_eq!(actual[1].contract_multiplier, dec!(0.01));
+        assert_eq!(actual[1].status, InstrumentStatus::Halted);
+
+        assert_eq!(
+            actual[2].market,
+            Market::new(ExchangeId::Okx, ("eth", "usdt", InstrumentKind::Spot))
+        );
+        assert_eq!(actual[2].contract_multiplier, Decimal::ONE);
+        assert_eq!(actual[2].max_quantity, None);
+    }
+}
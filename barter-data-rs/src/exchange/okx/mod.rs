@@ -18,6 +18,9 @@ use url::Url;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// [`InstrumentDiscovery`](crate::instrument::InstrumentDiscovery) implementation for [`Okx`].
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -0,0 +1,238 @@
+use crate::exchange::{Connector, ExchangeId};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::spec::{InstrumentRegistry, InstrumentSpec},
+    protocol::http::{rest::client::RestClient, BuildStrategy, HttpParser},
+};
+use reqwest::{header::USER_AGENT, StatusCode};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// `User-Agent` header sent with every instrument discovery Http request - some exchanges
+/// (eg/ Coinbase) reject requests that do not provide one.
+const USER_AGENT_BARTER_DATA: &str = "barter-data";
+
+/// [`RestClient`] used to execute the public instrument listing
+/// [`RestRequest`](barter_integration::protocol::http::rest::RestRequest)s of an exchange.
+pub type InstrumentClient<'a> = RestClient<'a, PublicUserAgent, InstrumentParser>;
+
+/// Lists the [`InstrumentSpec`]s of every instrument an exchange [`Connector`] can subscribe to,
+/// using the exchange REST API.
+///
+/// Each [`InstrumentSpec`] contains a normalised [`Market`](barter_integration::model::Market)
+/// that can be used to construct [`Subscription`](crate::subscription::Subscription)s, as well as
+/// the trading rules (eg/ tick size, lot size, contract multiplier) of the instrument.
+///
+/// Instruments that the [`Connector`] is unable to subscribe to (eg/ dated futures on a
+/// perpetuals only server) are not returned.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::{binance::futures::BinanceFuturesUsd, gateio::option::GateioOptions},
+///     instrument::InstrumentDiscovery,
+///     subscription::{trade::PublicTrades, Subscription},
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+/// use chrono::{Duration, Utc};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     // All USDT perpetuals listed by BinanceFuturesUsd
+///     let usdt_perpetuals = BinanceFuturesUsd::fetch_instruments()
+///         .await
+///         .unwrap()
+///         .into_iter()
+///         .filter(|spec| {
+///             spec.market.instrument.quote.as_ref() == "usdt"
+///                 && spec.market.instrument.kind == InstrumentKind::Perpetual
+///         })
+///         .map(|spec| Subscription::new(BinanceFuturesUsd::default(), spec.market.instrument, PublicTrades))
+///         .collect::<Vec<_>>();
+///
+///     // All BTC options expiring within the next week listed by GateioOptions
+///     let next_week = Utc::now() + Duration::weeks(1);
+///     let btc_options = GateioOptions::fetch_instruments()
+///         .await
+///         .unwrap()
+///         .into_iter()
+///         .filter(|spec| {
+///             spec.market.instrument.base.as_ref() == "btc"
+///                 && spec.market.instrument.kind.expiry().is_some_and(|expiry| expiry <= next_week)
+///         })
+///         .collect::<Vec<_>>();
+/// }
+/// ```
+#[async_trait]
+pub trait InstrumentDiscovery
+where
+    Self: Connector,
+{
+    /// Base Url of the exchange REST API that lists the instruments of this server.
+    const HTTP_BASE_URL: &'static str;
+
+    /// Fetch the [`InstrumentSpec`] of every instrument listed by the exchange server.
+    async fn fetch_instruments() -> Result<Vec<InstrumentSpec>, SocketError> {
+        // Http request Metrics are not exported, but the receiver is kept alive until all
+        // requests are executed so sending them does not fail
+        let (metric_tx, _metric_rx) = mpsc::unbounded_channel();
+        let client = RestClient::new(
+            Self::HTTP_BASE_URL,
+            metric_tx,
+            PublicUserAgent,
+            InstrumentParser,
+        );
+
+        let mut registry = InstrumentRegistry::new();
+        Self::fetch_instruments_into(&client, &mut registry).await?;
+
+        Ok(Vec::from(registry))
+    }
+
+    /// Execute the instrument listing
+    /// [`RestRequest`](barter_integration::protocol::http::rest::RestRequest)s of the exchange
+    /// server using the provided [`InstrumentClient`], inserting every [`InstrumentSpec`] into
+    /// the [`InstrumentRegistry`]. Returns the number of [`InstrumentSpec`]s inserted.
+    async fn fetch_instruments_into(
+        client: &InstrumentClient<'_>,
+        registry: &mut InstrumentRegistry,
+    ) -> Result<usize, SocketError>;
+}
+
+/// [`BuildStrategy`] that builds a non-authenticated Http request with the barter-data
+/// `User-Agent` header.
+#[derive(Debug, Copy, Clone)]
+pub struct PublicUserAgent;
+
+impl BuildStrategy for PublicUserAgent {
+    fn build<Request>(
+        &self,
+        _: Request,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Request, SocketError> {
+        builder
+            .header(USER_AGENT, USER_AGENT_BARTER_DATA)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/// [`HttpParser`] for public instrument listing responses. Any JSON response that cannot be
+/// deserialised into the expected listing is returned as a [`SocketError::HttpResponse`].
+#[derive(Debug, Copy, Clone)]
+pub struct InstrumentParser;
+
+impl HttpParser for InstrumentParser {
+    type ApiError = serde_json::Value;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        SocketError::HttpResponse(status, error.to_string())
+    }
+}
+
+/// Exchange specific instrument listing response that can be normalised into
+/// [`InstrumentSpec`]s.
+pub trait InstrumentListing {
+    /// Normalise every supported instrument into an [`InstrumentSpec`] of the provided
+    /// [`ExchangeId`] server. Returns the exchange error message if the exchange responded with
+    /// an error payload.
+    fn specs(self, exchange: ExchangeId) -> Result<Vec<InstrumentSpec>, String>;
+}
+
+/// Instrument listing [`RestRequest::Response`](barter_integration::protocol::http::rest::RestRequest::Response)
+/// that deserialises an exchange specific [`InstrumentListing`] & normalises it into the
+/// [`InstrumentSpec`]s of the `Server` [`Connector`].
+///
+/// Yields every [`InstrumentSpec`] when iterated, so can be consumed by
+/// [`InstrumentRegistry::fetch`].
+#[derive(Debug)]
+pub struct Instruments<Server, Listing> {
+    pub specs: Vec<InstrumentSpec>,
+    phantom: PhantomData<(Server, Listing)>,
+}
+
+impl<'de, Server, Listing> Deserialize<'de> for Instruments<Server, Listing>
+where
+    Server: Connector,
+    Listing: InstrumentListing + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Listing::deserialize(deserializer)?
+            .specs(Server::ID)
+            .map(|specs| Self {
+                specs,
+                phantom: PhantomData,
+            })
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl<Server, Listing> IntoIterator for Instruments<Server, Listing> {
+    type Item = InstrumentSpec;
+    type IntoIter = std::vec::IntoIter<InstrumentSpec>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.specs.into_iter()
+    }
+}
+
+/// Determine the [`Decimal`] increment associated with a number of decimal places.
+///
+/// eg/ 2 decimal places -> 0.01
+pub(crate) fn increment_from_decimal_places(decimal_places: u32) -> Decimal {
+    Decimal::try_new(1, decimal_places).unwrap_or(Decimal::ONE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_increment_from_decimal_places() {
+        struct TestCase {
+            input: u32,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Zero decimal places is a whole unit increment
+                input: 0,
+                expected: Decimal::ONE,
+            },
+            TestCase {
+                // TC1: Two decimal places
+                input: 2,
+                expected: dec!(0.01),
+            },
+            TestCase {
+                // TC2: Eight decimal places
+                input: 8,
+                expected: dec!(0.00000001),
+            },
+            TestCase {
+                // TC3: Maximum Decimal scale
+                input: 28,
+                expected: Decimal::new(1, 28),
+            },
+            TestCase {
+                // TC4: Beyond the maximum Decimal scale falls back to a whole unit increment
+                input: 29,
+                expected: Decimal::ONE,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = increment_from_decimal_places(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
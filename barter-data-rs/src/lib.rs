@@ -107,6 +107,10 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// Defines the [`InstrumentDiscovery`](instrument::InstrumentDiscovery) trait used to list the
+/// instruments (& their trading rules) supported by each exchange [`Connector`] via REST.
+pub mod instrument;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
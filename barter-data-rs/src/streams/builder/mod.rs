@@ -6,7 +6,7 @@ use crate::{
     subscription::{SubKind, Subscription},
     Identifier,
 };
-use barter_integration::{error::SocketError, Validator};
+use barter_integration::{error::SocketError, metric::Metric, Validator};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 use tokio::sync::mpsc;
 
@@ -28,6 +28,9 @@ where
 {
     pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    /// Optional [`Metric`] transmitter that each consumer loop sends it's message, parse error &
+    /// reconnection counters to.
+    pub metric_tx: Option<mpsc::UnboundedSender<Metric>>,
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -38,6 +41,7 @@ where
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("metrics", &self.metric_tx.is_some())
             .finish()
     }
 }
@@ -51,6 +55,19 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            metric_tx: None,
+        }
+    }
+
+    /// Send the [`Metric`]s of every consumer loop to the provided `mpsc::UnboundedSender`, see
+    /// [`consume`].
+    ///
+    /// Note that only the consumer loops of [`Subscription`]s added via
+    /// [`subscribe()`](StreamBuilder::subscribe()) after this method is invoked emit [`Metric`]s.
+    pub fn metrics(self, metric_tx: mpsc::UnboundedSender<Metric>) -> Self {
+        Self {
+            metric_tx: Some(metric_tx),
+            ..self
         }
     }
 
@@ -74,6 +91,7 @@ where
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let metric_tx = self.metric_tx.clone();
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
@@ -85,7 +103,7 @@ where
             subscriptions.dedup();
 
             // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+            tokio::spawn(consume(subscriptions, exchange_tx, metric_tx));
 
             Ok(())
         }));
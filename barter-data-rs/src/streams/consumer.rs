@@ -5,8 +5,9 @@ use crate::{
     subscription::{SubKind, Subscription},
     Identifier, MarketStream,
 };
+use barter_integration::metric::{Field, Metric, Tag};
 use futures::StreamExt;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
@@ -15,14 +16,22 @@ use tracing::{error, info, warn};
 /// of repeated disconnections with re-initialisation failures.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Minimum interval between the [`Metric`]s emitted by the [`consume`] function whilst consuming
+/// messages from a [`MarketStream`].
+pub const METRIC_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
 /// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
 /// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
 /// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
+///
+/// If a `metric_tx` is provided, a `market_stream` [`Metric`] is sent every [`METRIC_INTERVAL`]
+/// with the number of messages, parse errors & reconnections since the previous [`Metric`].
 pub async fn consume<Exchange, Kind>(
     subscriptions: Vec<Subscription<Exchange, Kind>>,
     exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
 ) -> DataError
 where
     Exchange: StreamSelector<Kind>,
@@ -39,6 +48,13 @@ where
         "MarketStream consumer loop running",
     );
 
+    // Determine the SubKind associated with these Subscriptions for categorising Metrics
+    let kind = subscriptions
+        .first()
+        .map(|subscription| format!("{:?}", subscription.kind))
+        .unwrap_or_default();
+    let mut metrics = StreamMetrics::new(metric_tx, exchange.as_str(), kind);
+
     // Consumer loop retry parameters
     let mut attempt: u32 = 0;
     let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
@@ -74,6 +90,7 @@ where
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
+                    metrics.message();
                     let _ = exchange_tx.send(market_event).map_err(|err| {
                         error!(
                             payload = ?err.0,
@@ -95,6 +112,7 @@ where
 
                 // If non-terminal DataError: log & continue
                 Err(error) => {
+                    metrics.parse_error();
                     warn!(
                         %exchange,
                         %error,
@@ -107,6 +125,7 @@ where
         }
 
         // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        metrics.reconnect();
         warn!(
             %exchange,
             backoff_ms,
@@ -116,3 +135,122 @@ where
         tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
     }
 }
+
+/// Message, parse error & reconnection counters of a [`consume`] loop, sent as a `market_stream`
+/// [`Metric`] at most every [`METRIC_INTERVAL`].
+#[derive(Debug)]
+struct StreamMetrics {
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+    tags: Vec<Tag>,
+    messages: u64,
+    parse_errors: u64,
+    reconnects: u64,
+    last_emitted: Instant,
+}
+
+impl StreamMetrics {
+    fn new(metric_tx: Option<mpsc::UnboundedSender<Metric>>, exchange: &str, kind: String) -> Self {
+        Self {
+            metric_tx,
+            tags: vec![Tag::new("exchange", exchange), Tag::new("kind", kind)],
+            messages: 0,
+            parse_errors: 0,
+            reconnects: 0,
+            last_emitted: Instant::now(),
+        }
+    }
+
+    fn message(&mut self) {
+        self.messages += 1;
+        self.emit_if_due();
+    }
+
+    fn parse_error(&mut self) {
+        self.parse_errors += 1;
+        self.emit_if_due();
+    }
+
+    /// Reconnections are infrequent, so the counters are always emitted immediately.
+    fn reconnect(&mut self) {
+        self.reconnects += 1;
+        self.emit();
+    }
+
+    fn emit_if_due(&mut self) {
+        if self.last_emitted.elapsed() >= METRIC_INTERVAL {
+            self.emit();
+        }
+    }
+
+    /// Send the counters accumulated since the previous [`Metric`] & reset them.
+    fn emit(&mut self) {
+        let metric_tx = match &self.metric_tx {
+            Some(metric_tx) => metric_tx,
+            None => return,
+        };
+
+        let metric = Metric::new(
+            "market_stream",
+            self.tags.clone(),
+            [
+                Field::new("messages_total", self.messages),
+                Field::new("parse_errors_total", self.parse_errors),
+                Field::new("reconnects_total", self.reconnects),
+            ],
+        );
+
+        if metric_tx.send(metric).is_err() {
+            warn!(
+                why = "Metric receiver dropped",
+                action = "no longer emitting MarketStream Metrics",
+                "failed to send Metric"
+            );
+            self.metric_tx = None;
+        }
+
+        self.messages = 0;
+        self.parse_errors = 0;
+        self.reconnects = 0;
+        self.last_emitted = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::metric::Value;
+
+    #[test]
+    fn test_stream_metrics_emits_counters_since_previous_metric() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let mut metrics =
+            StreamMetrics::new(Some(metric_tx), "binance_spot", "PublicTrades".into());
+
+        metrics.message();
+        metrics.message();
+        metrics.parse_error();
+        assert!(
+            metric_rx.try_recv().is_err(),
+            "emitted before METRIC_INTERVAL"
+        );
+
+        metrics.reconnect();
+        let metric = metric_rx.try_recv().unwrap();
+        assert_eq!(metric.name, "market_stream");
+        assert_eq!(
+            metric.fields,
+            vec![
+                Field::new("messages_total", Value::UInt(2)),
+                Field::new("parse_errors_total", Value::UInt(1)),
+                Field::new("reconnects_total", Value::UInt(1)),
+            ]
+        );
+
+        metrics.reconnect();
+        let metric = metric_rx.try_recv().unwrap();
+        assert_eq!(
+            metric.fields[0],
+            Field::new("messages_total", Value::UInt(0))
+        );
+    }
+}
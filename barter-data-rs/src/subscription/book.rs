@@ -6,8 +6,8 @@ use crate::{
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
 use tracing::debug;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
@@ -34,7 +34,7 @@ impl OrderBookL1 {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> f64 {
+    pub fn mid_price(&self) -> Decimal {
         mid_price(self.best_bid.price, self.best_ask.price)
     }
 
@@ -42,7 +42,7 @@ impl OrderBookL1 {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> f64 {
+    pub fn volume_weighed_mid_price(&self) -> Decimal {
         volume_weighted_mid_price(self.best_bid, self.best_ask)
     }
 }
@@ -90,7 +90,7 @@ impl OrderBook {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> Option<f64> {
+    pub fn mid_price(&self) -> Option<Decimal> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
             (Some(best_bid), None) => Some(best_bid.price),
@@ -103,7 +103,7 @@ impl OrderBook {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> Option<f64> {
+    pub fn volume_weighed_mid_price(&self) -> Option<Decimal> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => {
                 Some(volume_weighted_mid_price(*best_bid, *best_ask))
@@ -169,7 +169,7 @@ impl OrderBookSide {
             .find(|(_index, level)| level.eq_price(new_level.price))
         {
             // Scenario 1a: Level exists & new value is 0 => remove Level
-            Some((index, _)) if new_level.amount == 0.0 => {
+            Some((index, _)) if new_level.amount.is_zero() => {
                 self.levels.remove(index);
             }
 
@@ -179,7 +179,7 @@ impl OrderBookSide {
             }
 
             // Scenario 2a: Level does not exist & new value > 0 => insert new Level
-            None if new_level.amount > 0.0 => self.levels.push(new_level),
+            None if new_level.amount > Decimal::ZERO => self.levels.push(new_level),
 
             // Scenario 2b: Level does not exist & new value is 0 => log error & continue
             _ => {
@@ -204,44 +204,28 @@ impl OrderBookSide {
     }
 }
 
-/// Normalised Barter OrderBook [`Level`].
-#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+/// Normalised Barter OrderBook [`Level`]. Ordered by price, then amount.
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub struct Level {
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
 }
 
 impl<T> From<(T, T)> for Level
 where
-    T: Into<f64>,
+    T: Into<Decimal>,
 {
     fn from((price, amount): (T, T)) -> Self {
         Self::new(price, amount)
     }
 }
 
-impl Ord for Level {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other)
-            .unwrap_or_else(|| panic!("{:?}.partial_cmp({:?}) impossible", self, other))
-    }
-}
-
-impl PartialOrd for Level {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.price.partial_cmp(&other.price)? {
-            Ordering::Equal => self.amount.partial_cmp(&other.amount),
-            non_equal => Some(non_equal),
-        }
-    }
-}
-
-impl Eq for Level {}
-
 impl Level {
     pub fn new<T>(price: T, amount: T) -> Self
     where
-        T: Into<f64>,
+        T: Into<Decimal>,
     {
         Self {
             price: price.into(),
@@ -249,9 +233,8 @@ impl Level {
         }
     }
 
-    pub fn eq_price(&self, price: f64) -> bool {
-        let diff = (price - self.price).abs();
-        f64::EPSILON > diff
+    pub fn eq_price(&self, price: Decimal) -> bool {
+        self.price == price
     }
 }
 
@@ -260,17 +243,18 @@ impl Level {
 /// Calculate the mid price by taking the average of the best bid and ask prices.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn mid_price(best_bid_price: f64, best_ask_price: f64) -> f64 {
-    (best_bid_price + best_ask_price) / 2.0
+pub fn mid_price(best_bid_price: Decimal, best_ask_price: Decimal) -> Decimal {
+    (best_bid_price + best_ask_price) / Decimal::TWO
 }
 
 /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
-/// with their associated amount.
+/// with their associated amount. Falls back to the [`mid_price`] if both amounts are zero.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
+pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> Decimal {
     ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount))
-        / (best_bid.amount + best_ask.amount)
+        .checked_div(best_bid.amount + best_ask.amount)
+        .unwrap_or_else(|| mid_price(best_bid.price, best_ask.price))
 }
 
 impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
@@ -288,6 +272,8 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
+    use std::cmp::Ordering;
 
     mod order_book_l1 {
         use super::*;
@@ -296,7 +282,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Decimal,
             }
 
             let tests = vec![
@@ -307,7 +293,7 @@ mod tests {
                         best_bid: Level::new(100, 999999),
                         best_ask: Level::new(200, 1),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC1
@@ -316,7 +302,7 @@ mod tests {
                         best_bid: Level::new(50, 1),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC2
@@ -325,7 +311,7 @@ mod tests {
                         best_bid: Level::new(10, 999999),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 130.0,
+                    expected: dec!(130.0),
                 },
             ];
 
@@ -338,7 +324,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Decimal,
             }
 
             let tests = vec![
@@ -349,7 +335,7 @@ mod tests {
                         best_bid: Level::new(100, 100),
                         best_ask: Level::new(200, 100),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC1: volume affects mid-price
@@ -358,7 +344,7 @@ mod tests {
                         best_bid: Level::new(100, 600),
                         best_ask: Level::new(200, 1000),
                     },
-                    expected: 137.5,
+                    expected: dec!(137.5),
                 },
                 TestCase {
                     // TC2: volume the same and price the same
@@ -367,7 +353,7 @@ mod tests {
                         best_bid: Level::new(1000, 999999),
                         best_ask: Level::new(1000, 999999),
                     },
-                    expected: 1000.0,
+                    expected: dec!(1000.0),
                 },
             ];
 
@@ -388,7 +374,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Decimal>,
             }
 
             let tests = vec![
@@ -413,14 +399,17 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
                     },
-                    expected: Some(100.0),
+                    expected: Some(dec!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -432,10 +421,13 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(50.0), dec!(100.0)),
+                                Level::new(dec!(100.0), dec!(100.0)),
+                            ],
                         },
                     },
-                    expected: Some(50.0),
+                    expected: Some(dec!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -443,14 +435,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(100.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     },
-                    expected: Some(150.0),
+                    expected: Some(dec!(150.0)),
                 },
             ];
 
@@ -463,7 +461,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Decimal>,
             }
 
             let tests = vec![
@@ -488,14 +486,17 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
                     },
-                    expected: Some(100.0),
+                    expected: Some(dec!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -507,10 +508,13 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(50.0), dec!(100.0)),
+                                Level::new(dec!(100.0), dec!(100.0)),
+                            ],
                         },
                     },
-                    expected: Some(50.0),
+                    expected: Some(dec!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -518,14 +522,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(100.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     },
-                    expected: Some(150.0),
+                    expected: Some(dec!(150.0)),
                 },
                 TestCase {
                     // TC4: valid volume weighted mid-price
@@ -533,14 +543,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 3000.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(3000.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(1000.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     },
-                    expected: Some(175.0),
+                    expected: Some(dec!(175.0)),
                 },
             ];
 
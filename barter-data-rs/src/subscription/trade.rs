@@ -1,6 +1,7 @@
 use super::SubKind;
 use barter_integration::model::Side;
 use barter_macro::{DeSubKind, SerSubKind};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`PublicTrade`]
@@ -16,7 +17,7 @@ impl SubKind for PublicTrades {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PublicTrade {
     pub id: String,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
     pub side: Side,
 }
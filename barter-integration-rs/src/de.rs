@@ -16,6 +16,23 @@ where
     data.parse::<T>().map_err(serde::de::Error::custom)
 }
 
+/// Deserialize a `String` as the desired type, where an empty `String` is deserialized as `None`.
+pub fn de_str_optional<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let data: &str = serde::de::Deserialize::deserialize(deserializer)?;
+    match data {
+        "" => Ok(None),
+        data => data
+            .parse::<T>()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
 /// Deserialize a `u64` milliseconds value as `DateTime<Utc>`.
 pub fn de_u64_epoch_ms_as_datetime_utc<'de, D>(
     deserializer: D,
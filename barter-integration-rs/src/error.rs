@@ -1,5 +1,6 @@
-use crate::model::SubscriptionId;
+use crate::model::{instrument::spec::InstrumentStatus, MarketId, SubscriptionId};
 use reqwest::Error;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All socket IO related errors generated in `barter-integration`.
@@ -66,3 +67,47 @@ impl From<reqwest::Error> for SocketError {
         }
     }
 }
+
+/// All [`InstrumentSpec`](crate::model::instrument::spec::InstrumentSpec) validation & loading
+/// errors generated in `barter-integration`.
+#[derive(Debug, Error)]
+pub enum InstrumentError {
+    #[error("instrument is not trading: {0:?}")]
+    NotTrading(InstrumentStatus),
+
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    InvalidTickSize { price: Decimal, tick_size: Decimal },
+
+    #[error("quantity {quantity} is not a multiple of lot size {lot_size}")]
+    InvalidLotSize {
+        quantity: Decimal,
+        lot_size: Decimal,
+    },
+
+    #[error("quantity {quantity} is below the minimum quantity {min_quantity}")]
+    BelowMinQuantity {
+        quantity: Decimal,
+        min_quantity: Decimal,
+    },
+
+    #[error("quantity {quantity} is above the maximum quantity {max_quantity}")]
+    AboveMaxQuantity {
+        quantity: Decimal,
+        max_quantity: Decimal,
+    },
+
+    #[error("notional {notional} is below the minimum notional {min_notional}")]
+    BelowMinNotional {
+        notional: Decimal,
+        min_notional: Decimal,
+    },
+
+    #[error("no InstrumentSpec registered for market: {0}")]
+    NotFound(MarketId),
+
+    #[error("failed to read InstrumentSpecs: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to deserialise InstrumentSpecs: {0}")]
+    Deserialise(#[from] serde_json::Error),
+}
@@ -0,0 +1,220 @@
+use super::{Field, Metric, Tag, Value};
+use crate::error::SocketError;
+use reqwest::header::AUTHORIZATION;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Maximum number of [`Metric`]s an [`InfluxDbClient`] writes in a single request whilst
+/// consuming from a `mpsc::UnboundedReceiver`.
+pub const MAX_BATCH_SIZE: usize = 500;
+
+/// Encode a [`Metric`] as a single line of InfluxDB line protocol with millisecond precision.
+///
+/// eg/ `http_request_duration,base_url=https://api.binance.com duration=42u 1672531200000`
+///
+/// See documentation: <https://docs.influxdata.com/influxdb/v2/reference/syntax/line-protocol/>
+pub fn line_protocol(metric: &Metric) -> String {
+    let mut tags = metric.tags.clone();
+    tags.sort();
+
+    let tags = tags
+        .iter()
+        .map(|Tag { key, value }| format!(",{}={}", escape_key(key), escape_key(value)))
+        .collect::<String>();
+
+    let fields = metric
+        .fields
+        .iter()
+        .map(|Field { key, value }| format!("{}={}", escape_key(key), encode_value(value)))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "{}{tags} {fields} {}",
+        escape_measurement(metric.name),
+        metric.time
+    )
+}
+
+/// Escape a measurement name, which may not contain unescaped commas or spaces.
+fn escape_measurement(measurement: &str) -> String {
+    measurement.replace(',', "\\,").replace(' ', "\\ ")
+}
+
+/// Escape a tag key, tag value or field key, which may not contain unescaped commas, equals signs
+/// or spaces.
+fn escape_key(key: &str) -> String {
+    key.replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+/// Encode a field [`Value`] using the relevant line protocol data type.
+fn encode_value(value: &Value) -> String {
+    match value {
+        Value::Float(value) => value.to_string(),
+        Value::Int(value) => format!("{value}i"),
+        Value::UInt(value) => format!("{value}u"),
+        Value::Bool(value) => value.to_string(),
+        Value::String(value) => {
+            format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+    }
+}
+
+/// Configuration for constructing an [`InfluxDbClient`] that writes to an InfluxDB v2 bucket.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InfluxDbConfig {
+    /// Base Url of the InfluxDB server, eg/ `http://localhost:8086`.
+    pub url: String,
+    pub org: String,
+    pub bucket: String,
+    /// API token with write access to the bucket.
+    pub token: String,
+}
+
+/// Sink that writes [`Metric`]s to an InfluxDB v2 bucket using the line protocol.
+#[derive(Debug, Clone)]
+pub struct InfluxDbClient {
+    http_client: reqwest::Client,
+    write_url: url::Url,
+    token: String,
+}
+
+impl InfluxDbClient {
+    /// Constructs a new [`InfluxDbClient`] using the provided [`InfluxDbConfig`].
+    pub fn new(config: InfluxDbConfig) -> Result<Self, url::ParseError> {
+        let write_url = url::Url::parse_with_params(
+            &format!("{}/api/v2/write", config.url.trim_end_matches('/')),
+            [
+                ("org", config.org.as_str()),
+                ("bucket", config.bucket.as_str()),
+                ("precision", "ms"),
+            ],
+        )?;
+
+        Ok(Self {
+            http_client: reqwest::Client::new(),
+            write_url,
+            token: config.token,
+        })
+    }
+
+    /// Write a batch of [`Metric`]s to the InfluxDB bucket in a single request.
+    pub async fn write(&self, metrics: &[Metric]) -> Result<(), SocketError> {
+        let body = metrics
+            .iter()
+            .map(line_protocol)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let response = self
+            .http_client
+            .post(self.write_url.clone())
+            .header(AUTHORIZATION, format!("Token {}", self.token))
+            .body(body)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(SocketError::HttpResponse(status, response.text().await?))
+        }
+    }
+
+    /// Consume [`Metric`]s from the provided `mpsc::UnboundedReceiver` & write them to the
+    /// InfluxDB bucket until every transmitter is dropped. [`Metric`]s that are already queued
+    /// are written together in batches of up to [`MAX_BATCH_SIZE`]. Failed writes are logged &
+    /// the batch is dropped.
+    pub async fn consume(self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        while let Some(metric) = metric_rx.recv().await {
+            let mut batch = vec![metric];
+            while batch.len() < MAX_BATCH_SIZE {
+                match metric_rx.try_recv() {
+                    Ok(metric) => batch.push(metric),
+                    Err(_) => break,
+                }
+            }
+
+            if let Err(error) = self.write(&batch).await {
+                warn!(
+                    %error,
+                    num_metrics = batch.len(),
+                    action = "dropping batch",
+                    "failed to write Metrics to InfluxDB"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_protocol() {
+        struct TestCase {
+            input: Metric,
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Tags sorted & every Value type encoded
+                input: Metric {
+                    name: "trader",
+                    time: 1672531200000,
+                    tags: vec![
+                        Tag::new("market", "binance_btc_usdt_spot"),
+                        Tag::new("engine_id", "1"),
+                    ],
+                    fields: vec![
+                        Field::new("latency", 1.5),
+                        Field::new("depth", -2_i64),
+                        Field::new("orders_total", 3_u64),
+                        Field::new("paused", false),
+                        Field::new("note", "say \"hi\"".to_owned()),
+                    ],
+                },
+                expected: "trader,engine_id=1,market=binance_btc_usdt_spot \
+                    latency=1.5,depth=-2i,orders_total=3u,paused=false,note=\"say \\\"hi\\\"\" \
+                    1672531200000",
+            },
+            TestCase {
+                // TC1: Special characters escaped in measurement, tags & field keys
+                input: Metric {
+                    name: "http request",
+                    time: 1,
+                    tags: vec![Tag::new("path", "a=b,c d")],
+                    fields: vec![Field::new("duration ms", 7_u64)],
+                },
+                expected: "http\\ request,path=a\\=b\\,c\\ d duration\\ ms=7u 1",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = line_protocol(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_influx_db_client_write_url() {
+        let client = InfluxDbClient::new(InfluxDbConfig {
+            url: "http://localhost:8086/".to_owned(),
+            org: "barter".to_owned(),
+            bucket: "live trading".to_owned(),
+            token: "token".to_owned(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            client.write_url.as_str(),
+            "http://localhost:8086/api/v2/write?org=barter&bucket=live+trading&precision=ms"
+        );
+    }
+}
@@ -1,5 +1,21 @@
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
+/// [`PrometheusExporter`](prometheus::PrometheusExporter) that aggregates [`Metric`]s for scraping
+/// in the Prometheus text exposition format.
+pub mod prometheus;
+
+/// InfluxDB line protocol encoding of [`Metric`]s, and an
+/// [`InfluxDbClient`](influx::InfluxDbClient) sink that writes them to an InfluxDB bucket.
+pub mod influx;
+
+/// Suffix of a [`Field`] key that identifies it's [`Value`] as an increment to a monotonic
+/// counter, rather than the latest observation of a gauge.
+///
+/// eg/ `Field::new("messages_total", 3_u64)` communicates three more messages have been consumed
+/// since the previous [`Metric`] was emitted.
+pub const COUNTER_SUFFIX: &str = "_total";
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize)]
 pub struct Metric {
     /// Metric name.
@@ -36,6 +52,22 @@ pub enum Value {
     String(String),
 }
 
+impl Metric {
+    /// Constructs a new [`Metric`] with the provided [`Tag`]s & [`Field`]s, observed now.
+    pub fn new<Tags, Fields>(name: &'static str, tags: Tags, fields: Fields) -> Self
+    where
+        Tags: IntoIterator<Item = Tag>,
+        Fields: IntoIterator<Item = Field>,
+    {
+        Self {
+            name,
+            time: Utc::now().timestamp_millis() as u64,
+            tags: tags.into_iter().collect(),
+            fields: fields.into_iter().collect(),
+        }
+    }
+}
+
 impl<S> From<(&'static str, S)> for Tag
 where
     S: Into<String>,
@@ -76,6 +108,12 @@ impl Field {
             value: value.into(),
         }
     }
+
+    /// Determines if this [`Field`] is an increment to a monotonic counter, see
+    /// [`COUNTER_SUFFIX`].
+    pub fn is_counter(&self) -> bool {
+        self.key.ends_with(COUNTER_SUFFIX)
+    }
 }
 
 impl From<f64> for Value {
@@ -0,0 +1,226 @@
+use super::{Field, Metric, Tag, Value};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::mpsc;
+
+/// HTTP `Content-Type` of the Prometheus text exposition format rendered by a
+/// [`PrometheusExporter`].
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Prefix of every metric family name rendered by a [`PrometheusExporter`].
+pub const NAMESPACE: &str = "barter";
+
+/// Aggregates [`Metric`]s into Prometheus metric families that can be rendered in the text
+/// exposition format, eg/ to serve a `/metrics` endpoint for scraping.
+///
+/// Each numeric [`Field`] becomes the sample of a `barter_<metric>_<field>` family, labelled with
+/// the [`Metric`] [`Tag`]s. Counter [`Field`]s (see [`Field::is_counter`]) are accumulated, whilst
+/// every other [`Field`] is a gauge holding the latest observation. [`Value::String`] [`Field`]s
+/// cannot be represented & are ignored.
+///
+/// Cheap to clone - every clone shares the same metric families.
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusExporter {
+    families: Arc<Mutex<BTreeMap<String, Family>>>,
+}
+
+/// Prometheus metric family holding one sample for each unique set of labels.
+#[derive(Debug)]
+struct Family {
+    kind: FamilyKind,
+    samples: BTreeMap<Vec<Tag>, f64>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum FamilyKind {
+    Counter,
+    Gauge,
+}
+
+impl PrometheusExporter {
+    /// Constructs a new [`PrometheusExporter`] with no metric families.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the [`Field`]s of the provided [`Metric`] in the relevant metric families.
+    pub fn record(&self, metric: &Metric) {
+        let mut labels = metric.tags.clone();
+        labels.sort();
+
+        let mut families = self
+            .families
+            .lock()
+            .expect("PrometheusExporter lock poisoned");
+
+        for field in &metric.fields {
+            let value = match sample_value(&field.value) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let kind = if field.is_counter() {
+                FamilyKind::Counter
+            } else {
+                FamilyKind::Gauge
+            };
+
+            let family = families
+                .entry(family_name(metric.name, field))
+                .or_insert_with(|| Family {
+                    kind,
+                    samples: BTreeMap::new(),
+                });
+
+            let sample = family.samples.entry(labels.clone()).or_insert(0.0);
+            match family.kind {
+                FamilyKind::Counter => *sample += value,
+                FamilyKind::Gauge => *sample = value,
+            }
+        }
+    }
+
+    /// Consume [`Metric`]s from the provided `mpsc::UnboundedReceiver` & record them until every
+    /// transmitter is dropped.
+    pub async fn consume(self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        while let Some(metric) = metric_rx.recv().await {
+            self.record(&metric);
+        }
+    }
+
+    /// Render every metric family in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let families = self
+            .families
+            .lock()
+            .expect("PrometheusExporter lock poisoned");
+
+        let mut output = String::new();
+        for (name, family) in families.iter() {
+            let kind = match family.kind {
+                FamilyKind::Counter => "counter",
+                FamilyKind::Gauge => "gauge",
+            };
+            let _ = writeln!(output, "# TYPE {name} {kind}");
+
+            for (labels, value) in &family.samples {
+                let _ = writeln!(
+                    output,
+                    "{name}{} {}",
+                    render_labels(labels),
+                    render_value(*value)
+                );
+            }
+        }
+
+        output
+    }
+}
+
+/// Determine the name of the metric family a [`Metric`] [`Field`] is recorded in.
+fn family_name(metric: &str, field: &Field) -> String {
+    sanitise(&format!("{NAMESPACE}_{metric}_{}", field.key))
+}
+
+/// Replace any character that is invalid in a Prometheus metric or label name with `_`.
+fn sanitise(name: &str) -> String {
+    name.chars()
+        .map(|char| match char {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | ':' => char,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Render a set of labels, eg/ `{exchange="binance",market="btc_usdt"}`.
+fn render_labels(labels: &[Tag]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+
+    let labels = labels
+        .iter()
+        .map(|tag| {
+            let value = tag
+                .value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{value}\"", sanitise(tag.key))
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{{{labels}}}")
+}
+
+/// Render a sample value, using the Prometheus representation of infinity.
+fn render_value(value: f64) -> String {
+    match value {
+        value if value == f64::INFINITY => "+Inf".to_owned(),
+        value if value == f64::NEG_INFINITY => "-Inf".to_owned(),
+        value => value.to_string(),
+    }
+}
+
+/// Convert a [`Value`] into a Prometheus sample value, if it is numeric.
+fn sample_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(value) => Some(*value),
+        Value::Int(value) => Some(*value as f64),
+        Value::UInt(value) => Some(*value as f64),
+        Value::Bool(value) => Some(if *value { 1.0 } else { 0.0 }),
+        Value::String(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(fields: Vec<Field>) -> Metric {
+        Metric {
+            name: "market_stream",
+            time: 0,
+            tags: vec![Tag::new("exchange", "binance"), Tag::new("kind", "trades")],
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_render_accumulates_counters_and_replaces_gauges() {
+        let exporter = PrometheusExporter::new();
+
+        exporter.record(&metric(vec![
+            Field::new("messages_total", 3_u64),
+            Field::new("latency_ms", 10.5),
+            Field::new("description", "ignored".to_owned()),
+        ]));
+        exporter.record(&metric(vec![
+            Field::new("messages_total", 2_u64),
+            Field::new("latency_ms", 4.0),
+        ]));
+
+        let expected = "\
+# TYPE barter_market_stream_latency_ms gauge
+barter_market_stream_latency_ms{exchange=\"binance\",kind=\"trades\"} 4
+# TYPE barter_market_stream_messages_total counter
+barter_market_stream_messages_total{exchange=\"binance\",kind=\"trades\"} 5
+";
+
+        assert_eq!(exporter.render(), expected);
+    }
+
+    #[test]
+    fn test_render_labels_escapes_values() {
+        let labels = vec![Tag::new("reason", "a \"quoted\"\\path\nnext")];
+
+        assert_eq!(
+            render_labels(&labels),
+            "{reason=\"a \\\"quoted\\\"\\\\path\\nnext\"}"
+        );
+    }
+}
@@ -20,6 +20,18 @@ impl Default for InstrumentKind {
     }
 }
 
+impl InstrumentKind {
+    /// Returns the expiry of a [`FutureContract`] or [`OptionContract`], and `None` for
+    /// instruments that never expire (eg/ Spot & Perpetual).
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        match self {
+            InstrumentKind::Future(future) => Some(future.expiry),
+            InstrumentKind::Option(option) => Some(option.expiry),
+            InstrumentKind::Spot | InstrumentKind::Perpetual => None,
+        }
+    }
+}
+
 impl Display for InstrumentKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
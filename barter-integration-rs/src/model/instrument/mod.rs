@@ -5,6 +5,10 @@ use std::fmt::{Display, Formatter};
 pub mod kind;
 pub mod symbol;
 
+/// [`InstrumentSpec`](spec::InstrumentSpec) exchange trading rules (eg/ tick size, lot size, min
+/// notional) & the [`InstrumentRegistry`](spec::InstrumentRegistry) used to validate orders.
+pub mod spec;
+
 /// Barter representation of an `Instrument`. Used to uniquely identify a `base_quote` pair, and it's
 /// associated instrument type.
 ///
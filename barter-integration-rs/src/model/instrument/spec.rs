@@ -0,0 +1,480 @@
+use crate::{
+    error::InstrumentError,
+    model::{instrument::Instrument, Exchange, Market, MarketId},
+    protocol::http::{rest::client::RestClient, rest::RestRequest, BuildStrategy, HttpParser},
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path};
+
+/// Exchange trading rules & contract metadata associated with a [`Market`].
+///
+/// Used to round order prices & quantities to valid increments, and to validate orders before
+/// they are sent to the exchange.
+///
+/// eg/ InstrumentSpec { market: binance_spot_btc_usdt_spot, tick_size: 0.01, lot_size: 0.00001, .. }
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentSpec {
+    #[serde(flatten)]
+    pub market: Market,
+    /// Minimum price increment.
+    pub tick_size: Decimal,
+    /// Minimum quantity increment.
+    pub lot_size: Decimal,
+    #[serde(default)]
+    pub min_quantity: Decimal,
+    #[serde(default)]
+    pub max_quantity: Option<Decimal>,
+    /// Minimum order value, denominated in the quote currency.
+    #[serde(default)]
+    pub min_notional: Option<Decimal>,
+    /// Value of one contract - always one for spot markets.
+    #[serde(default = "InstrumentSpec::default_contract_multiplier")]
+    pub contract_multiplier: Decimal,
+    #[serde(default)]
+    pub settlement: Settlement,
+    #[serde(default)]
+    pub status: InstrumentStatus,
+}
+
+/// Currency an [`InstrumentSpec`] contract is margined & settled in.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Settlement {
+    /// Margined & settled in the quote currency (eg/ BTC-USDT perpetual settled in USDT).
+    #[default]
+    Linear,
+    /// Margined & settled in the base currency, with a contract value fixed in the quote currency
+    /// (eg/ BTC-USD perpetual worth 100 USD per contract, settled in BTC).
+    Inverse,
+    /// Margined & settled in a third currency at a fixed multiplier per quote price point,
+    /// independent of the exchange rate (eg/ ETH-USD perpetual worth 0.000001 BTC per USD, settled
+    /// in BTC).
+    Quanto,
+}
+
+/// Trading status of an [`InstrumentSpec`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InstrumentStatus {
+    #[default]
+    Trading,
+    PreTrading,
+    Halted,
+    Delisted,
+}
+
+impl InstrumentSpec {
+    fn default_contract_multiplier() -> Decimal {
+        Decimal::ONE
+    }
+
+    /// Rounds the provided price to the nearest multiple of the `tick_size`.
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        round_to_increment(price, self.tick_size, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds the provided quantity towards zero to a multiple of the `lot_size`, preserving it's
+    /// sign. Rounding towards zero guarantees an order never exceeds the intended size.
+    pub fn round_quantity(&self, quantity: Decimal) -> Decimal {
+        round_to_increment(quantity, self.lot_size, RoundingStrategy::ToZero)
+    }
+
+    /// Calculates the notional value of an order in the quote currency, or in the settlement
+    /// currency for quanto contracts.
+    ///
+    /// Linear & Quanto: |quantity| * price * contract_multiplier
+    /// Inverse: |quantity| * contract_multiplier
+    pub fn notional(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        match self.settlement {
+            Settlement::Linear | Settlement::Quanto => {
+                quantity.abs() * price * self.contract_multiplier
+            }
+            Settlement::Inverse => quantity.abs() * self.contract_multiplier,
+        }
+    }
+
+    /// Validates that an order with the provided price & signed quantity conforms to the trading
+    /// rules of this [`InstrumentSpec`].
+    pub fn validate_order(&self, price: Decimal, quantity: Decimal) -> Result<(), InstrumentError> {
+        if self.status != InstrumentStatus::Trading {
+            return Err(InstrumentError::NotTrading(self.status));
+        }
+
+        if !is_multiple_of(price, self.tick_size) {
+            return Err(InstrumentError::InvalidTickSize {
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+
+        if !is_multiple_of(quantity, self.lot_size) {
+            return Err(InstrumentError::InvalidLotSize {
+                quantity,
+                lot_size: self.lot_size,
+            });
+        }
+
+        let size = quantity.abs();
+        if size.is_zero() || size < self.min_quantity {
+            return Err(InstrumentError::BelowMinQuantity {
+                quantity,
+                min_quantity: self.min_quantity,
+            });
+        }
+
+        if let Some(max_quantity) = self.max_quantity {
+            if size > max_quantity {
+                return Err(InstrumentError::AboveMaxQuantity {
+                    quantity,
+                    max_quantity,
+                });
+            }
+        }
+
+        if let Some(min_notional) = self.min_notional {
+            let notional = self.notional(price, quantity);
+            if notional < min_notional {
+                return Err(InstrumentError::BelowMinNotional {
+                    notional,
+                    min_notional,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rounds the provided price & quantity to valid increments, and validates the result.
+    /// Returns the (price, quantity) that should be sent to the exchange.
+    pub fn normalise_order(
+        &self,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Result<(Decimal, Decimal), InstrumentError> {
+        let price = self.round_price(price);
+        let quantity = self.round_quantity(quantity);
+        self.validate_order(price, quantity)?;
+        Ok((price, quantity))
+    }
+}
+
+/// Rounds the value to a multiple of the increment using the provided [`RoundingStrategy`]. A
+/// non-positive increment leaves the value unchanged.
+fn round_to_increment(value: Decimal, increment: Decimal, strategy: RoundingStrategy) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+
+    (value / increment).round_dp_with_strategy(0, strategy) * increment
+}
+
+/// Determines if the value is an exact multiple of the increment. A non-positive increment accepts
+/// any value.
+fn is_multiple_of(value: Decimal, increment: Decimal) -> bool {
+    increment <= Decimal::ZERO || (value % increment).is_zero()
+}
+
+/// Registry of [`InstrumentSpec`]s keyed by [`MarketId`].
+///
+/// Can be loaded from a JSON file, a JSON string, or any exchange-info [`RestRequest`] whose
+/// response yields [`InstrumentSpec`]s.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(from = "Vec<InstrumentSpec>", into = "Vec<InstrumentSpec>")]
+pub struct InstrumentRegistry {
+    specs: HashMap<MarketId, InstrumentSpec>,
+}
+
+impl From<Vec<InstrumentSpec>> for InstrumentRegistry {
+    fn from(specs: Vec<InstrumentSpec>) -> Self {
+        specs.into_iter().collect()
+    }
+}
+
+impl From<InstrumentRegistry> for Vec<InstrumentSpec> {
+    fn from(registry: InstrumentRegistry) -> Self {
+        let mut specs = registry.specs.into_values().collect::<Vec<_>>();
+        specs.sort_by(|a, b| a.market.cmp(&b.market));
+        specs
+    }
+}
+
+impl FromIterator<InstrumentSpec> for InstrumentRegistry {
+    fn from_iter<Iter: IntoIterator<Item = InstrumentSpec>>(iter: Iter) -> Self {
+        let mut registry = Self::default();
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl Extend<InstrumentSpec> for InstrumentRegistry {
+    fn extend<Iter: IntoIterator<Item = InstrumentSpec>>(&mut self, iter: Iter) {
+        for spec in iter {
+            self.insert(spec);
+        }
+    }
+}
+
+impl InstrumentRegistry {
+    /// Constructs a new empty [`InstrumentRegistry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deserialises an [`InstrumentRegistry`] from a JSON array of [`InstrumentSpec`]s.
+    pub fn from_json_str(json: &str) -> Result<Self, InstrumentError> {
+        serde_json::from_str(json).map_err(InstrumentError::from)
+    }
+
+    /// Reads & deserialises an [`InstrumentRegistry`] from a JSON file containing an array of
+    /// [`InstrumentSpec`]s.
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, InstrumentError> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json_str(&json)
+    }
+
+    /// Executes the provided exchange-info [`RestRequest`] and inserts every [`InstrumentSpec`]
+    /// yielded by the response. Returns the number of [`InstrumentSpec`]s inserted.
+    pub async fn fetch<Strategy, Parser, Request>(
+        &mut self,
+        client: &RestClient<'_, Strategy, Parser>,
+        request: Request,
+    ) -> Result<usize, Parser::OutputError>
+    where
+        Strategy: BuildStrategy,
+        Parser: HttpParser,
+        Request: RestRequest,
+        Request::Response: IntoIterator<Item = InstrumentSpec>,
+    {
+        let len_before = self.specs.len();
+        self.extend(client.execute(request).await?);
+        Ok(self.specs.len() - len_before)
+    }
+
+    /// Inserts an [`InstrumentSpec`], returning the previous [`InstrumentSpec`] for the same
+    /// [`Market`] if one was present.
+    pub fn insert(&mut self, spec: InstrumentSpec) -> Option<InstrumentSpec> {
+        self.specs.insert(MarketId::from(&spec.market), spec)
+    }
+
+    /// Returns the [`InstrumentSpec`] associated with the provided [`MarketId`], if present.
+    pub fn get(&self, market_id: &MarketId) -> Option<&InstrumentSpec> {
+        self.specs.get(market_id)
+    }
+
+    /// Returns the [`InstrumentSpec`] associated with the provided [`Exchange`] & [`Instrument`].
+    pub fn find(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Result<&InstrumentSpec, InstrumentError> {
+        let market_id = MarketId::new(exchange, instrument);
+        self.specs
+            .get(&market_id)
+            .ok_or(InstrumentError::NotFound(market_id))
+    }
+
+    /// Iterator over all [`InstrumentSpec`]s in the registry.
+    pub fn iter(&self) -> impl Iterator<Item = &InstrumentSpec> {
+        self.specs.values()
+    }
+
+    /// Number of [`InstrumentSpec`]s in the registry.
+    pub fn len(&self) -> usize {
+        self.specs.len()
+    }
+
+    /// Determines if the registry contains no [`InstrumentSpec`]s.
+    pub fn is_empty(&self) -> bool {
+        self.specs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+    use rust_decimal_macros::dec;
+
+    fn spec(settlement: Settlement) -> InstrumentSpec {
+        InstrumentSpec {
+            market: Market::from(("binance_spot", "btc", "usdt", InstrumentKind::Spot)),
+            tick_size: dec!(0.01),
+            lot_size: dec!(0.001),
+            min_quantity: dec!(0.001),
+            max_quantity: Some(dec!(100)),
+            min_notional: Some(dec!(10)),
+            contract_multiplier: Decimal::ONE,
+            settlement,
+            status: InstrumentStatus::Trading,
+        }
+    }
+
+    #[test]
+    fn test_round_price_and_quantity() {
+        let spec = spec(Settlement::Linear);
+
+        assert_eq!(spec.round_price(dec!(100.004)), dec!(100.00));
+        assert_eq!(spec.round_price(dec!(100.006)), dec!(100.01));
+        assert_eq!(spec.round_quantity(dec!(0.0129)), dec!(0.012));
+        assert_eq!(spec.round_quantity(dec!(-0.0129)), dec!(-0.012));
+        assert_eq!(spec.round_quantity(dec!(0.0009)), dec!(0));
+    }
+
+    #[test]
+    fn test_validate_order() {
+        struct TestCase {
+            spec: InstrumentSpec,
+            price: Decimal,
+            quantity: Decimal,
+            expected: Result<(), InstrumentError>,
+        }
+
+        let mut halted = spec(Settlement::Linear);
+        halted.status = InstrumentStatus::Halted;
+
+        let mut inverse = spec(Settlement::Inverse);
+        inverse.contract_multiplier = dec!(100);
+        inverse.min_notional = Some(dec!(200));
+
+        let cases = vec![
+            TestCase {
+                // TC0: Valid order
+                spec: spec(Settlement::Linear),
+                price: dec!(20000.01),
+                quantity: dec!(-0.5),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Instrument not trading
+                spec: halted,
+                price: dec!(20000.01),
+                quantity: dec!(0.5),
+                expected: Err(InstrumentError::NotTrading(InstrumentStatus::Halted)),
+            },
+            TestCase {
+                // TC2: Price not a multiple of tick size
+                spec: spec(Settlement::Linear),
+                price: dec!(20000.015),
+                quantity: dec!(0.5),
+                expected: Err(InstrumentError::InvalidTickSize {
+                    price: dec!(20000.015),
+                    tick_size: dec!(0.01),
+                }),
+            },
+            TestCase {
+                // TC3: Quantity not a multiple of lot size
+                spec: spec(Settlement::Linear),
+                price: dec!(20000),
+                quantity: dec!(0.5005),
+                expected: Err(InstrumentError::InvalidLotSize {
+                    quantity: dec!(0.5005),
+                    lot_size: dec!(0.001),
+                }),
+            },
+            TestCase {
+                // TC4: Quantity above max quantity
+                spec: spec(Settlement::Linear),
+                price: dec!(20000),
+                quantity: dec!(101),
+                expected: Err(InstrumentError::AboveMaxQuantity {
+                    quantity: dec!(101),
+                    max_quantity: dec!(100),
+                }),
+            },
+            TestCase {
+                // TC5: Linear notional below min notional
+                spec: spec(Settlement::Linear),
+                price: dec!(100),
+                quantity: dec!(0.05),
+                expected: Err(InstrumentError::BelowMinNotional {
+                    notional: dec!(5.00),
+                    min_notional: dec!(10),
+                }),
+            },
+            TestCase {
+                // TC6: Inverse notional is independent of price
+                spec: inverse,
+                price: dec!(100),
+                quantity: dec!(1),
+                expected: Err(InstrumentError::BelowMinNotional {
+                    notional: dec!(100),
+                    min_notional: dec!(200),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.spec.validate_order(test.price, test.quantity);
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {
+                    // Test passed
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual.to_string(), expected.to_string(), "TC{index} failed")
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_instrument_registry() {
+        let input = r#"[
+            {
+                "exchange": "binance_futures_usd",
+                "base": "btc",
+                "quote": "usdt",
+                "instrument_kind": "perpetual",
+                "tick_size": "0.10",
+                "lot_size": "0.001",
+                "min_notional": 5
+            },
+            {
+                "exchange": "okx",
+                "base": "btc",
+                "quote": "usd",
+                "instrument_kind": "perpetual",
+                "tick_size": 0.1,
+                "lot_size": 1,
+                "contract_multiplier": 100,
+                "settlement": "inverse",
+                "status": "halted"
+            }
+        ]"#;
+
+        let registry = InstrumentRegistry::from_json_str(input).unwrap();
+        assert_eq!(registry.len(), 2);
+
+        let linear = registry
+            .find(
+                &Exchange::from("binance_futures_usd"),
+                &Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            )
+            .unwrap();
+        assert_eq!(linear.contract_multiplier, Decimal::ONE);
+        assert_eq!(linear.settlement, Settlement::Linear);
+        assert_eq!(linear.status, InstrumentStatus::Trading);
+        assert_eq!(linear.min_notional, Some(dec!(5)));
+
+        let inverse = registry
+            .get(&MarketId("okx_btc_usd_perpetual".to_string()))
+            .unwrap();
+        assert_eq!(inverse.contract_multiplier, dec!(100));
+        assert_eq!(inverse.settlement, Settlement::Inverse);
+        assert_eq!(inverse.status, InstrumentStatus::Halted);
+
+        let round_trip = serde_json::to_string(&registry).unwrap();
+        assert_eq!(
+            InstrumentRegistry::from_json_str(&round_trip).unwrap(),
+            registry
+        );
+    }
+}
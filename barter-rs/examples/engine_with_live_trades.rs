@@ -24,6 +24,7 @@ use barter_data::{
 };
 use barter_integration::model::{instrument::kind::InstrumentKind, Market};
 use parking_lot::Mutex;
+use rust_decimal_macros::dec;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -50,10 +51,10 @@ async fn main() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(dec!(10_000.0))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100.0),
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -82,9 +83,9 @@ async fn main() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: dec!(0.1),
+                    slippage: dec!(0.05),
+                    network: dec!(0.0),
                 },
             }))
             .build()
@@ -110,9 +111,10 @@ async fn main() {
         .expect("failed to build engine");
 
     // Run Engine trading & listen to Events it produces
+    // '--> live Traders run as asynchronous tasks rather than on dedicated threads
     tokio::spawn(listen_to_engine_events(event_rx));
 
-    let _ = tokio::time::timeout(ENGINE_RUN_TIMEOUT, engine.run()).await;
+    let _ = tokio::time::timeout(ENGINE_RUN_TIMEOUT, engine.run_async()).await;
 }
 
 async fn stream_market_event_trades() -> mpsc::UnboundedReceiver<MarketEvent<DataKind>> {
@@ -199,6 +201,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::EngineError(engine_error) => {
+                // Trader stage failure & the ErrorAction taken occurred in Engine
+                println!("{engine_error:?}");
+            }
         }
     }
 }
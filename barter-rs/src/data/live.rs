@@ -1,21 +1,36 @@
 use super::{Feed, MarketGenerator};
+use futures::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
 use tokio::sync::mpsc;
 
 /// Live [`Feed`] of market events.
+///
+/// Implements [`MarketGenerator`] for the synchronous [`Trader::run`](crate::engine::trader::Trader::run),
+/// as well as [`Stream`] for the asynchronous
+/// [`Trader::run_async`](crate::engine::trader::Trader::run_async).
 #[derive(Debug)]
 pub struct MarketFeed<Event> {
     pub market_rx: mpsc::UnboundedReceiver<Event>,
 }
 
 impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
+    /// Blocks the current thread until the next market `Event` is received. Must not be called
+    /// from within an asynchronous execution context.
     fn next(&mut self) -> Feed<Event> {
-        loop {
-            match self.market_rx.try_recv() {
-                Ok(event) => break Feed::Next(event),
-                Err(mpsc::error::TryRecvError::Empty) => continue,
-                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
-            }
-        }
+        self.market_rx
+            .blocking_recv()
+            .map_or(Feed::Finished, Feed::Next)
+    }
+}
+
+impl<Event> Stream for MarketFeed<Event> {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.market_rx.poll_recv(cx)
     }
 }
 
@@ -1,4 +1,7 @@
+use crate::event::EventTrace;
+use barter_data::event::DataKind;
 use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 /// Barter data module specific errors.
@@ -30,16 +33,77 @@ pub enum Feed<Event> {
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct MarketMeta {
     /// Close value from the source market event.
-    pub close: f64,
+    pub close: Decimal,
     /// Exchange timestamp from the source market event.
     pub time: DateTime<Utc>,
+    /// Correlation identifier & latency timestamps of the source market event, stamped by the
+    /// [`Trader`](crate::engine::trader::Trader). `None` for Events that do not originate from a
+    /// market event (eg/ forced exit orders).
+    #[serde(default)]
+    pub trace: Option<EventTrace>,
+    /// Members of an [`Ensemble`](crate::strategy::ensemble::Ensemble) strategy that contributed
+    /// to the source [`Signal`](crate::strategy::Signal). `None` if it was not generated by an
+    /// [`Ensemble`](crate::strategy::ensemble::Ensemble).
+    #[serde(default)]
+    pub attribution: Option<Attribution>,
+}
+
+/// Determine the close price of a market from a [`DataKind`] - the trade price, the order book mid
+/// price, or the candle close. Returns `None` for liquidations, empty order books & non-finite
+/// candle closes.
+pub fn market_close(kind: &DataKind) -> Option<Decimal> {
+    match kind {
+        DataKind::Trade(trade) => Some(trade.price),
+        DataKind::OrderBookL1(book_l1) => Some(book_l1.mid_price()),
+        DataKind::OrderBook(book) => book.mid_price(),
+        DataKind::Candle(candle) => Decimal::from_f64(candle.close),
+        DataKind::Liquidation(_) => None,
+    }
 }
 
 impl Default for MarketMeta {
     fn default() -> Self {
         Self {
-            close: 100.0,
+            close: Decimal::ONE_HUNDRED,
             time: Utc::now(),
+            trace: None,
+            attribution: None,
         }
     }
 }
+
+/// Records which [`Ensemble`](crate::strategy::ensemble::Ensemble) members contributed to a
+/// [`Signal`](crate::strategy::Signal), as a bit set of member indices. Propagated via the
+/// [`MarketMeta`] to the resulting [`Position`](crate::portfolio::position::Position) so
+/// per-strategy PnL can be tracked, see
+/// [`AttributionSummary`](crate::statistic::summary::attribution::AttributionSummary).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct Attribution(pub u64);
+
+impl Attribution {
+    /// Maximum number of [`Ensemble`](crate::strategy::ensemble::Ensemble) members an
+    /// [`Attribution`] can represent.
+    pub const MAX_MEMBERS: usize = u64::BITS as usize;
+
+    /// Record that the member at the provided index contributed.
+    pub fn insert(&mut self, index: usize) {
+        self.0 |= 1 << index;
+    }
+
+    /// Determines if the member at the provided index contributed.
+    pub fn contains(&self, index: usize) -> bool {
+        index < Self::MAX_MEMBERS && self.0 & (1 << index) != 0
+    }
+
+    /// Returns the index of every member that contributed, in ascending order.
+    pub fn members(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..Self::MAX_MEMBERS).filter(|index| self.contains(*index))
+    }
+
+    /// Determines if no members contributed.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
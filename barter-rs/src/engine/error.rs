@@ -1,4 +1,9 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{
+    engine::policy::TraderStage,
+    execution::error::ExecutionError,
+    portfolio::{error::PortfolioError, repository::error::RepositoryError},
+};
+use barter_integration::model::Market;
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -9,4 +14,67 @@ pub enum EngineError {
 
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Trader {stage:?} stage failed for market {market:?}: {source}")]
+    TraderStage {
+        market: Box<Market>,
+        stage: TraderStage,
+        #[source]
+        source: TraderStageError,
+    },
+
+    #[error("Failed to interact with Portfolio")]
+    PortfolioInteraction(#[from] PortfolioError),
+
+    #[error("Failed to serialise Statistics: {0}")]
+    StatisticSerialisation(#[from] serde_json::Error),
+
+    #[error("Engine has no Trader for market: {0:?}")]
+    MarketNotFound(Market),
+
+    #[error("Engine already has a Trader for market: {0:?}")]
+    MarketAlreadyTraded(Market),
+
+    #[error("Trader for market {0:?} has stopped")]
+    TraderStopped(Market),
+
+    #[error("Timed out waiting for the Trader of market {0:?} to stop")]
+    TraderStopTimeout(Market),
+
+    #[error("Trader for market {0:?} is already being removed")]
+    MarketRemovalPending(Market),
+
+    #[error("Engine has no TraderFactory to construct Traders at runtime")]
+    TraderFactoryMissing,
+}
+
+impl EngineError {
+    /// Returns the [`TraderStage`] that failed, if the error originated in a
+    /// [`Trader`](super::trader::Trader) event loop.
+    pub fn stage(&self) -> Option<TraderStage> {
+        match self {
+            Self::TraderStage { stage, .. } => Some(*stage),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Market`] of the [`Trader`](super::trader::Trader) that failed, if the error
+    /// originated in a [`Trader`](super::trader::Trader) event loop.
+    pub fn market(&self) -> Option<&Market> {
+        match self {
+            Self::TraderStage { market, .. } => Some(market),
+            _ => None,
+        }
+    }
+}
+
+/// Errors generated by the components a [`Trader`](super::trader::Trader) drives in each
+/// [`TraderStage`].
+#[derive(Error, Debug)]
+pub enum TraderStageError {
+    #[error(transparent)]
+    Portfolio(#[from] PortfolioError),
+
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
 }
@@ -0,0 +1,57 @@
+use crate::{engine::error::EngineError, portfolio::error::PortfolioError};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use thiserror::Error;
+
+/// All errors generated in the barter::engine::http module.
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("Engine is not running")]
+    EngineUnavailable,
+
+    #[error(transparent)]
+    Engine(#[from] EngineError),
+
+    #[error("Failed to bind HTTP server: {0}")]
+    Bind(#[from] std::io::Error),
+
+    #[error("HTTP server failed: {0}")]
+    Server(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Missing or invalid bearer token")]
+    Unauthorised,
+
+    #[error("Refusing to serve on non-loopback address {0} without a bearer token")]
+    InsecureAddress(std::net::SocketAddr),
+}
+
+impl ApiError {
+    /// Determines the HTTP [`StatusCode`] that communicates this [`ApiError`] to a client.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::EngineUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Engine(EngineError::MarketNotFound(_)) => StatusCode::NOT_FOUND,
+            Self::Engine(EngineError::MarketAlreadyTraded(_) | EngineError::TraderStopped(_)) => {
+                StatusCode::CONFLICT
+            }
+            Self::Engine(EngineError::TraderFactoryMissing) => StatusCode::NOT_IMPLEMENTED,
+            Self::Engine(EngineError::TraderStopTimeout(_)) => StatusCode::GATEWAY_TIMEOUT,
+            Self::Engine(EngineError::PortfolioInteraction(PortfolioError::Configuration(_))) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::Unauthorised => StatusCode::UNAUTHORIZED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = Json(json!({ "error": self.to_string() }));
+        (self.status_code(), body).into_response()
+    }
+}
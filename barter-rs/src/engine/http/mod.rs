@@ -0,0 +1,568 @@
+use crate::{
+    engine::{error::EngineError, http::error::ApiError, Command},
+    event::EventBroadcastTx,
+    portfolio::{position::Position, Balance},
+};
+use axum::{
+    extract::State,
+    http::{
+        header::{AUTHORIZATION, CONTENT_TYPE},
+        Request, StatusCode,
+    },
+    middleware::{self, Next},
+    response::{
+        sse::{Event as SseEvent, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post, put},
+    Json, Router,
+};
+use barter_integration::{
+    metric::prometheus::{self, PrometheusExporter},
+    model::{Market, MarketId},
+};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, TcpListener},
+};
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tracing::info;
+
+/// Barter HTTP API module specific errors.
+pub mod error;
+
+/// Body of a `POST /terminate` request.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct TerminateRequest {
+    /// Reason for terminating the [`Engine`](super::Engine), communicated to every
+    /// [`Trader`](super::trader::Trader).
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Embedded HTTP/JSON API for controlling & monitoring a running [`Engine`](super::Engine).
+/// Every [`Command`] is exposed as a REST endpoint, and live [`Event`](crate::event::Event)s
+/// are streamed as server-sent events:
+///
+/// | Method & Path             | Body        | [`Command`]                       |
+/// |---------------------------|-------------|-----------------------------------|
+/// | `GET /positions`          |             | [`Command::FetchOpenPositions`]   |
+/// | `POST /positions/exit`    |             | [`Command::ExitAllPositions`]     |
+/// | `GET /balance`            |             | [`Command::FetchBalance`]         |
+/// | `GET /statistics`         |             | [`Command::FetchStatistics`]      |
+/// | `POST /markets/add`       | [`Market`]  | [`Command::AddMarket`]            |
+/// | `POST /markets/remove`    | [`Market`]  | [`Command::RemoveMarket`]         |
+/// | `POST /markets/pause`     | [`Market`]  | [`Command::PauseMarket`]          |
+/// | `POST /markets/resume`    | [`Market`]  | [`Command::ResumeMarket`]         |
+/// | `POST /markets/exit`      | [`Market`]  | [`Command::ExitPosition`]         |
+/// | `PUT /config/allocator`   | JSON config | [`Command::ConfigureAllocator`]   |
+/// | `PUT /config/risk`        | JSON config | [`Command::ConfigureRisk`]        |
+/// | `POST /terminate`         | [`TerminateRequest`] | [`Command::Terminate`]   |
+/// | `GET /events`             |             | `text/event-stream` of [`Event`](crate::event::Event)s |
+/// | `GET /metrics`            |             | Prometheus text exposition, see [`HttpApi::metrics`] |
+///
+/// Failures are returned as `{"error": "<description>"}` with a status code determined by the
+/// [`ApiError`].
+///
+/// If a bearer token is configured (see [`HttpApi::auth_token`]) every endpoint requires an
+/// `Authorization: Bearer <token>` header. Without one the [`HttpApi`] only serves on a loopback
+/// address.
+#[derive(Debug, Clone)]
+pub struct HttpApi {
+    /// `mpsc::Sender` for sending [`Command`]s to the [`Engine`](super::Engine).
+    command_tx: mpsc::Sender<Command>,
+    /// [`EventBroadcastTx`] the [`Engine`](super::Engine)'s [`Trader`](super::trader::Trader)s
+    /// send their [`Event`](crate::event::Event)s to.
+    events: EventBroadcastTx,
+    /// Optional [`PrometheusExporter`] rendered by the `GET /metrics` endpoint.
+    metrics: Option<PrometheusExporter>,
+    /// Optional bearer token every request must be authorised with.
+    auth_token: Option<String>,
+}
+
+impl HttpApi {
+    /// Constructs a new [`HttpApi`] that controls the [`Engine`](super::Engine) via the provided
+    /// [`Command`] transmitter, and streams the [`Event`](crate::event::Event)s sent to the
+    /// provided [`EventBroadcastTx`].
+    pub fn new(command_tx: mpsc::Sender<Command>, events: EventBroadcastTx) -> Self {
+        Self {
+            command_tx,
+            events,
+            metrics: None,
+            auth_token: None,
+        }
+    }
+
+    /// Require every request to be authorised with the provided bearer token, via an
+    /// `Authorization: Bearer <token>` header. Enables serving on a non-loopback address.
+    pub fn auth_token(self, token: String) -> Self {
+        Self {
+            auth_token: Some(token),
+            ..self
+        }
+    }
+
+    /// Serve the metric families aggregated by the provided [`PrometheusExporter`] from a
+    /// `GET /metrics` endpoint for Prometheus to scrape.
+    pub fn metrics(self, exporter: PrometheusExporter) -> Self {
+        Self {
+            metrics: Some(exporter),
+            ..self
+        }
+    }
+
+    /// Constructs the [`Router`] serving every [`HttpApi`] endpoint. Useful for nesting the
+    /// [`HttpApi`] within an existing axum application.
+    pub fn router(self) -> Router {
+        let router = match self.metrics {
+            Some(_) => Router::new().route("/metrics", get(render_metrics)),
+            None => Router::new(),
+        };
+
+        router
+            .route("/positions", get(fetch_open_positions))
+            .route("/positions/exit", post(exit_all_positions))
+            .route("/balance", get(fetch_balance))
+            .route("/statistics", get(fetch_statistics))
+            .route("/markets/add", post(add_market))
+            .route("/markets/remove", post(remove_market))
+            .route("/markets/pause", post(pause_market))
+            .route("/markets/resume", post(resume_market))
+            .route("/markets/exit", post(exit_position))
+            .route("/config/allocator", put(configure_allocator))
+            .route("/config/risk", put(configure_risk))
+            .route("/terminate", post(terminate))
+            .route("/events", get(stream_events))
+            .route_layer(middleware::from_fn_with_state(self.clone(), authorise))
+            .with_state(self)
+    }
+
+    /// Bind to the provided [`SocketAddr`] & serve the [`HttpApi`] until the server fails.
+    ///
+    /// Non-loopback addresses are refused unless a bearer token is configured.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), ApiError> {
+        self.check_address(addr)?;
+        self.serve_listener(TcpListener::bind(addr)?).await
+    }
+
+    /// Serve the [`HttpApi`] on an already bound [`TcpListener`] until the server fails.
+    ///
+    /// Listeners bound to non-loopback addresses are refused unless a bearer token is configured.
+    pub async fn serve_listener(self, listener: TcpListener) -> Result<(), ApiError> {
+        self.check_address(listener.local_addr()?)?;
+
+        info!(addr = ?listener.local_addr(), "serving Engine HTTP API");
+
+        axum::Server::from_tcp(listener)
+            .map_err(|error| ApiError::Server(Box::new(error)))?
+            .serve(self.router().into_make_service())
+            .await
+            .map_err(|error| ApiError::Server(Box::new(error)))
+    }
+
+    /// Refuses to expose the unauthenticated [`HttpApi`] beyond the local machine.
+    fn check_address(&self, addr: SocketAddr) -> Result<(), ApiError> {
+        match self.auth_token.is_none() && !addr.ip().is_loopback() {
+            true => Err(ApiError::InsecureAddress(addr)),
+            false => Ok(()),
+        }
+    }
+
+    /// Sends a [`Command`] to the [`Engine`](super::Engine) & awaits it's reply.
+    async fn request<T, Build>(&self, build_command: Build) -> Result<T, ApiError>
+    where
+        Build: FnOnce(oneshot::Sender<Result<T, EngineError>>) -> Command,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.send(build_command(reply_tx)).await?;
+
+        reply_rx
+            .await
+            .map_err(|_| ApiError::EngineUnavailable)?
+            .map_err(ApiError::from)
+    }
+
+    /// Sends a [`Command`] to the [`Engine`](super::Engine) that does not reply.
+    async fn send(&self, command: Command) -> Result<(), ApiError> {
+        self.command_tx
+            .send(command)
+            .await
+            .map_err(|_| ApiError::EngineUnavailable)
+    }
+}
+
+/// Rejects requests without the configured bearer token, if the [`HttpApi`] has one.
+async fn authorise<Body>(
+    State(api): State<HttpApi>,
+    request: Request<Body>,
+    next: Next<Body>,
+) -> Result<Response, ApiError> {
+    if let Some(token) = &api.auth_token {
+        let authorised = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|provided| constant_time_eq(provided.as_bytes(), token.as_bytes()));
+
+        if !authorised {
+            return Err(ApiError::Unauthorised);
+        }
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Compares the provided byte slices in time independent of where they first differ, so a
+/// bearer token cannot be guessed byte by byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+async fn fetch_open_positions(State(api): State<HttpApi>) -> Result<Json<Vec<Position>>, ApiError> {
+    api.request(Command::FetchOpenPositions).await.map(Json)
+}
+
+async fn exit_all_positions(State(api): State<HttpApi>) -> Result<StatusCode, ApiError> {
+    api.send(Command::ExitAllPositions).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn fetch_balance(State(api): State<HttpApi>) -> Result<Json<Balance>, ApiError> {
+    api.request(Command::FetchBalance).await.map(Json)
+}
+
+/// Statistics are keyed by [`MarketId`] since JSON object keys must be strings.
+async fn fetch_statistics(
+    State(api): State<HttpApi>,
+) -> Result<Json<HashMap<String, serde_json::Value>>, ApiError> {
+    let statistics = api.request(Command::FetchStatistics).await?;
+
+    Ok(Json(
+        statistics
+            .into_iter()
+            .map(|(market, statistics)| (MarketId::from(&market).0, statistics))
+            .collect(),
+    ))
+}
+
+async fn add_market(
+    State(api): State<HttpApi>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::AddMarket(market, reply_tx))
+        .await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn remove_market(
+    State(api): State<HttpApi>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::RemoveMarket(market, reply_tx))
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+async fn pause_market(
+    State(api): State<HttpApi>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::PauseMarket(market, reply_tx))
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+async fn resume_market(
+    State(api): State<HttpApi>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::ResumeMarket(market, reply_tx))
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+async fn exit_position(
+    State(api): State<HttpApi>,
+    Json(market): Json<Market>,
+) -> Result<StatusCode, ApiError> {
+    api.send(Command::ExitPosition(market)).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn configure_allocator(
+    State(api): State<HttpApi>,
+    Json(config): Json<serde_json::Value>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::ConfigureAllocator(config, reply_tx))
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+async fn configure_risk(
+    State(api): State<HttpApi>,
+    Json(config): Json<serde_json::Value>,
+) -> Result<StatusCode, ApiError> {
+    api.request(|reply_tx| Command::ConfigureRisk(config, reply_tx))
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+async fn terminate(
+    State(api): State<HttpApi>,
+    request: Option<Json<TerminateRequest>>,
+) -> Result<StatusCode, ApiError> {
+    let message = request
+        .and_then(|Json(request)| request.message)
+        .unwrap_or_else(|| "terminated via HTTP API".to_owned());
+
+    api.send(Command::Terminate(message)).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn render_metrics(State(api): State<HttpApi>) -> impl IntoResponse {
+    let metrics = api
+        .metrics
+        .map(|exporter| exporter.render())
+        .unwrap_or_default();
+
+    ([(CONTENT_TYPE, prometheus::CONTENT_TYPE)], metrics)
+}
+
+/// Streams every [`Event`](crate::event::Event) as a JSON server-sent event. Subscribers that fall
+/// behind receive a `lagged` event communicating how many [`Event`](crate::event::Event)s they
+/// missed.
+async fn stream_events(
+    State(api): State<HttpApi>,
+) -> Sse<impl Stream<Item = Result<SseEvent, serde_json::Error>>> {
+    let events = BroadcastStream::new(api.events.subscribe()).map(|event| match event {
+        Ok(event) => SseEvent::default().json_data(event),
+        Err(BroadcastStreamRecvError::Lagged(skipped)) => Ok(SseEvent::default()
+            .event("lagged")
+            .data(skipped.to_string())),
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use tower::ServiceExt;
+
+    fn api() -> (HttpApi, mpsc::Receiver<Command>) {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        (
+            HttpApi::new(command_tx, EventBroadcastTx::new(10)),
+            command_rx,
+        )
+    }
+
+    /// Sends a request to the [`HttpApi`] router & returns the response status.
+    async fn request(
+        api: HttpApi,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+        token: Option<&str>,
+    ) -> StatusCode {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+
+        let request = match body {
+            Some(body) => request
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        api.router().oneshot(request).await.unwrap().status()
+    }
+
+    /// Mock [`Engine`](crate::engine::Engine) reply to a [`Command`], if it expects one.
+    fn reply_ok(command: Command) {
+        match command {
+            Command::FetchOpenPositions(reply_tx) => {
+                let _ = reply_tx.send(Ok(Vec::new()));
+            }
+            Command::FetchBalance(reply_tx) => {
+                let _ = reply_tx.send(Ok(Balance::new(Utc::now(), dec!(1.0), dec!(1.0))));
+            }
+            Command::FetchStatistics(reply_tx) => {
+                let _ = reply_tx.send(Ok(HashMap::new()));
+            }
+            Command::AddMarket(_, reply_tx)
+            | Command::RemoveMarket(_, reply_tx)
+            | Command::PauseMarket(_, reply_tx)
+            | Command::ResumeMarket(_, reply_tx)
+            | Command::ConfigureAllocator(_, reply_tx)
+            | Command::ConfigureRisk(_, reply_tx) => {
+                let _ = reply_tx.send(Ok(()));
+            }
+            _ => {}
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_are_mapped_to_engine_commands() {
+        struct TestCase {
+            method: &'static str,
+            uri: &'static str,
+            body: Option<serde_json::Value>,
+            expected_status: StatusCode,
+            expected_command: fn(&Command) -> bool,
+        }
+
+        let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        let market_json = serde_json::to_value(&market).unwrap();
+        let config = serde_json::json!({ "default_order_value": 250.0 });
+
+        let cases = vec![
+            TestCase {
+                method: "GET",
+                uri: "/positions",
+                body: None,
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::FetchOpenPositions(_)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/positions/exit",
+                body: None,
+                expected_status: StatusCode::ACCEPTED,
+                expected_command: |command| matches!(command, Command::ExitAllPositions),
+            },
+            TestCase {
+                method: "GET",
+                uri: "/balance",
+                body: None,
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::FetchBalance(_)),
+            },
+            TestCase {
+                method: "GET",
+                uri: "/statistics",
+                body: None,
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::FetchStatistics(_)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/markets/add",
+                body: Some(market_json.clone()),
+                expected_status: StatusCode::CREATED,
+                expected_command: |command| match command {
+                    Command::AddMarket(market, _) => market.exchange == Exchange::from("binance"),
+                    _ => false,
+                },
+            },
+            TestCase {
+                method: "POST",
+                uri: "/markets/remove",
+                body: Some(market_json.clone()),
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::RemoveMarket(..)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/markets/pause",
+                body: Some(market_json.clone()),
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::PauseMarket(..)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/markets/resume",
+                body: Some(market_json.clone()),
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::ResumeMarket(..)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/markets/exit",
+                body: Some(market_json),
+                expected_status: StatusCode::ACCEPTED,
+                expected_command: |command| matches!(command, Command::ExitPosition(_)),
+            },
+            TestCase {
+                method: "PUT",
+                uri: "/config/allocator",
+                body: Some(config.clone()),
+                expected_status: StatusCode::OK,
+                expected_command: |command| match command {
+                    Command::ConfigureAllocator(config, _) => {
+                        config["default_order_value"] == 250.0
+                    }
+                    _ => false,
+                },
+            },
+            TestCase {
+                method: "PUT",
+                uri: "/config/risk",
+                body: Some(config),
+                expected_status: StatusCode::OK,
+                expected_command: |command| matches!(command, Command::ConfigureRisk(..)),
+            },
+            TestCase {
+                method: "POST",
+                uri: "/terminate",
+                body: Some(serde_json::json!({ "message": "maintenance" })),
+                expected_status: StatusCode::ACCEPTED,
+                expected_command: |command| matches!(command, Command::Terminate(message) if message == "maintenance"),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (api, mut command_rx) = api();
+
+            // Mock Engine that replies to the first Command it receives
+            let engine = tokio::spawn(async move {
+                let command = command_rx.recv().await.unwrap();
+                let matched = (test.expected_command)(&command);
+                reply_ok(command);
+                matched
+            });
+
+            let status = request(api, test.method, test.uri, test.body, None).await;
+            assert_eq!(status, test.expected_status, "TC{index} failed");
+            assert!(engine.await.unwrap(), "TC{index} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_without_configured_bearer_token_are_unauthorised() {
+        let (api, mut command_rx) = api();
+        let api = api.auth_token("secret".to_owned());
+
+        for token in [None, Some("wrong"), Some("secre")] {
+            let status = request(api.clone(), "POST", "/terminate", None, token).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+        assert!(command_rx.try_recv().is_err());
+
+        let status = request(api, "POST", "/terminate", None, Some("secret")).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert!(matches!(command_rx.try_recv(), Ok(Command::Terminate(_))));
+    }
+
+    #[tokio::test]
+    async fn serve_refuses_non_loopback_address_without_bearer_token() {
+        let (api, _command_rx) = api();
+        let addr = SocketAddr::from(([0, 0, 0, 0], 0));
+
+        assert!(matches!(
+            api.serve(addr).await,
+            Err(ApiError::InsecureAddress(_))
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    portfolio::Balance,
+    statistic::{
+        decimal_to_f64,
+        metric::{drawdown::Drawdown, EquityPoint},
+    },
+};
+use barter_integration::{
+    metric::{Field, Metric, Tag},
+    model::{Market, MarketId},
+};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Default interval at which an [`Engine`](super::Engine) emits it's `portfolio` [`Metric`].
+pub const DEFAULT_PORTFOLIO_METRIC_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Records the performance & trading activity of a [`Trader`](super::trader::Trader) event loop.
+///
+/// A `trader` [`Metric`] is sent after every cycle that processes at least one
+/// [`Event`](crate::event::Event), containing the peak event queue depth, the loop latency &
+/// the number of orders, fills & rejected signals since the previous [`Metric`].
+#[derive(Debug)]
+pub(super) struct TraderMetrics {
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+    tags: Vec<Tag>,
+    cycle_start: Instant,
+    event_q_depth: usize,
+    orders: u64,
+    fills: u64,
+    rejections: u64,
+}
+
+impl TraderMetrics {
+    pub(super) fn new(
+        metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+        engine_id: Uuid,
+        market: &Market,
+    ) -> Self {
+        Self {
+            metric_tx,
+            tags: vec![
+                Tag::new("engine_id", engine_id.to_string()),
+                Tag::new("market", MarketId::from(market).0),
+            ],
+            cycle_start: Instant::now(),
+            event_q_depth: 0,
+            orders: 0,
+            fills: 0,
+            rejections: 0,
+        }
+    }
+
+    pub(super) fn start_cycle(&mut self) {
+        self.cycle_start = Instant::now();
+        self.event_q_depth = 0;
+    }
+
+    pub(super) fn observe_event_q(&mut self, depth: usize) {
+        self.event_q_depth = self.event_q_depth.max(depth);
+    }
+
+    pub(super) fn order(&mut self) {
+        self.orders += 1;
+    }
+
+    pub(super) fn fill(&mut self) {
+        self.fills += 1;
+    }
+
+    /// A [`Signal`](crate::strategy::Signal) the Portfolio did not turn into an order.
+    pub(super) fn rejection(&mut self) {
+        self.rejections += 1;
+    }
+
+    pub(super) fn finish_cycle(&mut self) {
+        if self.event_q_depth == 0 {
+            return;
+        }
+
+        let metric = Metric::new(
+            "trader",
+            self.tags.clone(),
+            [
+                Field::new("event_q_depth", self.event_q_depth as u64),
+                Field::new(
+                    "loop_latency_us",
+                    self.cycle_start.elapsed().as_micros() as u64,
+                ),
+                Field::new("orders_total", self.orders),
+                Field::new("fills_total", self.fills),
+                Field::new("rejections_total", self.rejections),
+            ],
+        );
+        send(&mut self.metric_tx, metric);
+
+        self.orders = 0;
+        self.fills = 0;
+        self.rejections = 0;
+    }
+}
+
+/// Records the equity, available cash & current drawdown of the global Portfolio as a `portfolio`
+/// [`Metric`], see [`DEFAULT_PORTFOLIO_METRIC_INTERVAL`].
+#[derive(Debug)]
+pub(super) struct PortfolioMetrics {
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+    pub(super) interval: Duration,
+    tags: Vec<Tag>,
+    drawdown: Option<Drawdown>,
+}
+
+impl PortfolioMetrics {
+    pub(super) fn new(
+        metric_tx: mpsc::UnboundedSender<Metric>,
+        interval: Duration,
+        engine_id: Uuid,
+    ) -> Self {
+        Self {
+            metric_tx: Some(metric_tx),
+            interval,
+            tags: vec![Tag::new("engine_id", engine_id.to_string())],
+            drawdown: None,
+        }
+    }
+
+    pub(super) fn record(&mut self, balance: Balance) {
+        // Equity peak is tracked from the first recorded Balance
+        let drawdown = self
+            .drawdown
+            .get_or_insert_with(|| Drawdown::init(decimal_to_f64(balance.total)));
+        drawdown.update(EquityPoint::from(balance));
+
+        let metric = Metric::new(
+            "portfolio",
+            self.tags.clone(),
+            [
+                Field::new("equity", decimal_to_f64(balance.total)),
+                Field::new("available_cash", decimal_to_f64(balance.available)),
+                Field::new("drawdown", drawdown.drawdown),
+            ],
+        );
+        send(&mut self.metric_tx, metric);
+    }
+}
+
+/// Send a [`Metric`], disabling the `mpsc::UnboundedSender` if the receiver has been dropped.
+fn send(metric_tx: &mut Option<mpsc::UnboundedSender<Metric>>, metric: Metric) {
+    if let Some(tx) = metric_tx {
+        if tx.send(metric).is_err() {
+            warn!(
+                why = "Metric receiver dropped",
+                action = "no longer emitting Metrics",
+                "failed to send Metric"
+            );
+            *metric_tx = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{metric::Value, model::instrument::kind::InstrumentKind};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn portfolio_metrics_tracks_drawdown_from_equity_peak() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let mut metrics = PortfolioMetrics::new(metric_tx, Duration::from_secs(1), Uuid::new_v4());
+
+        for total in [dec!(100.0), dec!(120.0), dec!(90.0)] {
+            metrics.record(Balance::new(Utc::now(), total, total));
+        }
+
+        let fields = std::iter::from_fn(|| metric_rx.try_recv().ok())
+            .last()
+            .unwrap()
+            .fields;
+        assert_eq!(fields[0], Field::new("equity", 90.0));
+        assert_eq!(fields[2], Field::new("drawdown", Value::Float(-0.25)));
+    }
+
+    #[test]
+    fn trader_metrics_only_emits_cycles_that_processed_events() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        let mut metrics = TraderMetrics::new(Some(metric_tx), Uuid::new_v4(), &market);
+
+        metrics.start_cycle();
+        metrics.finish_cycle();
+        assert!(metric_rx.try_recv().is_err());
+
+        metrics.start_cycle();
+        metrics.observe_event_q(3);
+        metrics.observe_event_q(1);
+        metrics.order();
+        metrics.rejection();
+        metrics.finish_cycle();
+
+        let metric = metric_rx.try_recv().unwrap();
+        assert_eq!(metric.name, "trader");
+        assert_eq!(metric.fields[0], Field::new("event_q_depth", 3_u64));
+        assert_eq!(metric.fields[2], Field::new("orders_total", 1_u64));
+        assert_eq!(metric.fields[3], Field::new("fills_total", 0_u64));
+        assert_eq!(metric.fields[4], Field::new("rejections_total", 1_u64));
+    }
+}
@@ -1,29 +1,66 @@
 use crate::{
     data::MarketGenerator,
-    engine::{error::EngineError, trader::Trader},
+    engine::{
+        error::EngineError,
+        metric::{PortfolioMetrics, DEFAULT_PORTFOLIO_METRIC_INTERVAL},
+        trader::{Trader, TraderSummary},
+    },
     event::{Event, MessageTransmitter},
     execution::ExecutionClient,
     portfolio::{
         position::Position,
-        repository::{PositionHandler, StatisticHandler},
-        FillUpdater, MarketUpdater, OrderGenerator,
+        repository::{BalanceHandler, PositionHandler, StatisticHandler},
+        Balance, FillUpdater, MarketUpdater, OrderGenerator, PortfolioConfigurer,
+    },
+    statistic::{
+        report::SessionReport,
+        summary::{
+            attribution::AttributionSummary,
+            benchmark::{self, BenchmarkSummary},
+            equity::{self, EquityRecorder},
+            latency::LatencySummary,
+            PositionSummariser, TableBuilder,
+        },
     },
-    statistic::summary::{PositionSummariser, TableBuilder},
-    strategy::SignalGenerator,
+    strategy::{Signal, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{Market, MarketId};
+use barter_integration::{
+    metric::Metric,
+    model::{Market, MarketId},
+};
+use futures::Stream;
 use parking_lot::Mutex;
-use prettytable::Table;
 use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
-use tokio::sync::{mpsc, oneshot};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Formatter},
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::{Instant, Interval},
+};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Embedded HTTP/JSON API for controlling & monitoring a running [`Engine`] via it's [`Command`]s
+/// and live [`Event`](crate::event::Event) stream.
+pub mod http;
+
+/// [`Metric`]s recorded by a running [`Engine`] & it's [`Trader`]s, eg/ event queue depth, loop
+/// latency, orders, fills, equity & drawdown.
+pub mod metric;
+
+/// Per-stage error handling policy applied by a [`Trader`] when part of it's event loop fails.
+pub mod policy;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
@@ -45,6 +82,132 @@ pub enum Command {
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Fetches the [`Engine`]'s Portfolio [`Balance`] and sends it on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchBalance(oneshot::Sender<Result<Balance, EngineError>>),
+
+    /// Fetches the Statistics of every [`Market`] the [`Engine`] is trading, serialised as JSON,
+    /// and sends them on the provided `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchStatistics(oneshot::Sender<Result<HashMap<Market, serde_json::Value>, EngineError>>),
+
+    /// Pause [`Signal`](crate::strategy::Signal) generation for a [`Market`], whilst continuing to
+    /// update it's open [`Position`]. Uses the [`Market`] provided to route this [`Command`] to
+    /// the relevant [`Trader`] instance. Involves one [`Trader`].
+    PauseMarket(Market, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Resume [`Signal`](crate::strategy::Signal) generation for a paused [`Market`]. Uses the
+    /// [`Market`] provided to route this [`Command`] to the relevant [`Trader`] instance. Involves
+    /// one [`Trader`].
+    ResumeMarket(Market, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Start trading a new [`Market`] with a [`Trader`] constructed by the [`Engine`]'s
+    /// [`TraderFactory`]. Involves the new [`Trader`].
+    AddMarket(Market, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Exit the [`Position`] of a [`Market`] & terminate it's [`Trader`], replying once the
+    /// [`Trader`] has stopped. Other [`Command`]s continue to be actioned whilst waiting. Uses
+    /// the [`Market`] provided to route this [`Command`] to the relevant [`Trader`] instance.
+    /// Involves one [`Trader`].
+    RemoveMarket(Market, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Replace the Portfolio allocation manager with one deserialised from the provided JSON
+    /// config. Involves the [`Engine`] only.
+    ConfigureAllocator(serde_json::Value, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Replace the Portfolio risk manager with one deserialised from the provided JSON config.
+    /// Involves the [`Engine`] only.
+    ConfigureRisk(serde_json::Value, oneshot::Sender<Result<(), EngineError>>),
+
+    /// Deliver a [`Signal`] generated by a portfolio level
+    /// [`MultiMarketSignalGenerator`](crate::strategy::multi::MultiMarketSignalGenerator) for
+    /// another [`Market`]. Uses the [`Signal`] exchange & instrument to route this [`Command`] to
+    /// the relevant [`Trader`] instance. Involves one [`Trader`].
+    Signal(Signal),
+}
+
+/// Capacity of the [`Command`] channel created for each [`Trader`] added at runtime.
+const TRADER_COMMAND_CAPACITY: usize = 20;
+
+/// Maximum duration to wait for the [`Trader`] of a removed [`Market`] to stop, see
+/// [`Command::RemoveMarket`].
+const TRADER_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Closure that constructs a [`Trader`] for a [`Market`] using the provided [`Command`] receiver.
+type BuildTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution> = dyn FnMut(
+        Market,
+        mpsc::Receiver<Command>,
+    )
+        -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError>
+    + Send
+    + Sync;
+
+/// Constructs the [`Trader`] for a [`Market`] added whilst the [`Engine`] is running, see
+/// [`Command::AddMarket`]. The `mpsc::Receiver` provided to the factory must be used as the
+/// [`Trader`]'s command_rx.
+pub struct TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    build: Box<BuildTrader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution> Debug
+    for TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TraderFactory").finish_non_exhaustive()
+    }
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// Constructs a new [`TraderFactory`] using the provided closure.
+    pub fn new<Build>(build: Build) -> Self
+    where
+        Build: FnMut(
+                Market,
+                mpsc::Receiver<Command>,
+            ) -> Result<
+                Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+                EngineError,
+            > + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            build: Box::new(build),
+        }
+    }
+
+    /// Constructs a [`Trader`] for the provided [`Market`].
+    fn build(
+        &mut self,
+        market: Market,
+        command_rx: mpsc::Receiver<Command>,
+    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        (self.build)(market, command_rx)
+    }
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
@@ -73,6 +236,26 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     pub statistics_summary: Statistic,
+    /// Optional [`TraderFactory`] used to construct [`Trader`]s for [`Market`]s added at runtime.
+    pub trader_factory:
+        Option<TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    /// Optional transmitter for the `portfolio` [`Metric`] recorded every
+    /// [`DEFAULT_PORTFOLIO_METRIC_INTERVAL`].
+    pub metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+    /// Optional receiver of [`Signal`]s generated by a portfolio level
+    /// [`SharedStrategy`](crate::strategy::multi::SharedStrategy) for another [`Market`].
+    pub signal_rx: Option<mpsc::UnboundedReceiver<Signal>>,
+    /// Optional configuration of a [`Benchmark`](benchmark::Benchmark) the trading session is
+    /// compared against in the session summary.
+    pub benchmark: Option<benchmark::Config>,
+    /// Optional configuration of the mark-to-market
+    /// [`EquityCurve`](equity::EquityCurve) included in the [`SessionReport`]. Otherwise the
+    /// [`SessionReport`] uses the realised equity curve of the exited [`Position`]s.
+    pub equity_curve: Option<equity::Config>,
+    /// Optional names of the [`Ensemble`](crate::strategy::ensemble::Ensemble) members the
+    /// realised PnL of exited [`Position`]s is attributed to in the session summary, see
+    /// [`Ensemble::member_names`](crate::strategy::ensemble::Ensemble::member_names).
+    pub attribution: Option<Vec<String>>,
 }
 
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
@@ -111,9 +294,32 @@ where
     /// `HashMap` containing a [`Command`] transmitter for every [`Trader`] associated with this
     /// [`Engine`].
     trader_command_txs: HashMap<Market, mpsc::Sender<Command>>,
+    /// [`Market`]s removed whilst the [`Engine`] was running, which are still included in the
+    /// trading session summary.
+    removed_markets: HashSet<Market>,
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Optional [`TraderFactory`] used to construct [`Trader`]s for [`Market`]s added at runtime.
+    trader_factory: Option<TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    /// Optional recorder of the Portfolio equity & drawdown [`Metric`]s.
+    portfolio_metrics: Option<PortfolioMetrics>,
+    /// Latency histograms of the [`Event`] chain, merged from the [`TraderSummary`] of every
+    /// stopped [`Trader`].
+    latency: LatencySummary,
+    /// Optional receiver of [`Signal`]s generated by a portfolio level
+    /// [`SharedStrategy`](crate::strategy::multi::SharedStrategy) for another [`Market`], which
+    /// are routed to the relevant [`Trader`].
+    signal_rx: Option<mpsc::UnboundedReceiver<Signal>>,
+    /// Optional comparison of the trading session to a [`Benchmark`](benchmark::Benchmark),
+    /// merged from the [`TraderSummary`] of every stopped [`Trader`].
+    benchmark: Option<BenchmarkSummary>,
+    /// Optional mark-to-market equity curve of the trading session, merged from the
+    /// [`TraderSummary`] of every stopped [`Trader`].
+    equity: Option<EquityRecorder>,
+    /// Optional attribution of the realised PnL of exited [`Position`]s to the
+    /// [`Ensemble`](crate::strategy::ensemble::Ensemble) members that contributed to them.
+    attribution: Option<AttributionSummary>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -122,7 +328,9 @@ where
     EventTx: MessageTransmitter<Event> + Send + 'static,
     Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
+        + PortfolioConfigurer
         + MarketUpdater
         + OrderGenerator
         + FillUpdater
@@ -144,7 +352,17 @@ where
             portfolio: lego.portfolio,
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
+            removed_markets: HashSet::new(),
             statistics_summary: lego.statistics_summary,
+            trader_factory: lego.trader_factory,
+            latency: LatencySummary::new(),
+            signal_rx: lego.signal_rx,
+            benchmark: lego.benchmark.map(BenchmarkSummary::new),
+            equity: lego.equity_curve.map(EquityRecorder::new),
+            portfolio_metrics: lego.metric_tx.map(|metric_tx| {
+                PortfolioMetrics::new(metric_tx, DEFAULT_PORTFOLIO_METRIC_INTERVAL, lego.engine_id)
+            }),
+            attribution: lego.attribution.map(AttributionSummary::new),
         }
     }
 
@@ -156,16 +374,142 @@ where
     /// Run the trading [`Engine`]. Spawns a thread for each [`Trader`] to run on. Asynchronously
     /// receives [`Command`]s via the `command_rx` and actions them
     /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
-    /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
-    /// for the trading session.
-    pub async fn run(mut self) {
-        // Run Traders on threads & send notification when they have stopped organically
-        let mut notify_traders_stopped = self.run_traders().await;
+    /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates, prints a summary
+    /// for the trading session & returns it's [`SessionReport`].
+    pub async fn run(self) -> SessionReport<Statistic> {
+        // Run each Trader on it's own thread & notify the Engine when it has stopped
+        self.run_traders(|trader, stopped_tx| {
+            thread::spawn(move || {
+                let market = trader.market().clone();
+
+                let summary = match panic::catch_unwind(AssertUnwindSafe(|| trader.run())) {
+                    Ok(summary) => Some(summary),
+                    Err(err) => {
+                        error!(
+                            error = &*format!("{:?}", err),
+                            ?market,
+                            "Trader thread has panicked during execution",
+                        );
+                        None
+                    }
+                };
+
+                let _ = stopped_tx.send((market, summary));
+            });
+        })
+        .await
+    }
+
+    /// Run the trading [`Engine`] with each [`Trader`] running it's asynchronous event-loop (see
+    /// [`Trader::run_async`]) as a task on the current tokio runtime, rather than on a dedicated
+    /// thread. Scales to many [`Market`]s on a small thread pool. Otherwise behaves like
+    /// [`Engine::run`].
+    pub async fn run_async(self) -> SessionReport<Statistic>
+    where
+        Data: Stream<Item = MarketEvent<DataKind>> + Unpin + 'static,
+        Portfolio: Sync,
+    {
+        // Run each Trader as it's own task & notify the Engine when it has stopped
+        self.run_traders(|trader, stopped_tx| {
+            let market = trader.market().clone();
+            let handle = tokio::spawn(trader.run_async());
+
+            tokio::spawn(async move {
+                let summary = match handle.await {
+                    Ok(summary) => Some(summary),
+                    Err(err) => {
+                        error!(
+                            error = &*format!("{:?}", err),
+                            ?market,
+                            "Trader task has panicked during execution",
+                        );
+                        None
+                    }
+                };
+
+                let _ = stopped_tx.send((market, summary));
+            });
+        })
+        .await
+    }
+
+    /// Runs every [`Trader`] using the provided spawn function, which must send the [`Trader`]'s
+    /// [`Market`] on the provided `mpsc::UnboundedSender` once it has stopped.
+    ///
+    /// Actions [`Command`]s received from remote until they request termination (or the remote
+    /// [`Command`] transmitter is dropped), until a [`Trader`] requests the [`Engine`] halts, or
+    /// until every running [`Trader`] has stopped organically. Removing the last [`Market`] via
+    /// [`Command::RemoveMarket`] does not stop the [`Engine`], so new [`Market`]s can still be
+    /// added. Then prints a summary for the trading session & returns it's [`SessionReport`].
+    async fn run_traders<Spawn>(mut self, spawn: Spawn) -> SessionReport<Statistic>
+    where
+        Spawn: Fn(
+            Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+            mpsc::UnboundedSender<(Market, Option<TraderSummary>)>,
+        ),
+    {
+        // Enable Traders to request the Engine halts if their ErrorPolicy requires it
+        let (halt_tx, mut halt_rx) = mpsc::unbounded_channel();
+
+        // Enable Traders to notify the Engine when they have stopped
+        let (stopped_tx, mut stopped_rx) = mpsc::unbounded_channel();
+
+        // Markets removed via Command::RemoveMarket whose Trader has not stopped yet
+        let mut pending_removals = HashMap::new();
+
+        // Extract Traders out of the Engine so they can be moved into threads or tasks
+        let mut running = HashSet::new();
+        for mut trader in std::mem::take(&mut self.traders) {
+            self.prepare_trader(&mut trader, &halt_tx);
+            running.insert(trader.market().clone());
+            spawn(trader, stopped_tx.clone());
+        }
+
+        // Periodically record Portfolio Metrics if a Metric transmitter has been provided
+        let mut metric_interval = self
+            .portfolio_metrics
+            .as_ref()
+            .map(|metrics| tokio::time::interval(metrics.interval));
 
         loop {
             // Action received commands from remote, or wait for all Traders to stop organically
             tokio::select! {
-                _ = notify_traders_stopped.recv() => {
+                Some((market, summary)) = stopped_rx.recv() => {
+                    self.merge_trader_summary(summary);
+                    running.remove(&market);
+
+                    // Removing the last Market does not stop the Engine
+                    if let Some(removal) = pending_removals.remove(&market) {
+                        self.complete_trader_removal(market, removal);
+                    } else if running.is_empty() {
+                        info!(engine_id = %self.engine_id, "every Trader has stopped");
+                        break;
+                    }
+                },
+
+                _ = removal_timeout(&pending_removals) => {
+                    self.expire_trader_removals(&mut pending_removals);
+                },
+
+                _ = tick(&mut metric_interval) => {
+                    self.record_portfolio_metrics();
+                },
+
+                Some(signal) = recv_signal(&mut self.signal_rx) => {
+                    self.route_signal(signal);
+                },
+
+                Some(error) = halt_rx.recv() => {
+                    error!(
+                        engine_id = %self.engine_id,
+                        market = ?error.market(),
+                        stage = ?error.stage(),
+                        %error,
+                        action = "terminating Traders without exiting Positions",
+                        "Trader requested Engine halt"
+                    );
+                    self.send_terminate_to_traders(format!("Engine halted: {error}"))
+                        .await;
                     break;
                 },
 
@@ -185,50 +529,109 @@ where
                             Command::ExitAllPositions => {
                                 self.exit_all_positions().await;
                             },
+                            Command::FetchBalance(balance_tx) => {
+                                let balance = self
+                                    .portfolio
+                                    .lock()
+                                    .get_balance(self.engine_id)
+                                    .map_err(EngineError::from);
+                                reply(balance_tx, balance, "Command::FetchBalance");
+                            },
+                            Command::FetchStatistics(statistics_tx) => {
+                                let statistics = self.fetch_statistics();
+                                reply(statistics_tx, statistics, "Command::FetchStatistics");
+                            },
+                            Command::PauseMarket(market, reply_tx) => {
+                                self.route_to_trader(Command::PauseMarket, market, reply_tx).await;
+                            },
+                            Command::ResumeMarket(market, reply_tx) => {
+                                self.route_to_trader(Command::ResumeMarket, market, reply_tx).await;
+                            },
+                            Command::AddMarket(market, reply_tx) => {
+                                let added = self.build_trader(market).map(|mut trader| {
+                                    self.prepare_trader(&mut trader, &halt_tx);
+                                    running.insert(trader.market().clone());
+                                    spawn(trader, stopped_tx.clone());
+                                });
+                                reply(reply_tx, added, "Command::AddMarket");
+                            },
+                            Command::RemoveMarket(market, reply_tx) => {
+                                match self.remove_trader(&market, &pending_removals).await {
+                                    Ok(()) => {
+                                        pending_removals.insert(market, PendingRemoval {
+                                            reply_tx,
+                                            deadline: Instant::now() + TRADER_STOP_TIMEOUT,
+                                        });
+                                    }
+                                    Err(error) => {
+                                        reply(reply_tx, Err(error), "Command::RemoveMarket");
+                                    }
+                                }
+                            },
+                            Command::ConfigureAllocator(config, reply_tx) => {
+                                let configured = self
+                                    .portfolio
+                                    .lock()
+                                    .configure_allocator(config)
+                                    .map_err(EngineError::from);
+                                reply(reply_tx, configured, "Command::ConfigureAllocator");
+                            },
+                            Command::ConfigureRisk(config, reply_tx) => {
+                                let configured = self
+                                    .portfolio
+                                    .lock()
+                                    .configure_risk(config)
+                                    .map_err(EngineError::from);
+                                reply(reply_tx, configured, "Command::ConfigureRisk");
+                            },
+                            Command::Signal(signal) => {
+                                self.route_signal(signal);
+                            },
                         }
                     } else {
                         // Terminate traders due to dropped receiver
+                        self.send_terminate_to_traders(
+                            "remote command transmitter dropped".to_owned(),
+                        )
+                        .await;
                         break;
                     }
                 }
             }
         }
 
-        // Print Trading Session Summary
-        self.generate_session_summary().printstd();
-    }
+        // Wait for Traders to stop so their TraderSummary is included in the SessionReport
+        self.await_traders_stopped(&mut stopped_rx, &mut running)
+            .await;
 
-    /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
-    /// if all the [`Trader`]s have stopped organically (eg/ due to a finished [`MarketEvent`] feed).
-    async fn run_traders(&mut self) -> mpsc::Receiver<bool> {
-        // Extract Traders out of the Engine so we can move them into threads
-        let traders = std::mem::take(&mut self.traders);
-
-        // Run each Trader instance on it's own thread
-        let mut thread_handles = Vec::with_capacity(traders.len());
-        for trader in traders.into_iter() {
-            let handle = thread::spawn(move || trader.run());
-            thread_handles.push(handle);
+        // Reply to any Market removals still pending once the Engine stopped
+        for (market, removal) in pending_removals {
+            match running.contains(&market) {
+                true => reply(
+                    removal.reply_tx,
+                    Err(EngineError::TraderStopTimeout(market)),
+                    "Command::RemoveMarket",
+                ),
+                false => self.complete_trader_removal(market, removal),
+            }
         }
 
-        // Create channel to notify the Engine when the Traders have stopped organically
-        let (notify_tx, notify_rx) = mpsc::channel(1);
+        // Print Trading Session Summary
+        let latency = std::mem::take(&mut self.latency);
+        let report = self.generate_session_report();
+        report.table().printstd();
 
-        // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
-            for handle in thread_handles {
-                if let Err(err) = handle.join() {
-                    error!(
-                        error = &*format!("{:?}", err),
-                        "Trader thread has panicked during execution",
-                    )
-                }
-            }
+        // Print Ensemble member PnL attribution summary
+        if let Some(attribution) = &report.attribution {
+            attribution.table().printstd();
+        }
 
-            let _ = notify_tx.send(true).await;
-        });
+        // Print Event chain latency summary
+        if !latency.is_empty() {
+            latency.table().printstd();
+        }
 
-        notify_rx
+        report
     }
 
     /// Fetches all the [`Engine`]'s open [`Position`]s and sends them on the provided
@@ -243,10 +646,282 @@ where
             .get_open_positions(self.engine_id, self.trader_command_txs.keys())
             .map_err(EngineError::RepositoryInteractionError);
 
-        if positions_tx.send(open_positions).is_err() {
+        reply(positions_tx, open_positions, "Command::FetchOpenPositions");
+    }
+
+    /// Fetches the Statistics of every [`Market`] the [`Engine`] is trading, serialised as JSON.
+    fn fetch_statistics(&self) -> Result<HashMap<Market, serde_json::Value>, EngineError> {
+        let mut portfolio = self.portfolio.lock();
+
+        self.trader_command_txs
+            .keys()
+            .map(|market| {
+                let statistics = portfolio.get_statistics(&MarketId::from(market))?;
+                Ok((market.clone(), serde_json::to_value(statistics)?))
+            })
+            .collect()
+    }
+
+    /// Records the current Portfolio equity & drawdown [`Metric`]s.
+    fn record_portfolio_metrics(&mut self) {
+        let metrics = match &mut self.portfolio_metrics {
+            Some(metrics) => metrics,
+            None => return,
+        };
+
+        match self.portfolio.lock().get_balance(self.engine_id) {
+            Ok(balance) => metrics.record(balance),
+            Err(error) => warn!(
+                engine_id = %self.engine_id,
+                %error,
+                "failed to fetch Balance for Portfolio Metrics"
+            ),
+        }
+    }
+
+    /// Routes a [`Market`] specific [`Command`] to the relevant [`Trader`] instance, which replies
+    /// on the provided `oneshot::Sender`. Replies with an [`EngineError`] if the [`Command`]
+    /// cannot be delivered.
+    async fn route_to_trader(
+        &self,
+        command: fn(Market, oneshot::Sender<Result<(), EngineError>>) -> Command,
+        market: Market,
+        reply_tx: oneshot::Sender<Result<(), EngineError>>,
+    ) {
+        let command_tx = match self.trader_command_txs.get(&market) {
+            Some(command_tx) => command_tx,
+            None => {
+                reply(
+                    reply_tx,
+                    Err(EngineError::MarketNotFound(market)),
+                    "Command",
+                );
+                return;
+            }
+        };
+
+        // Recover the oneshot::Sender from the undelivered Command to reply with the failure
+        if let Err(mpsc::error::SendError(
+            Command::PauseMarket(market, reply_tx) | Command::ResumeMarket(market, reply_tx),
+        )) = command_tx.send(command(market, reply_tx)).await
+        {
+            reply(reply_tx, Err(EngineError::TraderStopped(market)), "Command");
+        }
+    }
+
+    /// Routes a [`Signal`] generated for another [`Market`] to the relevant [`Trader`] instance as
+    /// a [`Command::Signal`]. Undeliverable [`Signal`]s are logged & dropped.
+    ///
+    /// Never waits for capacity in the [`Trader`]'s bounded [`Command`] channel, since that would
+    /// block the [`Engine`] from actioning anything else. A [`Signal`] routed to a [`Trader`] that
+    /// has fallen behind is dropped, as a fresh [`Signal`] will supersede it.
+    fn route_signal(&self, signal: Signal) {
+        let market = Market::new(signal.exchange.clone(), signal.instrument.clone());
+
+        let command_tx = match self.trader_command_txs.get(&market) {
+            Some(command_tx) => command_tx,
+            None => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    ?market,
+                    why = "no Trader is trading the Market",
+                    "failed to route Signal"
+                );
+                return;
+            }
+        };
+
+        let why = match command_tx.try_send(Command::Signal(signal)) {
+            Ok(()) => return,
+            Err(mpsc::error::TrySendError::Full(_)) => "Trader command channel is full",
+            Err(mpsc::error::TrySendError::Closed(_)) => "Trader command receiver dropped",
+        };
+
+        warn!(
+            engine_id = %self.engine_id,
+            ?market,
+            why,
+            "failed to route Signal"
+        );
+    }
+
+    /// Constructs a [`Trader`] for a new [`Market`] using the [`TraderFactory`], after
+    /// initialising the [`Market`] in the Portfolio.
+    fn build_trader(
+        &mut self,
+        market: Market,
+    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        if self.trader_command_txs.contains_key(&market) {
+            return Err(EngineError::MarketAlreadyTraded(market));
+        }
+
+        let trader_factory = self
+            .trader_factory
+            .as_mut()
+            .ok_or(EngineError::TraderFactoryMissing)?;
+
+        self.portfolio.lock().add_market(&market)?;
+
+        let (command_tx, command_rx) = mpsc::channel(TRADER_COMMAND_CAPACITY);
+        let trader = trader_factory.build(market.clone(), command_rx)?;
+
+        info!(
+            engine_id = %self.engine_id,
+            ?market,
+            "added new Trader to running Engine"
+        );
+        self.removed_markets.remove(&market);
+        self.trader_command_txs.insert(market, command_tx);
+
+        Ok(trader)
+    }
+
+    /// Exit the [`Position`] of a [`Market`] & terminate it's [`Trader`]. The removal is completed
+    /// by the [`Engine`] event loop once the [`Trader`] has stopped, see
+    /// [`Self::complete_trader_removal`].
+    ///
+    /// If the [`Trader`] has already stopped it is removed immediately, and
+    /// [`EngineError::TraderStopped`] is returned.
+    async fn remove_trader(
+        &mut self,
+        market: &Market,
+        pending_removals: &HashMap<Market, PendingRemoval>,
+    ) -> Result<(), EngineError> {
+        if pending_removals.contains_key(market) {
+            return Err(EngineError::MarketRemovalPending(market.clone()));
+        }
+
+        let command_tx = self
+            .trader_command_txs
+            .get(market)
+            .ok_or_else(|| EngineError::MarketNotFound(market.clone()))?
+            .clone();
+
+        // Trader actions Commands in order, so the Position is exited before it terminates
+        for command in [
+            Command::ExitPosition(market.clone()),
+            Command::Terminate(format!("Market removed: {market:?}")),
+        ] {
+            if command_tx.send(command).await.is_err() {
+                self.trader_command_txs.remove(market);
+                self.removed_markets.insert(market.clone());
+                return Err(EngineError::TraderStopped(market.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Completes the removal of a [`Market`] whose [`Trader`] has stopped & replies to the
+    /// [`Command::RemoveMarket`]. The [`Market`]'s statistics remain in the trading session
+    /// summary.
+    fn complete_trader_removal(&mut self, market: Market, removal: PendingRemoval) {
+        self.trader_command_txs.remove(&market);
+        self.removed_markets.insert(market.clone());
+
+        info!(
+            engine_id = %self.engine_id,
+            ?market,
+            "removed Trader from running Engine"
+        );
+
+        reply(removal.reply_tx, Ok(()), "Command::RemoveMarket");
+    }
+
+    /// Replies with [`EngineError::TraderStopTimeout`] to every pending [`Market`] removal whose
+    /// [`Trader`] did not stop before the [`TRADER_STOP_TIMEOUT`]. The [`Trader`] is still
+    /// running, so it remains associated with this [`Engine`] & can be removed again.
+    fn expire_trader_removals(&self, pending_removals: &mut HashMap<Market, PendingRemoval>) {
+        let now = Instant::now();
+        let expired = pending_removals
+            .iter()
+            .filter(|(_, removal)| removal.deadline <= now)
+            .map(|(market, _)| market.clone())
+            .collect::<Vec<_>>();
+
+        for market in expired {
+            if let Some(removal) = pending_removals.remove(&market) {
+                warn!(
+                    engine_id = %self.engine_id,
+                    ?market,
+                    why = "Trader did not stop before the timeout",
+                    "cannot remove Trader from running Engine"
+                );
+                reply(
+                    removal.reply_tx,
+                    Err(EngineError::TraderStopTimeout(market)),
+                    "Command::RemoveMarket",
+                );
+            }
+        }
+    }
+
+    /// Prepares a [`Trader`] to run within this [`Engine`], enabling it to request the [`Engine`]
+    /// halts & to record a [`BenchmarkSummary`] if the trading session has a
+    /// [`Benchmark`](benchmark::Benchmark).
+    fn prepare_trader(
+        &self,
+        trader: &mut Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+        halt_tx: &mpsc::UnboundedSender<EngineError>,
+    ) {
+        trader.set_engine_halt_tx(halt_tx.clone());
+        trader.set_benchmark_summary(
+            self.benchmark
+                .as_ref()
+                .map(|benchmark| BenchmarkSummary::new(benchmark.config().clone())),
+        );
+        trader.set_equity_recorder(
+            self.equity
+                .as_ref()
+                .map(|equity| EquityRecorder::new(*equity.config())),
+        );
+    }
+
+    /// Merges the [`TraderSummary`] of a stopped [`Trader`] into the trading session summaries.
+    /// A [`Trader`] that panicked has no [`TraderSummary`].
+    fn merge_trader_summary(&mut self, summary: Option<TraderSummary>) {
+        let Some(summary) = summary else {
+            return;
+        };
+
+        self.latency.merge(summary.latency);
+        if let (Some(benchmark), Some(trader_benchmark)) = (&mut self.benchmark, summary.benchmark)
+        {
+            benchmark.merge(trader_benchmark);
+        }
+        if let (Some(equity), Some(trader_equity)) = (&mut self.equity, summary.equity) {
+            equity.merge(trader_equity);
+        }
+    }
+
+    /// Waits until every running [`Trader`] has stopped, merging their [`TraderSummary`]s, or
+    /// until the [`TRADER_STOP_TIMEOUT`] elapses.
+    async fn await_traders_stopped(
+        &mut self,
+        stopped_rx: &mut mpsc::UnboundedReceiver<(Market, Option<TraderSummary>)>,
+        running: &mut HashSet<Market>,
+    ) {
+        let stopped = async {
+            while !running.is_empty() {
+                match stopped_rx.recv().await {
+                    Some((market, summary)) => {
+                        self.merge_trader_summary(summary);
+                        running.remove(&market);
+                    }
+                    None => return,
+                }
+            }
+        };
+
+        if tokio::time::timeout(TRADER_STOP_TIMEOUT, stopped)
+            .await
+            .is_err()
+        {
             warn!(
-                why = "oneshot receiver dropped",
-                "cannot action Command::FetchOpenPositions"
+                engine_id = %self.engine_id,
+                markets = ?running,
+                why = "Trader did not stop before the timeout",
+                "trading session summary excludes the TraderSummary of running Traders"
             );
         }
     }
@@ -257,7 +932,12 @@ where
         self.exit_all_positions().await;
         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
 
-        // Distribute Command::Terminate to all the Engine's Traders
+        self.send_terminate_to_traders(message).await;
+    }
+
+    /// Distribute [`Command::Terminate`] to all the [`Engine`]'s [`Trader`]s, without exiting any
+    /// open [`Position`]s.
+    async fn send_terminate_to_traders(&self, message: String) {
         for (market, command_tx) in self.trader_command_txs.iter() {
             if command_tx
                 .send(Command::Terminate(message.clone()))
@@ -314,11 +994,18 @@ where
         }
     }
 
-    /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
-    /// combination with the average statistics across all [`Market`]s traded.
-    fn generate_session_summary(mut self) -> Table {
-        // Fetch statistics for each Market
-        let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
+    /// Generate a [`SessionReport`] for the trading session. Uses the Portfolio's statistics per
+    /// [`Market`] in combination with the average statistics across all [`Market`]s traded.
+    fn generate_session_report(mut self) -> SessionReport<Statistic> {
+        // Compare the trading session to it's Benchmark, if there is one
+        let benchmark = self.benchmark.as_ref().map(BenchmarkSummary::statistics);
+
+        // Fetch statistics for each Market, including those removed during the trading session
+        let markets = self
+            .trader_command_txs
+            .into_keys()
+            .chain(self.removed_markets);
+        let stats_per_market = markets.filter_map(|market| {
             let market_id = MarketId::from(&market);
 
             match self.portfolio.lock().get_statistics(&market_id) {
@@ -335,11 +1022,16 @@ where
         });
 
         // Generate average statistics across all markets using session's exited Positions
-        self.portfolio
+        let exited_positions = self
+            .portfolio
             .lock()
             .get_exited_positions(self.engine_id)
             .map(|exited_positions| {
                 self.statistics_summary.generate_summary(&exited_positions);
+                if let Some(attribution) = &mut self.attribution {
+                    attribution.generate_summary(&exited_positions);
+                }
+                exited_positions
             })
             .unwrap_or_else(|error| {
                 warn!(
@@ -347,12 +1039,69 @@ where
                     why = "failed to get exited Positions from Portfolio's repository",
                     "failed to generate Statistics summary for trading session"
                 );
+                Vec::new()
             });
 
-        // Combine Total & Per-Market Statistics Into Table
-        crate::statistic::summary::combine(
-            stats_per_market.chain([("Total".to_owned(), self.statistics_summary)]),
-        )
+        SessionReport {
+            attribution: self.attribution,
+            ..SessionReport::new(
+                self.engine_id,
+                stats_per_market.collect(),
+                self.statistics_summary,
+                benchmark,
+                exited_positions,
+                self.equity.map(EquityRecorder::into_curve).as_ref(),
+            )
+        }
+    }
+}
+
+/// [`Command::RemoveMarket`] waiting for the [`Trader`] of the removed [`Market`] to stop.
+struct PendingRemoval {
+    reply_tx: oneshot::Sender<Result<(), EngineError>>,
+    deadline: Instant,
+}
+
+/// Completes when the earliest pending [`Market`] removal times out. Pends forever if there are
+/// no pending removals, so it never completes a `tokio::select!` branch.
+async fn removal_timeout(pending_removals: &HashMap<Market, PendingRemoval>) {
+    match pending_removals
+        .values()
+        .map(|removal| removal.deadline)
+        .min()
+    {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Completes when the next Portfolio [`Metric`] is due. Pends forever if the [`Engine`] has no
+/// metric [`Interval`], so it never completes a `tokio::select!` branch.
+async fn tick(interval: &mut Option<Interval>) -> Instant {
+    match interval {
+        Some(interval) => interval.tick().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Receives the next [`Signal`] routed by a
+/// [`SharedStrategy`](crate::strategy::multi::SharedStrategy). Pends forever if the [`Engine`] has
+/// no signal_rx, so it never completes a `tokio::select!` branch.
+async fn recv_signal(signal_rx: &mut Option<mpsc::UnboundedReceiver<Signal>>) -> Option<Signal> {
+    match signal_rx {
+        Some(signal_rx) => signal_rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Replies to a [`Command`] on the provided `oneshot::Sender`.
+fn reply<T>(
+    reply_tx: oneshot::Sender<Result<T, EngineError>>,
+    result: Result<T, EngineError>,
+    command: &'static str,
+) {
+    if reply_tx.send(result).is_err() {
+        warn!(why = "oneshot receiver dropped", "cannot action {command}");
     }
 }
 
@@ -373,6 +1122,13 @@ where
     traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
     trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
     statistics_summary: Option<Statistic>,
+    trader_factory: Option<TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
+    metric_interval: Option<Duration>,
+    signal_rx: Option<mpsc::UnboundedReceiver<Signal>>,
+    benchmark: Option<benchmark::Config>,
+    equity_curve: Option<equity::Config>,
+    attribution: Option<Vec<String>>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -398,6 +1154,13 @@ where
             traders: None,
             trader_command_txs: None,
             statistics_summary: None,
+            trader_factory: None,
+            metric_tx: None,
+            metric_interval: None,
+            signal_rx: None,
+            benchmark: None,
+            equity_curve: None,
+            attribution: None,
         }
     }
 
@@ -446,13 +1209,90 @@ where
         }
     }
 
+    /// Optional [`TraderFactory`] used to construct [`Trader`]s for [`Market`]s added at runtime,
+    /// see [`Command::AddMarket`].
+    pub fn trader_factory(
+        self,
+        value: TraderFactory<EventTx, Statistic, Portfolio, Data, Strategy, Execution>,
+    ) -> Self {
+        Self {
+            trader_factory: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional transmitter for the `portfolio` [`Metric`] recorded by the [`Engine`] whilst it
+    /// is running.
+    pub fn metric_tx(self, value: mpsc::UnboundedSender<Metric>) -> Self {
+        Self {
+            metric_tx: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional interval between each `portfolio` [`Metric`]. Defaults to
+    /// [`DEFAULT_PORTFOLIO_METRIC_INTERVAL`].
+    pub fn metric_interval(self, value: Duration) -> Self {
+        Self {
+            metric_interval: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional receiver of [`Signal`]s generated by a portfolio level
+    /// [`SharedStrategy`](crate::strategy::multi::SharedStrategy) for another [`Market`], see
+    /// [`MultiMarketSignalGenerator`](crate::strategy::multi::MultiMarketSignalGenerator).
+    pub fn signal_rx(self, value: mpsc::UnboundedReceiver<Signal>) -> Self {
+        Self {
+            signal_rx: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Benchmark`](benchmark::Benchmark) the trading session is compared against in
+    /// the session summary, eg/ buy-and-hold.
+    pub fn benchmark(self, value: benchmark::Config) -> Self {
+        Self {
+            benchmark: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional configuration of the mark-to-market [`EquityCurve`](equity::EquityCurve) included
+    /// in the [`SessionReport`], rather than the realised equity curve of the exited
+    /// [`Position`]s.
+    pub fn equity_curve(self, value: equity::Config) -> Self {
+        Self {
+            equity_curve: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional names of the [`Ensemble`](crate::strategy::ensemble::Ensemble) members the
+    /// realised PnL of exited [`Position`]s is attributed to in the session summary.
+    pub fn attribution(self, value: Vec<String>) -> Self {
+        Self {
+            attribution: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        let engine_id = self
+            .engine_id
+            .ok_or(EngineError::BuilderIncomplete("engine_id"))?;
+        let metric_interval = self
+            .metric_interval
+            .unwrap_or(DEFAULT_PORTFOLIO_METRIC_INTERVAL);
+
         Ok(Engine {
-            engine_id: self
-                .engine_id
-                .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
+            latency: LatencySummary::new(),
+            portfolio_metrics: self
+                .metric_tx
+                .map(|metric_tx| PortfolioMetrics::new(metric_tx, metric_interval, engine_id)),
+            engine_id,
             command_rx: self
                 .command_rx
                 .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
@@ -465,9 +1305,15 @@ where
             trader_command_txs: self
                 .trader_command_txs
                 .ok_or(EngineError::BuilderIncomplete("trader_command_txs"))?,
+            removed_markets: HashSet::new(),
             statistics_summary: self
                 .statistics_summary
                 .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            trader_factory: self.trader_factory,
+            signal_rx: self.signal_rx,
+            benchmark: self.benchmark.map(BenchmarkSummary::new),
+            equity: self.equity_curve.map(EquityRecorder::new),
+            attribution: self.attribution.map(AttributionSummary::new),
         })
     }
 }
@@ -0,0 +1,215 @@
+use barter_integration::model::Market;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Default delay before the first retry of a failed [`TraderStage`].
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Upper bound of the exponentially increasing delay between retries of a failed
+/// [`TraderStage`].
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Stages of the [`Trader`](super::trader::Trader) event loop that can fail.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum TraderStage {
+    /// Portfolio update from a [`MarketEvent`](barter_data::event::MarketEvent).
+    UpdateFromMarket,
+    /// Portfolio [`OrderEvent`](crate::portfolio::OrderEvent) generation from a
+    /// [`Signal`](crate::strategy::Signal).
+    GenerateOrder,
+    /// Portfolio [`OrderEvent`](crate::portfolio::OrderEvent) generation from a
+    /// [`SignalForceExit`](crate::strategy::SignalForceExit).
+    GenerateExitOrder,
+    /// Execution [`FillEvent`](crate::execution::FillEvent) generation from an
+    /// [`OrderEvent`](crate::portfolio::OrderEvent).
+    GenerateFill,
+    /// Portfolio update from a [`FillEvent`](crate::execution::FillEvent).
+    UpdateFromFill,
+}
+
+/// Action taken by a [`Trader`](super::trader::Trader) after a [`TraderStage`] fails.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ErrorAction {
+    /// Re-attempt the failed [`TraderStage`] with the same input.
+    Retry,
+    /// Drop the input that caused the failure & continue trading.
+    SkipEvent,
+    /// Stop the [`Trader`](super::trader::Trader) of the affected [`Market`]. Other markets
+    /// continue trading.
+    HaltMarket,
+    /// Stop every [`Trader`](super::trader::Trader) of the [`Engine`](super::Engine).
+    HaltEngine,
+}
+
+/// Error handling policy for a single [`TraderStage`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StagePolicy {
+    /// Number of times the [`TraderStage`] is retried before the on_failure [`ErrorAction`]
+    /// is taken.
+    pub max_retries: u32,
+    /// [`ErrorAction`] taken once the retries are exhausted. [`ErrorAction::Retry`] is treated as
+    /// [`ErrorAction::SkipEvent`] since the retries have already been attempted.
+    pub on_failure: ErrorAction,
+    /// Delay before the first retry, doubled for every subsequent retry up to a maximum of
+    /// 5 seconds.
+    #[serde(default = "default_retry_backoff")]
+    pub retry_backoff: Duration,
+}
+
+impl StagePolicy {
+    /// Constructs a [`StagePolicy`] that takes the provided [`ErrorAction`] without retrying.
+    pub fn new(on_failure: ErrorAction) -> Self {
+        Self::with_retries(0, on_failure)
+    }
+
+    /// Constructs a [`StagePolicy`] that retries the provided number of times, with the default
+    /// exponential backoff, before taking the provided [`ErrorAction`].
+    pub fn with_retries(max_retries: u32, on_failure: ErrorAction) -> Self {
+        Self {
+            max_retries,
+            on_failure,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+        }
+    }
+
+    /// Determines the delay before retrying after the provided (1-indexed) failed attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let multiplier = 2_u32.saturating_pow(attempt.saturating_sub(1));
+
+        self.retry_backoff
+            .checked_mul(multiplier)
+            .map_or(MAX_RETRY_BACKOFF, |backoff| backoff.min(MAX_RETRY_BACKOFF))
+    }
+
+    /// Determines the [`ErrorAction`] to take after the provided (1-indexed) failed attempt.
+    pub fn action(&self, attempt: u32) -> ErrorAction {
+        if attempt <= self.max_retries {
+            ErrorAction::Retry
+        } else if self.on_failure == ErrorAction::Retry {
+            ErrorAction::SkipEvent
+        } else {
+            self.on_failure
+        }
+    }
+}
+
+/// Per [`TraderStage`] error handling policy used by a [`Trader`](super::trader::Trader) event
+/// loop.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ErrorPolicy {
+    pub update_from_market: StagePolicy,
+    pub generate_order: StagePolicy,
+    pub generate_exit_order: StagePolicy,
+    pub generate_fill: StagePolicy,
+    pub update_from_fill: StagePolicy,
+}
+
+impl Default for ErrorPolicy {
+    /// Skips failed market updates, orders & fills since the next
+    /// [`Signal`](crate::strategy::Signal) can try again. Failures to exit a Position or account
+    /// for a fill leave the Portfolio out of sync with the exchange, so they are retried before
+    /// halting the market.
+    fn default() -> Self {
+        Self {
+            update_from_market: StagePolicy::new(ErrorAction::SkipEvent),
+            generate_order: StagePolicy::new(ErrorAction::SkipEvent),
+            generate_exit_order: StagePolicy::with_retries(2, ErrorAction::HaltMarket),
+            generate_fill: StagePolicy::new(ErrorAction::SkipEvent),
+            update_from_fill: StagePolicy::with_retries(2, ErrorAction::HaltMarket),
+        }
+    }
+}
+
+impl ErrorPolicy {
+    /// Constructs an [`ErrorPolicy`] that applies the same [`StagePolicy`] to every
+    /// [`TraderStage`].
+    pub fn uniform(policy: StagePolicy) -> Self {
+        Self {
+            update_from_market: policy,
+            generate_order: policy,
+            generate_exit_order: policy,
+            generate_fill: policy,
+            update_from_fill: policy,
+        }
+    }
+
+    /// Returns the [`StagePolicy`] of the provided [`TraderStage`].
+    pub fn stage(&self, stage: TraderStage) -> StagePolicy {
+        match stage {
+            TraderStage::UpdateFromMarket => self.update_from_market,
+            TraderStage::GenerateOrder => self.generate_order,
+            TraderStage::GenerateExitOrder => self.generate_exit_order,
+            TraderStage::GenerateFill => self.generate_fill,
+            TraderStage::UpdateFromFill => self.update_from_fill,
+        }
+    }
+}
+
+fn default_retry_backoff() -> Duration {
+    DEFAULT_RETRY_BACKOFF
+}
+
+/// Communicates a failed [`TraderStage`] & the [`ErrorAction`] taken by the
+/// [`Trader`](super::trader::Trader) as a result.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EngineErrorEvent {
+    pub time: DateTime<Utc>,
+    pub engine_id: Uuid,
+    pub market: Market,
+    pub stage: TraderStage,
+    /// 1-indexed attempt of the [`TraderStage`] that failed.
+    pub attempt: u32,
+    /// Display representation of the error that caused the failure.
+    pub error: String,
+    pub action: ErrorAction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_policy_retries_then_takes_on_failure_action() {
+        let policy = StagePolicy::with_retries(2, ErrorAction::HaltEngine);
+
+        assert_eq!(policy.action(1), ErrorAction::Retry);
+        assert_eq!(policy.action(2), ErrorAction::Retry);
+        assert_eq!(policy.action(3), ErrorAction::HaltEngine);
+    }
+
+    #[test]
+    fn stage_policy_with_exhausted_retry_on_failure_skips_event() {
+        let policy = StagePolicy::with_retries(1, ErrorAction::Retry);
+
+        assert_eq!(policy.action(1), ErrorAction::Retry);
+        assert_eq!(policy.action(2), ErrorAction::SkipEvent);
+    }
+
+    #[test]
+    fn stage_policy_backoff_doubles_every_retry_up_to_maximum() {
+        let policy = StagePolicy {
+            retry_backoff: Duration::from_millis(100),
+            ..StagePolicy::with_retries(10, ErrorAction::SkipEvent)
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), MAX_RETRY_BACKOFF);
+        assert_eq!(policy.backoff(u32::MAX), MAX_RETRY_BACKOFF);
+    }
+
+    #[test]
+    fn stage_policy_deserialises_without_retry_backoff() {
+        let policy =
+            serde_json::from_str::<StagePolicy>(r#"{"max_retries":1,"on_failure":"HaltMarket"}"#)
+                .unwrap();
+
+        assert_eq!(
+            policy,
+            StagePolicy::with_retries(1, ErrorAction::HaltMarket)
+        );
+    }
+}
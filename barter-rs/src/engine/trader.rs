@@ -1,20 +1,39 @@
-use super::{error::EngineError, Command};
+use super::{
+    error::{EngineError, TraderStageError},
+    metric::TraderMetrics,
+    policy::{EngineErrorEvent, ErrorAction, ErrorPolicy, TraderStage},
+    Command,
+};
 use crate::{
     data::{Feed, MarketGenerator},
-    event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
-    strategy::{SignalForceExit, SignalGenerator},
+    event::{Event, EventTrace, LatencyStage, MessageTransmitter},
+    execution::{ExecutionClient, FillEvent},
+    portfolio::{FillUpdater, MarketUpdater, OrderEvent, OrderGenerator},
+    statistic::summary::{
+        benchmark::BenchmarkSummary, equity::EquityRecorder, latency::LatencySummary,
+    },
+    strategy::{Signal, SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::Market;
+use barter_integration::{metric::Metric, model::Market};
+use chrono::Utc;
+use futures::{Stream, StreamExt};
 use parking_lot::Mutex;
 use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Statistics recorded by a [`Trader`] whilst trading, returned once it has stopped so the
+/// [`Engine`](super::Engine) can merge them into the trading session summary.
+#[derive(Clone, Debug, Default)]
+pub struct TraderSummary {
+    pub latency: LatencySummary,
+    pub benchmark: Option<BenchmarkSummary>,
+    pub equity: Option<EquityRecorder>,
+}
+
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
 #[derive(Debug)]
 pub struct TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -83,6 +102,31 @@ where
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// Optional `mpsc::UnboundedReceiver` for [`FillEvent`] execution responses that arrive
+    /// asynchronously (eg/ from an exchange user data stream). Only used by
+    /// [`Trader::run_async`].
+    fill_rx: Option<mpsc::UnboundedReceiver<FillEvent>>,
+    /// [`ErrorPolicy`] that determines the [`ErrorAction`] taken when a [`TraderStage`] fails.
+    error_policy: ErrorPolicy,
+    /// Optional `mpsc::UnboundedSender` used to request the [`Engine`](super::Engine) halts when a
+    /// [`TraderStage`] fails with [`ErrorAction::HaltEngine`].
+    engine_halt_tx: Option<mpsc::UnboundedSender<EngineError>>,
+    /// Flag to communicate the [`Trader`] has been halted by it's [`ErrorPolicy`].
+    halted: bool,
+    /// Flag to communicate [`Signal`](crate::strategy::Signal) generation has been paused via a
+    /// [`Command::PauseMarket`].
+    paused: bool,
+    /// Records the event loop performance & trading activity of this [`Trader`] as [`Metric`]s.
+    metrics: TraderMetrics,
+    /// Latency histograms of each [`LatencyStage`] of the [`Event`] chain, returned in the
+    /// [`TraderSummary`] so they can be summarised at the end of the trading session.
+    latency: LatencySummary,
+    /// Optional [`BenchmarkSummary`] updated with every [`MarketEvent`] & change in Portfolio
+    /// equity this [`Trader`] encounters, returned in the [`TraderSummary`].
+    benchmark: Option<BenchmarkSummary>,
+    /// Optional [`EquityRecorder`] updated with every change in Portfolio equity this [`Trader`]
+    /// encounters, returned in the [`TraderSummary`].
+    equity: Option<EquityRecorder>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -105,6 +149,10 @@ where
         );
 
         Self {
+            metrics: TraderMetrics::new(None, lego.engine_id, &lego.market),
+            latency: LatencySummary::new(),
+            benchmark: None,
+            equity: None,
             engine_id: lego.engine_id,
             market: lego.market,
             command_rx: lego.command_rx,
@@ -114,10 +162,20 @@ where
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
+            fill_rx: None,
+            error_policy: ErrorPolicy::default(),
+            engine_halt_tx: None,
+            halted: false,
+            paused: false,
             _statistic_marker: PhantomData::default(),
         }
     }
 
+    /// Returns the [`Market`] this [`Trader`] is bartering on.
+    pub fn market(&self) -> &Market {
+        &self.market
+    }
+
     /// Builder to construct [`Trader`] instances.
     pub fn builder() -> TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution> {
         TraderBuilder::new()
@@ -126,18 +184,18 @@ where
     /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
     /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
     /// [`MarketGenerator`] yields [`Feed::Finished`].
-    pub fn run(mut self) {
+    ///
+    /// Blocks the current thread whilst waiting for the next [`MarketEvent`], so is best suited
+    /// to backtesting. See [`Trader::run_async`] for live-trading many markets.
+    ///
+    /// Returns the [`TraderSummary`] recorded whilst trading once the loop has stopped.
+    pub fn run(mut self) -> TraderSummary {
         // Run trading loop for this Trader instance
         'trading: loop {
             // Check for new remote Commands before continuing to generate another MarketEvent
             while let Some(command) = self.receive_remote_command() {
-                match command {
-                    Command::Terminate(_) => break 'trading,
-                    Command::ExitPosition(market) => {
-                        self.event_q
-                            .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
-                    }
-                    _ => continue,
+                if !self.action_remote_command(command) {
+                    break 'trading;
                 }
             }
 
@@ -160,80 +218,438 @@ where
             }
 
             // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
-                            self.event_tx.send(Event::Signal(signal.clone()));
-                            self.event_q.push_back(Event::Signal(signal));
-                        }
+            futures::executor::block_on(self.process_event_q(Backoff::Blocking));
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
-                        }
+            if self.halted {
+                break 'trading;
+            }
+        }
+
+        debug!(
+            engine_id = &*self.engine_id.to_string(),
+            market = &*format!("{:?}", self.market),
+            "Trader trading loop stopped"
+        );
+
+        self.into_summary()
+    }
+
+    /// Run the asynchronous trading event-loop for this [`Trader`] instance. Waits on remote
+    /// [`Command`]s, asynchronous [`FillEvent`] execution responses & the next [`MarketEvent`]
+    /// concurrently, rather than polling for them. Loop will run until [`Trader`] receives a
+    /// [`Command::Terminate`] via the mpsc::Receiver command_rx, or the market data `Stream` ends.
+    ///
+    /// Many [`Trader`]s can run concurrently as tasks on a small tokio thread pool. Blocking
+    /// Portfolio & repository stages are run via [`tokio::task::block_in_place`] on a
+    /// multi-threaded runtime, so they do not stall the other tasks scheduled on the worker thread.
+    /// Failed stages are retried after an asynchronous backoff, during which remote [`Command`]s
+    /// continue to be actioned.
+    ///
+    /// Returns the [`TraderSummary`] recorded whilst trading once the loop has stopped.
+    pub async fn run_async(mut self) -> TraderSummary
+    where
+        Data: Stream<Item = MarketEvent<DataKind>> + Unpin,
+    {
+        'trading: loop {
+            // Prioritise remote Commands, then execution responses, then the next MarketEvent
+            tokio::select! {
+                biased;
+
+                command = self.command_rx.recv() => {
+                    if !self.action_remote_command(command_or_terminate(command)) {
+                        break 'trading;
                     }
+                }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
-                        }
+                Some(mut fill) = receive_fill(&mut self.fill_rx) => {
+                    self.trace_fill(&mut fill);
+                    self.event_tx.send(Event::Fill(fill.clone()));
+                    self.event_q.push_back(Event::Fill(fill));
+                }
+
+                market = StreamExt::next(&mut self.data) => match market {
+                    Some(market) => {
+                        self.event_tx.send(Event::Market(market.clone()));
+                        self.event_q.push_back(Event::Market(market));
                     }
+                    None => break 'trading,
+                }
+            }
+
+            // Handle Events in the event_q, actioning remote Commands whilst backing off retries
+            self.process_event_q(Backoff::Async).await;
+
+            if self.halted {
+                break 'trading;
+            }
+        }
+
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            "Trader trading loop stopped"
+        );
+
+        self.into_summary()
+    }
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
+    /// Actions a remote [`Command`]. Returns false if the [`Trader`] should stop trading.
+    fn action_remote_command(&mut self, command: Command) -> bool {
+        match command {
+            Command::Terminate(_) => return false,
+            Command::ExitPosition(market) => {
+                self.event_q
+                    .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
+            }
+            Command::PauseMarket(_, reply_tx) => {
+                self.paused = true;
+                info!(engine_id = %self.engine_id, market = ?self.market, "Trader paused");
+                let _ = reply_tx.send(Ok(()));
+            }
+            Command::ResumeMarket(_, reply_tx) => {
+                self.paused = false;
+                info!(engine_id = %self.engine_id, market = ?self.market, "Trader resumed");
+                let _ = reply_tx.send(Ok(()));
+            }
+            Command::Signal(signal) => {
+                if self.paused {
+                    debug!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        "Trader paused, ignoring routed Signal"
+                    );
+                } else {
+                    self.event_tx.send(Event::Signal(signal.clone()));
+                    self.event_q.push_back(Event::Signal(signal));
+                }
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    /// Handle the [`Event`]s in the event_q until it is empty and requires another
+    /// [`MarketEvent`]. Failed [`TraderStage`]s are actioned according to the [`ErrorPolicy`],
+    /// with retries delayed using the provided [`Backoff`].
+    async fn process_event_q(&mut self, backoff: Backoff) {
+        self.metrics.start_cycle();
+
+        while let Some(event) = self.event_q.pop_front() {
+            self.metrics.observe_event_q(self.event_q.len() + 1);
+
+            match event {
+                Event::Market(market) => {
+                    let trace = EventTrace::from(&market);
+                    self.latency
+                        .record_trace(&trace, &[LatencyStage::ExchangeToReceive]);
+                    if let Some(benchmark) = &mut self.benchmark {
+                        benchmark.update_market(&market);
+                    }
+
+                    // Strategy always sees the MarketEvent so it remains warm whilst paused
+                    let signal = self.strategy.generate_signal(&market);
+                    if let Some(mut signal) = signal.filter(|_| !self.paused) {
+                        self.trace_signal(trace, &mut signal);
+                        self.event_tx.send(Event::Signal(signal.clone()));
+                        self.event_q.push_back(Event::Signal(signal));
+                    }
+
+                    if let Some(Some(position_update)) = self
+                        .run_stage(backoff, TraderStage::UpdateFromMarket, |trader| {
+                            blocking(|| trader.portfolio.lock().update_from_market(&market))
+                        })
+                        .await
+                    {
+                        let position_update = Event::PositionUpdate(position_update);
+                        self.update_equity(std::slice::from_ref(&position_update));
+                        self.event_tx.send(position_update);
+                    }
+                }
+
+                Event::Signal(signal) => {
+                    match self
+                        .run_stage(backoff, TraderStage::GenerateOrder, |trader| {
+                            blocking(|| trader.portfolio.lock().generate_order(&signal))
+                        })
+                        .await
+                    {
+                        Some(Some(mut order)) => {
+                            self.metrics.order();
+                            self.trace_order(&mut order);
                             self.event_tx.send(Event::OrderNew(order.clone()));
                             self.event_q.push_back(Event::OrderNew(order));
                         }
+                        Some(None) => self.metrics.rejection(),
+                        None => {}
                     }
+                }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                Event::SignalForceExit(signal_force_exit) => {
+                    if let Some(Some(order)) = self
+                        .run_stage(backoff, TraderStage::GenerateExitOrder, |trader| {
+                            blocking(|| {
+                                trader
+                                    .portfolio
+                                    .lock()
+                                    .generate_exit_order(signal_force_exit.clone())
+                            })
+                        })
+                        .await
+                    {
+                        self.metrics.order();
+                        self.event_tx.send(Event::OrderNew(order.clone()));
+                        self.event_q.push_back(Event::OrderNew(order));
+                    }
+                }
 
+                Event::OrderNew(order) => {
+                    if let Some(mut fill) = self
+                        .run_stage(backoff, TraderStage::GenerateFill, |trader| {
+                            trader.execution.generate_fill(&order)
+                        })
+                        .await
+                    {
+                        self.trace_fill(&mut fill);
                         self.event_tx.send(Event::Fill(fill.clone()));
                         self.event_q.push_back(Event::Fill(fill));
                     }
+                }
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
-
+                Event::Fill(fill) => {
+                    if let Some(fill_side_effect_events) = self
+                        .run_stage(backoff, TraderStage::UpdateFromFill, |trader| {
+                            blocking(|| trader.portfolio.lock().update_from_fill(&fill))
+                        })
+                        .await
+                    {
+                        self.metrics.fill();
+                        self.update_equity(&fill_side_effect_events);
                         self.event_tx.send_many(fill_side_effect_events);
                     }
-                    _ => {}
                 }
+                _ => {}
+            }
+        }
+
+        self.metrics.finish_cycle();
+    }
+
+    /// Updates the strategy equity curve of the [`BenchmarkSummary`] & the [`EquityRecorder`], if
+    /// there are any, with any change in Portfolio equity communicated by the provided
+    /// [`Event`]s.
+    fn update_equity(&mut self, events: &[Event]) {
+        for event in events {
+            if let Some(benchmark) = &mut self.benchmark {
+                benchmark.update_equity(event);
             }
+            if let Some(equity) = &mut self.equity {
+                equity.record(event);
+            }
+        }
+    }
+
+    /// Stamps the [`EventTrace`] of the [`MarketEvent`] that yielded the [`Signal`] with the
+    /// signal time, & records the receive to signal latency.
+    fn trace_signal(&mut self, mut trace: EventTrace, signal: &mut Signal) {
+        trace.signal_time = Some(Utc::now());
+        self.latency
+            .record_trace(&trace, &[LatencyStage::ReceiveToSignal]);
+        signal.market_meta.trace = Some(trace);
+    }
+
+    /// Stamps the [`EventTrace`] propagated to the [`OrderEvent`] with the order time, & records
+    /// the signal to order latency.
+    fn trace_order(&mut self, order: &mut OrderEvent) {
+        if let Some(trace) = &mut order.market_meta.trace {
+            trace.order_time = Some(Utc::now());
+            self.latency
+                .record_trace(trace, &[LatencyStage::SignalToOrder]);
+        }
+    }
+
+    /// Stamps the [`EventTrace`] propagated to the [`FillEvent`] with the fill time, & records the
+    /// order to fill & tick to trade latencies.
+    fn trace_fill(&mut self, fill: &mut FillEvent) {
+        if let Some(trace) = &mut fill.market_meta.trace {
+            trace.fill_time = Some(Utc::now());
+            self.latency.record_trace(
+                trace,
+                &[LatencyStage::OrderToFill, LatencyStage::TickToTrade],
+            );
 
             debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                correlation_id = %trace.correlation_id,
+                tick_to_trade = ?trace.latency(LatencyStage::TickToTrade),
+                "Trader generated traced FillEvent"
             );
         }
     }
 
+    /// Runs a [`TraderStage`] operation, actioning any failure according to the [`ErrorPolicy`].
+    /// Every failure emits an [`Event::EngineError`] communicating the [`ErrorAction`] taken, and
+    /// retries are delayed by the exponential backoff of the [`ErrorPolicy`].
+    ///
+    /// Returns `None` if the operation ultimately failed & it's input [`Event`] was dropped, or
+    /// the [`Trader`] was terminated whilst backing off.
+    async fn run_stage<Output, Error, Operation>(
+        &mut self,
+        backoff: Backoff,
+        stage: TraderStage,
+        mut operation: Operation,
+    ) -> Option<Output>
+    where
+        Error: Into<TraderStageError>,
+        Operation: FnMut(&mut Self) -> Result<Output, Error>,
+    {
+        let policy = self.error_policy.stage(stage);
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let error = match operation(self) {
+                Ok(output) => return Some(output),
+                Err(error) => error.into(),
+            };
+
+            let action = policy.action(attempt);
+            error!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?stage,
+                attempt,
+                %error,
+                ?action,
+                "Trader stage failed"
+            );
+
+            self.event_tx.send(Event::EngineError(EngineErrorEvent {
+                time: Utc::now(),
+                engine_id: self.engine_id,
+                market: self.market.clone(),
+                stage,
+                attempt,
+                error: error.to_string(),
+                action,
+            }));
+
+            match action {
+                ErrorAction::Retry => match self.backoff(backoff, policy.backoff(attempt)).await {
+                    true => continue,
+                    false => return None,
+                },
+                ErrorAction::SkipEvent => return None,
+                ErrorAction::HaltMarket => {
+                    self.halt();
+                    return None;
+                }
+                ErrorAction::HaltEngine => {
+                    self.halt();
+                    self.request_engine_halt(EngineError::TraderStage {
+                        market: Box::new(self.market.clone()),
+                        stage,
+                        source: error,
+                    });
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Waits out the [`ErrorPolicy`] backoff before a failed [`TraderStage`] is retried. Whilst
+    /// backing off asynchronously, remote [`Command`]s continue to be actioned.
+    ///
+    /// Returns false if a [`Command::Terminate`] was received whilst backing off, in which case
+    /// this [`Trader`] is halted.
+    async fn backoff(&mut self, backoff: Backoff, duration: Duration) -> bool {
+        match backoff {
+            Backoff::Blocking => {
+                std::thread::sleep(duration);
+                true
+            }
+            Backoff::Async => {
+                let sleep = tokio::time::sleep(duration);
+                tokio::pin!(sleep);
+
+                loop {
+                    tokio::select! {
+                        biased;
+
+                        command = self.command_rx.recv() => {
+                            if !self.action_remote_command(command_or_terminate(command)) {
+                                self.halt();
+                                break false;
+                            }
+                        }
+
+                        _ = &mut sleep => break true,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stops this [`Trader`] from processing any further [`Event`]s.
+    fn halt(&mut self) {
+        self.event_q.clear();
+        self.halted = true;
+    }
+
+    /// Requests the [`Engine`](super::Engine) halts every [`Trader`] due to the provided
+    /// [`EngineError`].
+    fn request_engine_halt(&self, error: EngineError) {
+        match &self.engine_halt_tx {
+            Some(engine_halt_tx) => {
+                if engine_halt_tx.send(error).is_err() {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        why = "Engine halt receiver dropped",
+                        "cannot request Engine halt"
+                    );
+                }
+            }
+            None => warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                why = "Trader is not running within an Engine",
+                "cannot request Engine halt"
+            ),
+        }
+    }
+
+    /// Sets the transmitter used to request the [`Engine`](super::Engine) halts, see
+    /// [`ErrorAction::HaltEngine`].
+    pub(super) fn set_engine_halt_tx(
+        &mut self,
+        engine_halt_tx: mpsc::UnboundedSender<EngineError>,
+    ) {
+        self.engine_halt_tx = Some(engine_halt_tx);
+    }
+
+    /// Sets the [`BenchmarkSummary`] this [`Trader`] updates with it's [`MarketEvent`]s & changes in
+    /// Portfolio equity.
+    pub(super) fn set_benchmark_summary(&mut self, benchmark: Option<BenchmarkSummary>) {
+        self.benchmark = benchmark;
+    }
+
+    /// Sets the [`EquityRecorder`] this [`Trader`] updates with changes in Portfolio equity.
+    pub(super) fn set_equity_recorder(&mut self, equity: Option<EquityRecorder>) {
+        self.equity = equity;
+    }
+
+    /// Consumes the [`Trader`], returning the [`TraderSummary`] it recorded whilst trading.
+    fn into_summary(self) -> TraderSummary {
+        TraderSummary {
+            latency: self.latency,
+            benchmark: self.benchmark,
+            equity: self.equity,
+        }
+    }
+
     /// Returns a [`Command`] if one has been received.
     fn receive_remote_command(&mut self) -> Option<Command> {
         match self.command_rx.try_recv() {
@@ -262,6 +678,53 @@ where
     }
 }
 
+/// How a [`Trader`] waits out the [`ErrorPolicy`] backoff before retrying a failed
+/// [`TraderStage`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Backoff {
+    /// Block the current thread, used by the synchronous [`Trader::run`].
+    Blocking,
+    /// Sleep asynchronously whilst actioning remote [`Command`]s, used by [`Trader::run_async`].
+    Async,
+}
+
+/// Returns the received [`Command`], or synthesises a [`Command::Terminate`] if the remote
+/// [`Command`] transmitter has been dropped.
+fn command_or_terminate(command: Option<Command>) -> Command {
+    command.unwrap_or_else(|| {
+        warn!(
+            action = "synthesising a Command::Terminate",
+            "remote Command transmitter has been dropped"
+        );
+        Command::Terminate("remote command transmitter dropped".to_owned())
+    })
+}
+
+/// Runs a blocking Portfolio operation (eg/ waiting on the shared Portfolio lock, or a
+/// repository round trip). When called from a multi-threaded tokio runtime (ie/
+/// [`Trader::run_async`]), the worker thread is handed over to the runtime's other tasks for the
+/// duration of the operation via [`tokio::task::block_in_place`].
+fn blocking<Output, Operation>(operation: Operation) -> Output
+where
+    Operation: FnOnce() -> Output,
+{
+    match tokio::runtime::Handle::try_current().map(|runtime| runtime.runtime_flavor()) {
+        Ok(tokio::runtime::RuntimeFlavor::MultiThread) => tokio::task::block_in_place(operation),
+        _ => operation(),
+    }
+}
+
+/// Receives the next asynchronous [`FillEvent`] execution response. Pends forever if the
+/// [`Trader`] has no fill_rx, so it never completes a `tokio::select!` branch.
+async fn receive_fill(
+    fill_rx: &mut Option<mpsc::UnboundedReceiver<FillEvent>>,
+) -> Option<FillEvent> {
+    match fill_rx {
+        Some(fill_rx) => fill_rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
 /// Builder to construct [`Trader`] instances.
 #[derive(Debug, Default)]
 pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -281,6 +744,9 @@ where
     data: Option<Data>,
     strategy: Option<Strategy>,
     execution: Option<Execution>,
+    fill_rx: Option<mpsc::UnboundedReceiver<FillEvent>>,
+    error_policy: Option<ErrorPolicy>,
+    metric_tx: Option<mpsc::UnboundedSender<Metric>>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -304,6 +770,9 @@ where
             data: None,
             strategy: None,
             execution: None,
+            fill_rx: None,
+            error_policy: None,
+            metric_tx: None,
             _statistic_marker: None,
         }
     }
@@ -364,16 +833,49 @@ where
         }
     }
 
+    /// Optional receiver of asynchronous [`FillEvent`] execution responses, see
+    /// [`Trader::run_async`].
+    pub fn fill_rx(self, value: mpsc::UnboundedReceiver<FillEvent>) -> Self {
+        Self {
+            fill_rx: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`ErrorPolicy`] for actioning failed [`TraderStage`]s. Defaults to
+    /// [`ErrorPolicy::default`].
+    pub fn error_policy(self, value: ErrorPolicy) -> Self {
+        Self {
+            error_policy: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional transmitter for the `trader` [`Metric`]s recorded by the [`Trader`] event loop.
+    pub fn metric_tx(self, value: mpsc::UnboundedSender<Metric>) -> Self {
+        Self {
+            metric_tx: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        let engine_id = self
+            .engine_id
+            .ok_or(EngineError::BuilderIncomplete("engine_id"))?;
+        let market = self
+            .market
+            .ok_or(EngineError::BuilderIncomplete("market"))?;
+
         Ok(Trader {
-            engine_id: self
-                .engine_id
-                .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
-            market: self
-                .market
-                .ok_or(EngineError::BuilderIncomplete("market"))?,
+            metrics: TraderMetrics::new(self.metric_tx, engine_id, &market),
+            latency: LatencySummary::new(),
+            benchmark: None,
+            equity: None,
+            engine_id,
+            market,
             command_rx: self
                 .command_rx
                 .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
@@ -391,6 +893,11 @@ where
             execution: self
                 .execution
                 .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            fill_rx: self.fill_rx,
+            error_policy: self.error_policy.unwrap_or_default(),
+            engine_halt_tx: None,
+            halted: false,
+            paused: false,
             _statistic_marker: PhantomData::default(),
         })
     }
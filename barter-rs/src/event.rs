@@ -1,4 +1,5 @@
 use crate::{
+    engine::policy::EngineErrorEvent,
     execution::FillEvent,
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
@@ -7,10 +8,12 @@ use crate::{
     strategy::{Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
-use tokio::sync::mpsc;
+use std::fmt::{Debug, Display, Formatter};
+use tokio::sync::{broadcast, mpsc};
 use tracing::warn;
+use uuid::Uuid;
 
 /// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
 /// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
@@ -28,6 +31,98 @@ pub enum Event {
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
+    EngineError(EngineErrorEvent),
+}
+
+/// Correlation identifier & timing metadata of the [`MarketEvent`] that (in)directly yielded a
+/// [`Signal`], [`OrderEvent`] or [`FillEvent`]. Carried through the [`Event`] chain in each
+/// [`MarketMeta`](crate::data::MarketMeta), and stamped by the
+/// [`Trader`](crate::engine::trader::Trader) as each stage completes.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EventTrace {
+    /// Unique identifier shared by every [`Event`] yielded by the same [`MarketEvent`].
+    pub correlation_id: Uuid,
+    /// Exchange timestamp of the source [`MarketEvent`].
+    pub exchange_time: DateTime<Utc>,
+    /// Time the source [`MarketEvent`] was received.
+    pub received_time: DateTime<Utc>,
+    /// Time the [`Signal`] was generated.
+    pub signal_time: Option<DateTime<Utc>>,
+    /// Time the [`OrderEvent`] was generated.
+    pub order_time: Option<DateTime<Utc>>,
+    /// Time the [`FillEvent`] was generated.
+    pub fill_time: Option<DateTime<Utc>>,
+}
+
+impl<T> From<&MarketEvent<T>> for EventTrace {
+    fn from(market: &MarketEvent<T>) -> Self {
+        Self {
+            correlation_id: Uuid::new_v4(),
+            exchange_time: market.exchange_time,
+            received_time: market.received_time,
+            signal_time: None,
+            order_time: None,
+            fill_time: None,
+        }
+    }
+}
+
+impl EventTrace {
+    /// Determines the latency of the provided [`LatencyStage`], if both of it's timestamps have
+    /// been stamped.
+    pub fn latency(&self, stage: LatencyStage) -> Option<Duration> {
+        let (start, end) = match stage {
+            LatencyStage::ExchangeToReceive => (Some(self.exchange_time), Some(self.received_time)),
+            LatencyStage::ReceiveToSignal => (Some(self.received_time), self.signal_time),
+            LatencyStage::SignalToOrder => (self.signal_time, self.order_time),
+            LatencyStage::OrderToFill => (self.order_time, self.fill_time),
+            LatencyStage::TickToTrade => (Some(self.exchange_time), self.fill_time),
+        };
+
+        Some(end?.signed_duration_since(start?))
+    }
+}
+
+/// Stage of the [`Event`] chain between two [`EventTrace`] timestamps.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum LatencyStage {
+    /// Exchange timestamp to [`MarketEvent`] received.
+    ExchangeToReceive,
+    /// [`MarketEvent`] received to [`Signal`] generated.
+    ReceiveToSignal,
+    /// [`Signal`] generated to [`OrderEvent`] generated.
+    SignalToOrder,
+    /// [`OrderEvent`] generated to [`FillEvent`] generated.
+    OrderToFill,
+    /// Exchange timestamp to [`FillEvent`] generated.
+    TickToTrade,
+}
+
+impl LatencyStage {
+    /// Every [`LatencyStage`] in the order they occur.
+    pub const ALL: [LatencyStage; 5] = [
+        LatencyStage::ExchangeToReceive,
+        LatencyStage::ReceiveToSignal,
+        LatencyStage::SignalToOrder,
+        LatencyStage::OrderToFill,
+        LatencyStage::TickToTrade,
+    ];
+}
+
+impl Display for LatencyStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LatencyStage::ExchangeToReceive => "exchange -> receive",
+                LatencyStage::ReceiveToSignal => "receive -> signal",
+                LatencyStage::SignalToOrder => "signal -> order",
+                LatencyStage::OrderToFill => "order -> fill",
+                LatencyStage::TickToTrade => "tick -> trade",
+            }
+        )
+    }
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
@@ -85,3 +180,39 @@ impl EventTx {
         }
     }
 }
+
+/// Transmitter for broadcasting Barter [`Event`]s to any number of subscribers, eg/ the
+/// [`HttpApi`](crate::engine::http::HttpApi) event stream. [`Event`]s sent whilst there are no
+/// subscribers are dropped.
+#[derive(Debug, Clone)]
+pub struct EventBroadcastTx {
+    /// [`Event`] broadcast transmitter to send [`Event`]s to every subscriber.
+    event_tx: broadcast::Sender<Event>,
+}
+
+impl MessageTransmitter<Event> for EventBroadcastTx {
+    fn send(&mut self, message: Event) {
+        let _ = self.event_tx.send(message);
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        messages.into_iter().for_each(|message| {
+            let _ = self.event_tx.send(message);
+        })
+    }
+}
+
+impl EventBroadcastTx {
+    /// Constructs a new [`EventBroadcastTx`] instance. Subscribers that fall more than `capacity`
+    /// [`Event`]s behind skip the oldest [`Event`]s.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            event_tx: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// Subscribe to every [`Event`] sent after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.event_tx.subscribe()
+    }
+}
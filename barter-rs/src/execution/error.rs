@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
@@ -5,4 +6,7 @@ use thiserror::Error;
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("Cannot simulate a fill at a non-positive close price: {0}")]
+    InvalidClosePrice(Decimal),
 }
@@ -2,6 +2,7 @@ use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Barter execution module specific errors.
@@ -28,9 +29,9 @@ pub struct FillEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// abs(Quantity) * ClosePrice, excluding TotalFees
-    pub fill_value_gross: f64,
+    pub fill_value_gross: Decimal,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
 }
@@ -57,13 +58,13 @@ pub struct Fees {
 
 impl Fees {
     /// Calculates the sum of every [FeeAmount] in [Fees].
-    pub fn calculate_total_fees(&self) -> f64 {
+    pub fn calculate_total_fees(&self) -> Decimal {
         self.exchange + self.network + self.slippage
     }
 }
 
-/// Communicative type alias for Fee amount as f64.
-pub type FeeAmount = f64;
+/// Communicative type alias for Fee amount as Decimal.
+pub type FeeAmount = Decimal;
 
 /// Builder to construct [FillEvent] instances.
 #[derive(Debug, Default)]
@@ -73,8 +74,8 @@ pub struct FillEventBuilder {
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
-    pub fill_value_gross: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub fill_value_gross: Option<Decimal>,
     pub fees: Option<Fees>,
 }
 
@@ -118,14 +119,14 @@ impl FillEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
         }
     }
 
-    pub fn fill_value_gross(self, value: f64) -> Self {
+    pub fn fill_value_gross(self, value: Decimal) -> Self {
         Self {
             fill_value_gross: Some(value),
             ..self
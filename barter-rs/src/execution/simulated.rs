@@ -1,4 +1,5 @@
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -23,7 +24,7 @@ pub struct SimulatedExecution {
 impl ExecutionClient for SimulatedExecution {
     fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
         // Assume (for now) that all orders are filled at the market price
-        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
+        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order)?;
 
         Ok(FillEvent {
             time: Utc::now(),
@@ -47,12 +48,17 @@ impl SimulatedExecution {
     }
 
     /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
-    fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
-        order.quantity.abs() * order.market_meta.close
+    fn calculate_fill_value_gross(order: &OrderEvent) -> Result<Decimal, ExecutionError> {
+        let close = order.market_meta.close;
+        if close <= Decimal::ZERO {
+            return Err(ExecutionError::InvalidClosePrice(close));
+        }
+
+        Ok(order.quantity.abs() * close)
     }
 
     /// Calculates the simulated [`Fees`] a [`FillEvent`] will incur, based on the input [`OrderEvent`].
-    fn calculate_fees(&self, fill_value_gross: &f64) -> Fees {
+    fn calculate_fees(&self, fill_value_gross: &Decimal) -> Fees {
         Fees {
             exchange: self.fees_pct.exchange * fill_value_gross,
             slippage: self.fees_pct.slippage * fill_value_gross,
@@ -65,28 +71,29 @@ impl SimulatedExecution {
 mod tests {
     use super::*;
     use crate::test_util::order_event;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn should_generate_ok_fill_event_with_valid_order_event_provided() {
         let simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.1,
-                slippage: 0.05,
-                network: 0.0,
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0.0),
             },
         });
 
         let mut input_order = order_event();
-        input_order.quantity = 10.0;
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = dec!(10.0);
+        input_order.market_meta.close = dec!(10.0);
 
         let actual_result = simulated_execution.generate_fill(&input_order);
 
-        let expected_fill_value_gross = 100.0;
+        let expected_fill_value_gross = dec!(100.0);
         let expected_fees = Fees {
-            exchange: 10.0,
-            slippage: 5.0,
-            network: 0.0,
+            exchange: dec!(10.0),
+            slippage: dec!(5.0),
+            network: dec!(0.0),
         };
 
         assert!(actual_result.is_ok());
@@ -98,12 +105,12 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly() {
         let mut input_order = order_event();
-        input_order.quantity = 100.0;
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = dec!(100.0);
+        input_order.market_meta.close = dec!(10.0);
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order).unwrap();
 
-        let expected = 100.0 * 10.0;
+        let expected = dec!(1000.0);
 
         assert_eq!(actual, expected)
     }
@@ -111,34 +118,45 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly_with_negative_order_quantity_provided() {
         let mut input_order = order_event();
-        input_order.quantity = -(100.0);
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = dec!(-100.0);
+        input_order.market_meta.close = dec!(10.0);
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order).unwrap();
 
-        let expected = (100.0 * 10.0) as f64;
+        let expected = dec!(1000.0);
 
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn should_return_error_when_calculating_fill_value_gross_with_zero_close() {
+        let mut input_order = order_event();
+        input_order.quantity = dec!(100.0);
+        input_order.market_meta.close = Decimal::ZERO;
+
+        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+
+        assert!(matches!(actual, Err(ExecutionError::InvalidClosePrice(_))));
+    }
+
     #[test]
     fn should_calculate_simulated_fees_correctly() {
         let simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.5,
-                slippage: 0.1,
-                network: 0.001,
+                exchange: dec!(0.5),
+                slippage: dec!(0.1),
+                network: dec!(0.001),
             },
         });
 
-        let input_fill_value_gross = 100.0;
+        let input_fill_value_gross = dec!(100.0);
 
         let actual_result = simulated_execution.calculate_fees(&input_fill_value_gross);
 
         let expected = Fees {
-            exchange: 50.0,
-            slippage: 10.0,
-            network: 0.1,
+            exchange: dec!(50.0),
+            slippage: dec!(10.0),
+            network: dec!(0.1),
         };
 
         assert_eq!(actual_result, expected)
@@ -0,0 +1,21 @@
+use crate::portfolio::{position::PositionId, repository::error::RepositoryError};
+use thiserror::Error;
+
+/// All errors generated in the barter::journal module.
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("Failed to read or write journal due to: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to deserialize/serialize JSON due to: {0}")]
+    JsonSerDeError(#[from] serde_json::Error),
+
+    #[error("Journal sequence gap: expected {expected} but found {found}")]
+    SequenceGap { expected: u64, found: u64 },
+
+    #[error("Journal references Position {0} that is not open")]
+    PositionNotOpen(PositionId),
+
+    #[error("Failed to interact with repository")]
+    RepositoryInteraction(#[from] RepositoryError),
+}
@@ -0,0 +1,393 @@
+use crate::{
+    event::{Event, MessageTransmitter},
+    journal::error::JournalError,
+};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::Arc,
+};
+use tracing::{error, warn};
+
+/// Barter journal module specific errors.
+pub mod error;
+
+/// Replays a journal of sequenced [`Event`]s to rebuild Portfolio state in a repository.
+pub mod replay;
+
+/// Communicates a monotonically increasing sequence number assigned to every journaled [`Event`].
+pub type Sequence = u64;
+
+/// Append-only journal record containing an [`Event`] & the [`Sequence`] number it was assigned.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct JournalEntry {
+    pub sequence: Sequence,
+    /// Timestamp the [`Event`] was appended to the journal.
+    pub time: DateTime<Utc>,
+    pub event: Event,
+}
+
+/// [`MessageTransmitter`] that appends every [`Event`] to an append-only journal as a line of
+/// JSON, assigning each a contiguous [`Sequence`] number.
+///
+/// Cloned [`JournalTx`]s share the same underlying writer & sequence, so one journal can be
+/// distributed to every [`Trader`](crate::engine::trader::Trader) of an
+/// [`Engine`](crate::engine::Engine).
+#[derive(Debug, Clone)]
+pub struct JournalTx<W>
+where
+    W: Write,
+{
+    journal: Arc<Mutex<JournalWriter<W>>>,
+}
+
+#[derive(Debug)]
+struct JournalWriter<W>
+where
+    W: Write,
+{
+    next_sequence: Sequence,
+    writer: W,
+}
+
+impl<W> JournalWriter<W>
+where
+    W: Write,
+{
+    /// Serialise the [`Event`] as the next [`JournalEntry`] line. The sequence is only advanced
+    /// once the line has been fully written.
+    fn append(&mut self, event: Event) -> Result<(), JournalError> {
+        let entry = JournalEntry {
+            sequence: self.next_sequence,
+            time: Utc::now(),
+            event,
+        };
+
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+        self.writer.write_all(&line)?;
+
+        self.next_sequence += 1;
+        Ok(())
+    }
+}
+
+impl<W> MessageTransmitter<Event> for JournalTx<W>
+where
+    W: Write,
+{
+    fn send(&mut self, message: Event) {
+        let mut journal = self.journal.lock();
+
+        let appended = journal
+            .append(message)
+            .and_then(|_| journal.writer.flush().map_err(JournalError::from));
+
+        if let Err(error) = appended {
+            error!(
+                ?error,
+                sequence = journal.next_sequence,
+                "failed to append Event to journal"
+            );
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        let mut journal = self.journal.lock();
+
+        for message in messages {
+            if let Err(error) = journal.append(message) {
+                error!(
+                    ?error,
+                    sequence = journal.next_sequence,
+                    "failed to append Event to journal"
+                );
+            }
+        }
+
+        if let Err(error) = journal.writer.flush() {
+            error!(?error, "failed to flush journal");
+        }
+    }
+}
+
+impl<W> JournalTx<W>
+where
+    W: Write,
+{
+    /// Constructs a new [`JournalTx`] that appends to the provided writer, starting with the
+    /// [`Sequence`] provided.
+    pub fn new(writer: W, next_sequence: Sequence) -> Self {
+        Self {
+            journal: Arc::new(Mutex::new(JournalWriter {
+                next_sequence,
+                writer,
+            })),
+        }
+    }
+
+    /// Returns the [`Sequence`] that will be assigned to the next journaled [`Event`].
+    pub fn next_sequence(&self) -> Sequence {
+        self.journal.lock().next_sequence
+    }
+}
+
+impl JournalTx<BufWriter<File>> {
+    /// Opens (or creates) the journal file at the provided path in append mode. If the journal
+    /// already contains entries, the [`Sequence`] continues from the last one.
+    ///
+    /// A torn final record (eg/ a partially written line left by a crash) is truncated before
+    /// appending, whereas invalid records before the final line are still an error.
+    pub fn open<P>(path: P) -> Result<Self, JournalError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        // Determine the next Sequence & valid length by reading any existing entries
+        let recovered = recover_journal(BufReader::new(&mut file))?;
+        if recovered.valid_len < file.metadata()?.len() {
+            warn!(
+                path = %path.display(),
+                valid_len = recovered.valid_len,
+                "truncating torn final record of journal"
+            );
+            file.set_len(recovered.valid_len)?;
+        }
+        if recovered.missing_newline {
+            file.write_all(b"\n")?;
+        }
+
+        Ok(Self::new(BufWriter::new(file), recovered.next_sequence))
+    }
+}
+
+/// State of an existing journal recovered by [`recover_journal`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct RecoveredJournal {
+    next_sequence: Sequence,
+    /// Length in bytes of the journal excluding any torn final record.
+    valid_len: u64,
+    /// Final record is complete but was written without it's trailing newline.
+    missing_newline: bool,
+}
+
+/// Reads every record of an existing journal to determine the next [`Sequence`], detecting a
+/// torn final record that should be truncated.
+fn recover_journal<R>(mut reader: R) -> Result<RecoveredJournal, JournalError>
+where
+    R: BufRead,
+{
+    let mut recovered = RecoveredJournal {
+        next_sequence: 0,
+        valid_len: 0,
+        missing_newline: false,
+    };
+    let mut line = Vec::new();
+
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        if read == 0 {
+            return Ok(recovered);
+        }
+
+        let is_final = !line.ends_with(b"\n");
+        let record = std::str::from_utf8(&line).map(str::trim);
+
+        match record {
+            Ok("") => {}
+            Ok(record) => match serde_json::from_str::<JournalEntry>(record) {
+                Ok(entry) => {
+                    recovered.next_sequence = entry.sequence + 1;
+                    recovered.missing_newline = is_final;
+                }
+                // Torn final record - exclude it from the valid journal
+                Err(_) if is_final => return Ok(recovered),
+                Err(error) => return Err(JournalError::from(error)),
+            },
+            Err(_) if is_final => return Ok(recovered),
+            Err(error) => {
+                return Err(JournalError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    error,
+                )))
+            }
+        }
+
+        recovered.valid_len += read as u64;
+    }
+}
+
+/// Iterator that reads [`JournalEntry`]s line-by-line from a journal. Blank lines are skipped.
+#[derive(Debug)]
+pub struct JournalReader<R>
+where
+    R: BufRead,
+{
+    lines: std::io::Lines<R>,
+}
+
+impl<R> Iterator for JournalReader<R>
+where
+    R: BufRead,
+{
+    type Item = Result<JournalEntry, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(error) => return Some(Err(JournalError::Io(error))),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            return Some(serde_json::from_str(&line).map_err(JournalError::from));
+        }
+    }
+}
+
+impl<R> JournalReader<R>
+where
+    R: BufRead,
+{
+    /// Constructs a new [`JournalReader`] that reads [`JournalEntry`]s from the provided reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+        }
+    }
+}
+
+impl JournalReader<BufReader<File>> {
+    /// Opens the journal file at the provided path for reading.
+    pub fn open<P>(path: P) -> Result<Self, JournalError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, order_event};
+
+    #[test]
+    fn journal_tx_appends_sequenced_entries_that_can_be_read_back() {
+        let path = std::env::temp_dir().join(format!("{}.journal", uuid::Uuid::new_v4()));
+
+        let mut journal_tx = JournalTx::open(&path).unwrap();
+        journal_tx.send(Event::OrderNew(order_event()));
+        journal_tx.send_many(vec![Event::Fill(fill_event()), Event::OrderUpdate]);
+        assert_eq!(journal_tx.next_sequence(), 3);
+        drop(journal_tx);
+
+        // Re-opening an existing journal continues from the last Sequence
+        let mut journal_tx = JournalTx::open(&path).unwrap();
+        assert_eq!(journal_tx.next_sequence(), 3);
+        journal_tx.send(Event::OrderUpdate);
+        drop(journal_tx);
+
+        let entries = JournalReader::open(&path)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let sequences = entries
+            .iter()
+            .map(|entry| entry.sequence)
+            .collect::<Vec<_>>();
+        assert_eq!(sequences, vec![0, 1, 2, 3]);
+        assert!(matches!(entries[0].event, Event::OrderNew(_)));
+        assert!(matches!(entries[1].event, Event::Fill(_)));
+        assert_eq!(entries[2].event, Event::OrderUpdate);
+    }
+
+    #[test]
+    fn journal_tx_open_truncates_torn_final_record() {
+        let path = std::env::temp_dir().join(format!("{}.journal", uuid::Uuid::new_v4()));
+
+        let mut journal_tx = JournalTx::open(&path).unwrap();
+        journal_tx.send_many(vec![Event::OrderUpdate, Event::OrderUpdate]);
+        drop(journal_tx);
+
+        // Simulate a crash part way through writing the next record
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"sequence\":2,\"time\":\"2023-01-")
+            .unwrap();
+        drop(file);
+
+        let mut journal_tx = JournalTx::open(&path).unwrap();
+        assert_eq!(journal_tx.next_sequence(), 2);
+        journal_tx.send(Event::OrderUpdate);
+        drop(journal_tx);
+
+        let entries = JournalReader::open(&path)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let sequences = entries
+            .iter()
+            .map(|entry| entry.sequence)
+            .collect::<Vec<_>>();
+        assert_eq!(sequences, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn recover_journal_detects_final_record_without_newline() {
+        let input = "{\"sequence\":0,\"time\":\"2023-01-01T00:00:00Z\",\"event\":\"OrderUpdate\"}";
+
+        let actual = recover_journal(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            actual,
+            RecoveredJournal {
+                next_sequence: 1,
+                valid_len: input.len() as u64,
+                missing_newline: true,
+            }
+        );
+
+        // Invalid records before the final line are still an error
+        let input = "not json\n{\"sequence\":0,\"time\":\"2023-01-01T00:00:00Z\",\"event\":\"OrderUpdate\"}\n";
+        assert!(matches!(
+            recover_journal(input.as_bytes()),
+            Err(JournalError::JsonSerDeError(_))
+        ));
+    }
+
+    #[test]
+    fn journal_reader_skips_blank_lines_and_errors_on_invalid_lines() {
+        let input = "\n{\"sequence\":0,\"time\":\"2023-01-01T00:00:00Z\",\"event\":\"OrderUpdate\"}\n\nnot json\n";
+
+        let mut reader = JournalReader::new(input.as_bytes());
+
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!(first.sequence, 0);
+        assert_eq!(first.event, Event::OrderUpdate);
+
+        assert!(matches!(
+            reader.next(),
+            Some(Err(JournalError::JsonSerDeError(_)))
+        ));
+        assert!(reader.next().is_none());
+    }
+}
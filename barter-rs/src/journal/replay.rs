@@ -0,0 +1,327 @@
+use crate::{
+    event::Event,
+    journal::{error::JournalError, JournalEntry, Sequence},
+    portfolio::{
+        position::{Position, PositionExit, PositionUpdate},
+        repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
+    },
+    statistic::summary::{Initialiser, PositionSummariser},
+};
+use barter_integration::model::MarketId;
+use std::marker::PhantomData;
+use uuid::Uuid;
+
+/// Rebuilds the open [`Position`]s, exited [`Position`]s, [`Balance`](crate::portfolio::Balance)
+/// & per-market statistics of an [`Engine`](crate::engine::Engine) by replaying its journaled
+/// [`Event`]s into a repository.
+///
+/// Only the state changing [`Event`]s are applied (`PositionNew`, `PositionUpdate`,
+/// `PositionExit` & `Balance`) since they are the journaled side effects of every
+/// `MarketEvent` & `FillEvent` the Portfolio processed.
+#[derive(Debug)]
+pub struct JournalReplay<Repository, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Statistic: Initialiser + PositionSummariser,
+{
+    engine_id: Uuid,
+    repository: Repository,
+    statistic_config: Statistic::Config,
+    last_sequence: Option<Sequence>,
+    _statistic_marker: PhantomData<Statistic>,
+}
+
+impl<Repository, Statistic> JournalReplay<Repository, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Statistic: Initialiser + PositionSummariser,
+{
+    /// Constructs a new [`JournalReplay`] that rebuilds the state of the provided engine_id in
+    /// the provided repository. Market statistics that are not yet present in the repository are
+    /// initialised using the statistic config.
+    pub fn new(
+        engine_id: Uuid,
+        repository: Repository,
+        statistic_config: Statistic::Config,
+    ) -> Self {
+        Self {
+            engine_id,
+            repository,
+            statistic_config,
+            last_sequence: None,
+            _statistic_marker: PhantomData,
+        }
+    }
+
+    /// Applies every [`JournalEntry`] in order, returning the [`Sequence`] of the last entry
+    /// applied. Fails on the first gap in the journal [`Sequence`].
+    pub fn replay<Entries>(&mut self, entries: Entries) -> Result<Option<Sequence>, JournalError>
+    where
+        Entries: IntoIterator<Item = Result<JournalEntry, JournalError>>,
+    {
+        for entry in entries {
+            self.apply(entry?)?;
+        }
+
+        Ok(self.last_sequence)
+    }
+
+    /// Applies a single [`JournalEntry`] to the repository.
+    pub fn apply(&mut self, entry: JournalEntry) -> Result<(), JournalError> {
+        // Validate the journal is contiguous
+        if let Some(last_sequence) = self.last_sequence {
+            if entry.sequence != last_sequence + 1 {
+                return Err(JournalError::SequenceGap {
+                    expected: last_sequence + 1,
+                    found: entry.sequence,
+                });
+            }
+        }
+
+        match entry.event {
+            Event::PositionNew(position) => {
+                self.repository.set_open_position(position)?;
+            }
+            Event::PositionUpdate(update) => {
+                let mut position = self.open_position(&update.position_id)?;
+                apply_position_update(&mut position, &update);
+                self.repository.set_open_position(position)?;
+            }
+            Event::PositionExit(exit) => {
+                let mut position = self
+                    .repository
+                    .remove_position(&exit.position_id)?
+                    .ok_or_else(|| JournalError::PositionNotOpen(exit.position_id.clone()))?;
+                apply_position_exit(&mut position, &exit);
+
+                // Update statistics for the exited Position market
+                let market_id = MarketId::new(&position.exchange, &position.instrument);
+                let mut statistics = match self.repository.get_statistics(&market_id) {
+                    Ok(statistics) => statistics,
+                    Err(RepositoryError::ExpectedDataNotPresentError) => {
+                        Statistic::init(self.statistic_config)
+                    }
+                    Err(error) => return Err(JournalError::from(error)),
+                };
+                statistics.update(&position);
+
+                self.repository.set_statistics(market_id, statistics)?;
+                self.repository
+                    .set_exited_position(self.engine_id, position)?;
+            }
+            Event::Balance(balance) => {
+                self.repository.set_balance(self.engine_id, balance)?;
+            }
+            _ => {}
+        }
+
+        self.last_sequence = Some(entry.sequence);
+        Ok(())
+    }
+
+    /// Returns the [`Sequence`] of the last [`JournalEntry`] applied.
+    pub fn last_sequence(&self) -> Option<Sequence> {
+        self.last_sequence
+    }
+
+    /// Consumes the [`JournalReplay`], returning the rebuilt repository.
+    pub fn into_repository(self) -> Repository {
+        self.repository
+    }
+
+    fn open_position(&mut self, position_id: &String) -> Result<Position, JournalError> {
+        self.repository
+            .get_open_position(position_id)?
+            .ok_or_else(|| JournalError::PositionNotOpen(position_id.clone()))
+    }
+}
+
+/// Applies the change in state communicated by a [`PositionUpdate`] to an open [`Position`].
+fn apply_position_update(position: &mut Position, update: &PositionUpdate) {
+    position.meta.update_time = update.update_time;
+    position.current_symbol_price = update.current_symbol_price;
+    position.current_value_gross = update.current_value_gross;
+    position.unrealised_profit_loss = update.unrealised_profit_loss;
+    position.update_excursions(update.unrealised_profit_loss);
+}
+
+/// Applies the change in state communicated by a [`PositionExit`] to an open [`Position`].
+fn apply_position_exit(position: &mut Position, exit: &PositionExit) {
+    position.exit_fees = exit.exit_fees;
+    position.exit_fees_total = exit.exit_fees_total;
+    position.exit_avg_price_gross = exit.exit_avg_price_gross;
+    position.exit_value_gross = exit.exit_value_gross;
+    position.realised_profit_loss = exit.realised_profit_loss;
+    position.unrealised_profit_loss = exit.realised_profit_loss;
+    position.update_excursions(exit.realised_profit_loss);
+    position.meta.update_time = exit.exit_time;
+    position.meta.exit_balance = Some(exit.exit_balance);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{
+            allocator::DefaultAllocator, portfolio::MetaPortfolio,
+            repository::in_memory::InMemoryRepository, risk::DefaultRisk, FillUpdater,
+            MarketUpdater,
+        },
+        statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        strategy::Decision,
+        test_util::{fill_event, market_event_trade},
+    };
+    use barter_data::event::DataKind;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn statistic_config() -> StatisticConfig {
+        StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }
+    }
+
+    #[test]
+    fn replay_rebuilds_portfolio_state_from_journaled_events() {
+        let engine_id = Uuid::new_v4();
+        let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(1000.0))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(statistic_config())
+            .build_and_init()
+            .unwrap();
+
+        // Drive the Portfolio through an entry, a market update & an exit, journaling the Events
+        let mut events = Vec::new();
+
+        let mut enter_fill = fill_event();
+        enter_fill.decision = Decision::Long;
+        events.extend(portfolio.update_from_fill(&enter_fill).unwrap());
+
+        let mut market_event = market_event_trade(Side::Buy);
+        market_event.exchange = market.exchange.clone();
+        market_event.instrument = market.instrument.clone();
+        if let DataKind::Trade(trade) = &mut market_event.kind {
+            trade.price = dec!(150.0);
+        }
+        events.extend(
+            portfolio
+                .update_from_market(&market_event)
+                .unwrap()
+                .map(Event::PositionUpdate),
+        );
+
+        let mut second_enter_fill = fill_event();
+        second_enter_fill.instrument = ("btc", "usdt", InstrumentKind::Spot).into();
+        events.extend(portfolio.update_from_fill(&second_enter_fill).unwrap());
+
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.quantity = dec!(-1.0);
+        exit_fill.fill_value_gross = dec!(150.0);
+        events.extend(portfolio.update_from_fill(&exit_fill).unwrap());
+
+        let expected_balance = events
+            .iter()
+            .rev()
+            .find_map(|event| match event {
+                Event::Balance(balance) => Some(*balance),
+                _ => None,
+            })
+            .unwrap();
+
+        let entries = events
+            .into_iter()
+            .enumerate()
+            .map(|(sequence, event)| {
+                Ok(JournalEntry {
+                    sequence: sequence as Sequence,
+                    time: Utc::now(),
+                    event,
+                })
+            })
+            .collect::<Vec<_>>();
+        let expected_last_sequence = entries.len() as Sequence - 1;
+
+        // Replay the journal into an empty repository
+        let mut replay = JournalReplay::new(
+            engine_id,
+            InMemoryRepository::<TradingSummary>::new(),
+            statistic_config(),
+        );
+        assert_eq!(
+            replay.replay(entries).unwrap(),
+            Some(expected_last_sequence)
+        );
+        let mut rebuilt = replay.into_repository();
+
+        let market_id = MarketId::from(&market);
+        let markets = [
+            market.clone(),
+            Market::new("binance", ("btc", "usdt", InstrumentKind::Spot)),
+        ];
+
+        assert_eq!(rebuilt.get_balance(engine_id).unwrap(), expected_balance);
+        assert_eq!(
+            rebuilt
+                .get_open_positions(engine_id, markets.iter())
+                .unwrap(),
+            portfolio
+                .get_open_positions(engine_id, markets.iter())
+                .unwrap()
+        );
+        assert_eq!(
+            rebuilt.get_exited_positions(engine_id).unwrap(),
+            portfolio.get_exited_positions(engine_id).unwrap()
+        );
+        let rebuilt_statistics = rebuilt.get_statistics(&market_id).unwrap();
+        let expected_statistics = portfolio.get_statistics(&market_id).unwrap();
+        assert_eq!(
+            rebuilt_statistics.pnl_returns.total,
+            expected_statistics.pnl_returns.total
+        );
+        assert_eq!(
+            rebuilt_statistics.pnl_returns.losses,
+            expected_statistics.pnl_returns.losses
+        );
+    }
+
+    #[test]
+    fn replay_fails_on_sequence_gap() {
+        let mut replay = JournalReplay::new(
+            Uuid::new_v4(),
+            InMemoryRepository::<TradingSummary>::new(),
+            statistic_config(),
+        );
+
+        let entry = |sequence| {
+            Ok(JournalEntry {
+                sequence,
+                time: Utc::now(),
+                event: Event::OrderUpdate,
+            })
+        };
+
+        let actual = replay.replay([entry(0), entry(1), entry(3)]);
+
+        assert!(matches!(
+            actual,
+            Err(JournalError::SequenceGap {
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert_eq!(replay.last_sequence(), Some(1));
+    }
+}
@@ -95,16 +95,19 @@
 //!     test_util,
 //! };
 //! use barter_integration::model::{Market, instrument::kind::InstrumentKind};
-//! use std::marker::PhantomData;
+//! use rust_decimal_macros::dec;
+//! use std::{collections::HashMap, marker::PhantomData};
 //! use uuid::Uuid;
 //!
 //! let components = PortfolioLego {
 //!     engine_id: Uuid::new_v4(),
 //!     markets: vec![Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))],
 //!     repository: InMemoryRepository::new(),
-//!     allocator: DefaultAllocator{ default_order_value: 100.0 },
+//!     allocator: DefaultAllocator{ default_order_value: dec!(100.0) },
 //!     risk: DefaultRisk{},
-//!     starting_cash: 10000.0,
+//!     starting_cash: dec!(10000.0),
+//!     contracts: HashMap::new(),
+//!     settlement_sources: HashMap::new(),
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
@@ -144,12 +147,13 @@
 //!         Fees, ExecutionClient,
 //!     }
 //! };
+//! use rust_decimal_macros::dec;
 //!
 //! let config = ExecutionConfig {
 //!     simulated_fees_pct: Fees {
-//!         exchange: 0.1,
-//!         slippage: 0.05, // Simulated slippage modelled as a Fee
-//!         network: 0.0,
+//!         exchange: dec!(0.1),
+//!         slippage: dec!(0.05), // Simulated slippage modelled as a Fee
+//!         network: dec!(0.0),
 //!     }
 //! };
 //!
@@ -232,11 +236,22 @@ pub mod execution;
 /// as changes in system state (eg/ PositionUpdate).
 pub mod event;
 
+/// Append-only journal of sequenced [`Event`](event::Event)s, as well as a replay tool that
+/// rebuilds Portfolio state from a journal. Useful for post-mortems & crash recovery.
+pub mod journal;
+
 /// Defines various iterative statistical methods that can be used to calculate trading performance
 /// metrics in one-pass. A trading performance summary implementation has been provided containing
 /// several key metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown.
 pub mod statistic;
 
+/// Options analytics - Black-Scholes & Black-76 pricing, implied volatility solving, [`Greeks`]
+/// per option Position, as well as an OptionChain view by expiry & strike that is fed from
+/// MarketEvents.
+///
+/// [`Greeks`]: options::greeks::Greeks
+pub mod options;
+
 /// Multi-threaded trading Engine capable of trading with an arbitrary number market pairs. Contains
 /// a Trader for each Market pair that consists of it's own Data, Strategy &
 /// Execution components, as well as shared access to a global Portfolio.
@@ -262,6 +277,7 @@ pub mod test_util {
         Exchange, Side,
     };
     use chrono::Utc;
+    use rust_decimal::Decimal;
     use std::ops::Add;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
@@ -273,8 +289,8 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Trade(PublicTrade {
                 id: "trade_id".to_string(),
-                price: 1000.0,
-                amount: 1.0,
+                price: Decimal::from(1000),
+                amount: Decimal::ONE,
                 side,
             }),
         }
@@ -319,7 +335,7 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: MarketMeta::default(),
             decision: Decision::default(),
-            quantity: 1.0,
+            quantity: Decimal::ONE,
             order_type: OrderType::default(),
         }
     }
@@ -332,8 +348,8 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: Default::default(),
             decision: Decision::default(),
-            quantity: 1.0,
-            fill_value_gross: 100.0,
+            quantity: Decimal::ONE,
+            fill_value_gross: Decimal::ONE_HUNDRED,
             fees: Fees::default(),
         }
     }
@@ -346,19 +362,21 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             meta: Default::default(),
             side: Side::Buy,
-            quantity: 1.0,
+            quantity: Decimal::ONE,
+            contract: Default::default(),
             enter_fees: Default::default(),
-            enter_fees_total: 0.0,
-            enter_avg_price_gross: 100.0,
-            enter_value_gross: 100.0,
+            enter_fees_total: Decimal::ZERO,
+            enter_avg_price_gross: Decimal::ONE_HUNDRED,
+            enter_value_gross: Decimal::ONE_HUNDRED,
+            enter_notional: Decimal::ONE_HUNDRED,
             exit_fees: Default::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
-            current_symbol_price: 100.0,
-            current_value_gross: 100.0,
-            unrealised_profit_loss: 0.0,
-            realised_profit_loss: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
+            current_symbol_price: Decimal::ONE_HUNDRED,
+            current_value_gross: Decimal::ONE_HUNDRED,
+            unrealised_profit_loss: Decimal::ZERO,
+            realised_profit_loss: Decimal::ZERO,
         }
     }
 }
@@ -0,0 +1,348 @@
+use super::{error::OptionsError, greeks::Greeks, market_price, OptionPricer};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{
+    instrument::kind::{InstrumentKind, OptionContract, OptionKind},
+    Exchange, Market,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::debug;
+
+/// Latest market quote of one option in an [`OptionChain`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OptionQuote {
+    /// Exchange timestamp of the [`MarketEvent`] that generated this quote.
+    pub time: DateTime<Utc>,
+    /// Market price (trade or mid), in the [`PremiumCurrency`](super::PremiumCurrency) it is
+    /// quoted in.
+    pub price: f64,
+    /// Underlying price at the time of the quote.
+    pub underlying: f64,
+    pub implied_volatility: f64,
+    /// [`Greeks`] of one option, not scaled by any contract multiplier.
+    pub greeks: Greeks,
+}
+
+/// Latest call & put [`OptionQuote`]s of one strike in an [`OptionChain`].
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OptionStrike {
+    pub call: Option<OptionQuote>,
+    pub put: Option<OptionQuote>,
+}
+
+impl OptionStrike {
+    /// Returns the [`OptionQuote`] of the provided [`OptionKind`].
+    pub fn get(&self, kind: OptionKind) -> Option<&OptionQuote> {
+        match kind {
+            OptionKind::Call => self.call.as_ref(),
+            OptionKind::Put => self.put.as_ref(),
+        }
+    }
+}
+
+/// Aggregate view of the options on an underlying [`Market`], organised by expiry & strike.
+///
+/// Fed from [`MarketEvent`]s of both the underlying (eg/ spot or perpetual) & it's options.
+/// Each option [`MarketEvent`] is priced against the latest underlying price to solve it's
+/// implied volatility & [`Greeks`], so option events received before the first underlying
+/// event are ignored.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OptionChain {
+    /// [`Exchange`] the options are traded on (eg/ "okx", "gateio_options").
+    pub exchange: Exchange,
+    /// Underlying [`Market`] the options are priced against.
+    pub underlying: Market,
+    pub pricer: OptionPricer,
+    /// Latest underlying price, if any has been received.
+    pub underlying_price: Option<f64>,
+    expiries: BTreeMap<DateTime<Utc>, BTreeMap<Decimal, OptionStrike>>,
+}
+
+impl OptionChain {
+    /// Construct a new empty [`OptionChain`] for the options of the underlying [`Market`]
+    /// traded on the provided [`Exchange`].
+    pub fn new(exchange: Exchange, underlying: Market, pricer: OptionPricer) -> Self {
+        Self {
+            exchange,
+            underlying,
+            pricer,
+            underlying_price: None,
+            expiries: BTreeMap::new(),
+        }
+    }
+
+    /// Update the [`OptionChain`] with the latest [`MarketEvent`]. Returns the new
+    /// [`OptionQuote`] if the event updated an option in the chain.
+    ///
+    /// Events of other [`Market`]s, options that have expired, or prices outside of the
+    /// no-arbitrage bounds are ignored.
+    pub fn update(&mut self, market: &MarketEvent<DataKind>) -> Option<OptionQuote> {
+        if market.exchange == self.underlying.exchange
+            && market.instrument == self.underlying.instrument
+        {
+            self.underlying_price = market_price(&market.kind).or(self.underlying_price);
+            return None;
+        }
+
+        let contract = match market.instrument.kind {
+            InstrumentKind::Option(contract)
+                if market.exchange == self.exchange
+                    && market.instrument.base == self.underlying.instrument.base
+                    && market.instrument.quote == self.underlying.instrument.quote =>
+            {
+                contract
+            }
+            _ => return None,
+        };
+
+        let underlying = self.underlying_price?;
+        let price = market_price(&market.kind)?;
+
+        let quote = match self.price_quote(&contract, price, underlying, market.exchange_time) {
+            Ok(quote) => quote,
+            Err(error) => {
+                debug!(
+                    exchange = %market.exchange,
+                    instrument = %market.instrument,
+                    price,
+                    underlying,
+                    %error,
+                    "ignoring option MarketEvent that cannot be priced"
+                );
+                return None;
+            }
+        };
+
+        let strike = self
+            .expiries
+            .entry(contract.expiry)
+            .or_default()
+            .entry(contract.strike)
+            .or_default();
+
+        match contract.kind {
+            OptionKind::Call => strike.call = Some(quote),
+            OptionKind::Put => strike.put = Some(quote),
+        }
+
+        Some(quote)
+    }
+
+    /// Solve the implied volatility & [`Greeks`] of an option market price.
+    fn price_quote(
+        &self,
+        contract: &OptionContract,
+        price: f64,
+        underlying: f64,
+        time: DateTime<Utc>,
+    ) -> Result<OptionQuote, OptionsError> {
+        let implied_volatility = self
+            .pricer
+            .implied_volatility(contract, price, underlying, time)?;
+
+        let greeks = self
+            .pricer
+            .inputs(contract, underlying, implied_volatility, time)?
+            .greeks();
+
+        Ok(OptionQuote {
+            time,
+            price,
+            underlying,
+            implied_volatility,
+            greeks,
+        })
+    }
+
+    /// Returns the latest [`OptionQuote`] of the provided [`OptionContract`], if any.
+    pub fn quote(&self, contract: &OptionContract) -> Option<&OptionQuote> {
+        self.expiries
+            .get(&contract.expiry)?
+            .get(&contract.strike)?
+            .get(contract.kind)
+    }
+
+    /// Iterator over every expiry in the [`OptionChain`], in ascending order.
+    pub fn expiries(&self) -> impl Iterator<Item = &DateTime<Utc>> {
+        self.expiries.keys()
+    }
+
+    /// Returns every [`OptionStrike`] of the provided expiry, ordered by ascending strike.
+    pub fn strikes(&self, expiry: &DateTime<Utc>) -> Option<&BTreeMap<Decimal, OptionStrike>> {
+        self.expiries.get(expiry)
+    }
+
+    /// Returns the strike of the provided expiry closest to the latest underlying price.
+    pub fn at_the_money_strike(&self, expiry: &DateTime<Utc>) -> Option<Decimal> {
+        let underlying = self.underlying_price?;
+
+        self.expiries.get(expiry)?.keys().copied().min_by(|a, b| {
+            let distance =
+                |strike: &Decimal| (strike.to_f64().unwrap_or(f64::NAN) - underlying).abs();
+            distance(a).total_cmp(&distance(b))
+        })
+    }
+
+    /// Implied volatility smile of the provided expiry as (strike, implied volatility) pairs,
+    /// ordered by ascending strike.
+    ///
+    /// Uses the out of the money option of each strike (puts below the underlying price, calls
+    /// at or above), falling back to the other if only one has been quoted.
+    pub fn volatility_smile(&self, expiry: &DateTime<Utc>) -> Vec<(Decimal, f64)> {
+        let (strikes, underlying) = match (self.expiries.get(expiry), self.underlying_price) {
+            (Some(strikes), Some(underlying)) => (strikes, underlying),
+            _ => return Vec::new(),
+        };
+
+        strikes
+            .iter()
+            .filter_map(|(strike, quotes)| {
+                let (preferred, fallback) = match strike.to_f64()? < underlying {
+                    true => (quotes.put, quotes.call),
+                    false => (quotes.call, quotes.put),
+                };
+
+                preferred
+                    .or(fallback)
+                    .map(|quote| (*strike, quote.implied_volatility))
+            })
+            .collect()
+    }
+
+    /// Remove every expiry at or before the provided time from the [`OptionChain`].
+    pub fn remove_expired(&mut self, time: DateTime<Utc>) {
+        self.expiries.retain(|expiry, _| *expiry > time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{pricing::PricingModel, PremiumCurrency};
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::{
+        instrument::{kind::OptionExercise, Instrument},
+        Side,
+    };
+    use chrono::Duration;
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal_macros::dec;
+
+    fn chain() -> OptionChain {
+        OptionChain::new(
+            Exchange::from("okx"),
+            Market::new("okx", ("btc", "usd", InstrumentKind::Perpetual)),
+            OptionPricer {
+                model: PricingModel::Black76,
+                risk_free_rate: 0.0,
+                premium: PremiumCurrency::Quote,
+            },
+        )
+    }
+
+    fn trade(
+        exchange: &'static str,
+        kind: InstrumentKind,
+        price: f64,
+        time: DateTime<Utc>,
+    ) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usd", kind)),
+            kind: DataKind::Trade(PublicTrade {
+                id: "trade_id".to_string(),
+                price: Decimal::from_f64(price).unwrap(),
+                amount: dec!(1.0),
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    fn option(kind: OptionKind, strike: Decimal, expiry: DateTime<Utc>) -> OptionContract {
+        OptionContract {
+            kind,
+            exercise: OptionExercise::European,
+            expiry,
+            strike,
+        }
+    }
+
+    #[test]
+    fn test_option_chain_update() {
+        let now = Utc::now();
+        let expiry = now + Duration::days(30);
+        let mut chain = chain();
+
+        let call = option(OptionKind::Call, dec!(32000), expiry);
+        let put = option(OptionKind::Put, dec!(28000), expiry);
+
+        // Price each option at a known volatility
+        let price = |contract: &OptionContract, volatility: f64| {
+            chain
+                .pricer
+                .inputs(contract, 30000.0, volatility, now)
+                .unwrap()
+                .price()
+        };
+        let (call_price, put_price) = (price(&call, 0.5), price(&put, 0.6));
+
+        // Option events before the underlying price is known are ignored
+        assert_eq!(
+            chain.update(&trade("okx", InstrumentKind::Option(call), call_price, now)),
+            None
+        );
+
+        // Underlying event
+        assert_eq!(
+            chain.update(&trade("okx", InstrumentKind::Perpetual, 30000.0, now)),
+            None
+        );
+        assert_eq!(chain.underlying_price, Some(30000.0));
+
+        // Option events
+        let call_quote = chain
+            .update(&trade("okx", InstrumentKind::Option(call), call_price, now))
+            .unwrap();
+        assert!((call_quote.implied_volatility - 0.5).abs() < 1e-6);
+        assert!(call_quote.greeks.delta > 0.0);
+
+        let put_quote = chain
+            .update(&trade("okx", InstrumentKind::Option(put), put_price, now))
+            .unwrap();
+        assert!((put_quote.implied_volatility - 0.6).abs() < 1e-6);
+        assert!(put_quote.greeks.delta < 0.0);
+
+        // Options of other exchanges & unpriceable prices are ignored
+        assert_eq!(
+            chain.update(&trade(
+                "deribit",
+                InstrumentKind::Option(call),
+                call_price,
+                now
+            )),
+            None
+        );
+        assert_eq!(
+            chain.update(&trade("okx", InstrumentKind::Option(call), 40000.0, now)),
+            None
+        );
+
+        assert_eq!(chain.quote(&call), Some(&call_quote));
+        assert_eq!(chain.expiries().collect::<Vec<_>>(), vec![&expiry]);
+        assert_eq!(chain.strikes(&expiry).unwrap().len(), 2);
+        assert_eq!(chain.at_the_money_strike(&expiry), Some(dec!(28000)));
+
+        let smile = chain.volatility_smile(&expiry);
+        assert_eq!(smile.len(), 2);
+        assert_eq!(smile[0].0, dec!(28000));
+        assert!((smile[0].1 - 0.6).abs() < 1e-6);
+        assert_eq!(smile[1].0, dec!(32000));
+
+        chain.remove_expired(expiry);
+        assert_eq!(chain.expiries().count(), 0);
+    }
+}
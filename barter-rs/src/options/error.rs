@@ -0,0 +1,21 @@
+use barter_integration::model::instrument::Instrument;
+use thiserror::Error;
+
+/// All errors generated in the barter::options module.
+#[derive(Error, Clone, Debug)]
+pub enum OptionsError {
+    #[error("Instrument is not an option: {0}")]
+    NotAnOption(Instrument),
+
+    #[error("Invalid option pricing inputs: {0}")]
+    InvalidInputs(&'static str),
+
+    #[error("Option has expired")]
+    Expired,
+
+    #[error("Option price {price} is outside of the no-arbitrage bounds [{lower}, {upper}]")]
+    PriceOutOfBounds { price: f64, lower: f64, upper: f64 },
+
+    #[error("Implied volatility failed to converge after {iterations} iterations")]
+    NoConvergence { iterations: usize },
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::{iter::Sum, ops::Add};
+
+/// Sensitivities of an option (or portfolio of options) price to it's inputs.
+///
+/// Greeks of many [`Position`](crate::portfolio::position::Position)s can be aggregated by
+/// summing them, since each is already scaled by it's signed quantity.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct Greeks {
+    /// Change in price per unit change in the underlying.
+    pub delta: f64,
+    /// Change in delta per unit change in the underlying.
+    pub gamma: f64,
+    /// Change in price per unit (ie/ 100%) change in volatility.
+    pub vega: f64,
+    /// Change in price per year passing.
+    pub theta: f64,
+    /// Change in price per unit (ie/ 100%) change in the risk free rate.
+    pub rho: f64,
+}
+
+impl Greeks {
+    /// Scales every [`Greeks`] sensitivity by the provided factor (eg/ signed quantity *
+    /// contract multiplier).
+    pub fn scale(self, factor: f64) -> Self {
+        Self {
+            delta: self.delta * factor,
+            gamma: self.gamma * factor,
+            vega: self.vega * factor,
+            theta: self.theta * factor,
+            rho: self.rho * factor,
+        }
+    }
+}
+
+impl Add for Greeks {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            delta: self.delta + other.delta,
+            gamma: self.gamma + other.gamma,
+            vega: self.vega + other.vega,
+            theta: self.theta + other.theta,
+            rho: self.rho + other.rho,
+        }
+    }
+}
+
+impl Sum for Greeks {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Greeks::default(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greeks_scale_and_sum() {
+        let greeks = Greeks {
+            delta: 0.5,
+            gamma: 0.01,
+            vega: 20.0,
+            theta: -5.0,
+            rho: 10.0,
+        };
+
+        let actual = vec![greeks.scale(2.0), greeks.scale(-1.0)]
+            .into_iter()
+            .sum::<Greeks>();
+
+        assert_eq!(actual, greeks);
+    }
+}
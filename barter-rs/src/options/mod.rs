@@ -0,0 +1,216 @@
+use self::{
+    error::OptionsError,
+    greeks::Greeks,
+    pricing::{OptionInputs, PricingModel},
+    volatility::implied_volatility,
+};
+use crate::{data::market_close, portfolio::position::Position, statistic::decimal_to_f64};
+use barter_data::event::DataKind;
+use barter_integration::model::instrument::kind::{InstrumentKind, OptionContract};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+/// Black-Scholes & Black-76 European option pricing, as well as option [`Greeks`].
+pub mod pricing;
+
+/// Implied volatility solver that backs out the volatility priced into an option market price.
+pub mod volatility;
+
+/// Option [`Greeks`] sensitivities that can be scaled & aggregated across
+/// [`Position`]s.
+pub mod greeks;
+
+/// [`OptionChain`](chain::OptionChain) aggregate view of an underlying's options by expiry &
+/// strike, fed from [`MarketEvent`](barter_data::event::MarketEvent)s.
+pub mod chain;
+
+/// Error types generated in the barter::options module.
+pub mod error;
+
+/// Number of milliseconds in a year of 365.25 days, used to measure time to expiry.
+const MILLISECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// Currency an option market price (premium) is quoted in.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PremiumCurrency {
+    /// Quoted in the quote currency (eg/ USDT for a BTC-USDT option).
+    #[default]
+    Quote,
+    /// Quoted in the underlying base currency (eg/ BTC for an Okx BTC-USD option), so is
+    /// multiplied by the underlying price to convert it into the quote currency.
+    Base,
+}
+
+/// Configuration used to price options, solve their implied volatilities & calculate their
+/// [`Greeks`].
+///
+/// eg/ OptionPricer { model: Black76, risk_free_rate: 0.03, premium: Base } for Okx options.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OptionPricer {
+    pub model: PricingModel,
+    /// Continuously compounded annual risk free rate (eg/ 0.05 for 5%).
+    pub risk_free_rate: f64,
+    #[serde(default)]
+    pub premium: PremiumCurrency,
+}
+
+impl OptionPricer {
+    /// Construct the [`OptionInputs`] of an [`OptionContract`] at the provided time.
+    pub fn inputs(
+        &self,
+        contract: &OptionContract,
+        underlying: f64,
+        volatility: f64,
+        time: DateTime<Utc>,
+    ) -> Result<OptionInputs, OptionsError> {
+        let inputs = OptionInputs {
+            model: self.model,
+            kind: contract.kind,
+            underlying,
+            strike: contract.strike.to_f64().ok_or(OptionsError::InvalidInputs(
+                "strike must be representable as f64",
+            ))?,
+            time_to_expiry: time_to_expiry(contract.expiry, time),
+            risk_free_rate: self.risk_free_rate,
+            volatility,
+        };
+
+        inputs.validate().map(|_| inputs)
+    }
+
+    /// Converts an option market price into the quote currency using the [`PremiumCurrency`].
+    pub fn premium(&self, price: f64, underlying: f64) -> f64 {
+        match self.premium {
+            PremiumCurrency::Quote => price,
+            PremiumCurrency::Base => price * underlying,
+        }
+    }
+
+    /// Solves the implied volatility of an [`OptionContract`] from it's market price.
+    pub fn implied_volatility(
+        &self,
+        contract: &OptionContract,
+        price: f64,
+        underlying: f64,
+        time: DateTime<Utc>,
+    ) -> Result<f64, OptionsError> {
+        let inputs = self.inputs(contract, underlying, 0.0, time)?;
+        implied_volatility(inputs, self.premium(price, underlying))
+    }
+
+    /// Calculates the [`Greeks`] of an option [`Position`], using the volatility implied by it's
+    /// [`Position::current_symbol_price`]. The [`Greeks`] are scaled by the signed quantity &
+    /// [`Contract`](crate::portfolio::position::Contract) multiplier.
+    pub fn position_greeks(
+        &self,
+        position: &Position,
+        underlying: f64,
+        time: DateTime<Utc>,
+    ) -> Result<Greeks, OptionsError> {
+        let contract = match &position.instrument.kind {
+            InstrumentKind::Option(contract) => contract,
+            _ => return Err(OptionsError::NotAnOption(position.instrument.clone())),
+        };
+
+        let price = position
+            .current_symbol_price
+            .to_f64()
+            .ok_or(OptionsError::InvalidInputs(
+                "price must be representable as f64",
+            ))?;
+        let volatility = self.implied_volatility(contract, price, underlying, time)?;
+
+        let size = (position.quantity * position.contract.multiplier)
+            .to_f64()
+            .ok_or(OptionsError::InvalidInputs(
+                "quantity must be representable as f64",
+            ))?;
+
+        Ok(self
+            .inputs(contract, underlying, volatility, time)?
+            .greeks()
+            .scale(size))
+    }
+}
+
+/// Time in years from the provided time until the expiry. Negative once expired.
+pub fn time_to_expiry(expiry: DateTime<Utc>, time: DateTime<Utc>) -> f64 {
+    (expiry - time).num_milliseconds() as f64 / MILLISECONDS_PER_YEAR
+}
+
+/// Determine the market price of an instrument from a [`DataKind`] as an `f64` for option
+/// pricing. See [`market_close`] for the price used.
+pub fn market_price(kind: &DataKind) -> Option<f64> {
+    market_close(kind).map(decimal_to_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::position::Contract, test_util::position};
+    use barter_integration::model::instrument::{
+        kind::{OptionExercise, OptionKind},
+        spec::Settlement,
+    };
+    use chrono::Duration;
+    use rust_decimal::{prelude::FromPrimitive, Decimal};
+    use rust_decimal_macros::dec;
+
+    fn option_contract(kind: OptionKind, now: DateTime<Utc>) -> OptionContract {
+        OptionContract {
+            kind,
+            exercise: OptionExercise::European,
+            expiry: now + Duration::days(30),
+            strike: dec!(30000),
+        }
+    }
+
+    #[test]
+    fn test_position_greeks_scaled_by_quantity_and_multiplier() {
+        let now = Utc::now();
+        let pricer = OptionPricer {
+            model: PricingModel::Black76,
+            risk_free_rate: 0.0,
+            premium: PremiumCurrency::Base,
+        };
+        let contract = option_contract(OptionKind::Call, now);
+
+        // Price a short position of 5 contracts worth 0.1 BTC each, quoted in BTC
+        let inputs = pricer.inputs(&contract, 31000.0, 0.6, now).unwrap();
+        let mut position = position();
+        position.instrument.kind = InstrumentKind::Option(contract);
+        position.quantity = dec!(-5);
+        position.contract = Contract {
+            settlement: Settlement::Inverse,
+            multiplier: dec!(0.1),
+            settlement_rate: Decimal::ONE,
+        };
+        position.current_symbol_price = Decimal::from_f64(inputs.price() / 31000.0).unwrap();
+
+        let actual = pricer.position_greeks(&position, 31000.0, now).unwrap();
+        let expected = inputs.greeks().scale(-0.5);
+
+        assert!((actual.delta - expected.delta).abs() < 1e-6);
+        assert!((actual.vega - expected.vega).abs() < 1e-3);
+        assert!(actual.delta < 0.0);
+    }
+
+    #[test]
+    fn test_position_greeks_of_non_option_position() {
+        let result = OptionPricer::default().position_greeks(&position(), 100.0, Utc::now());
+        assert!(matches!(result, Err(OptionsError::NotAnOption(_))));
+    }
+
+    #[test]
+    fn test_time_to_expiry() {
+        let now = Utc::now();
+        let actual = time_to_expiry(
+            now + Duration::milliseconds(MILLISECONDS_PER_YEAR as i64),
+            now,
+        );
+        assert_eq!(actual, 1.0);
+        assert!(time_to_expiry(now - Duration::days(1), now) < 0.0);
+    }
+}
@@ -0,0 +1,298 @@
+use super::{error::OptionsError, greeks::Greeks};
+use crate::statistic::algorithm::normal_distribution::normal_cdf;
+use barter_integration::model::instrument::kind::OptionKind;
+use serde::{Deserialize, Serialize};
+
+/// Closed form model used to price a European option.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PricingModel {
+    /// Black-Scholes - the underlying is a spot price, with a cost of carry equal to the
+    /// risk free rate.
+    #[default]
+    BlackScholes,
+    /// Black-76 - the underlying is a futures or forward price, with zero cost of carry.
+    Black76,
+}
+
+/// Inputs required to price a European option with a [`PricingModel`].
+///
+/// Prices are denominated in the same currency as the underlying & strike (eg/ USD for a BTC-USD
+/// option), and time is measured in years.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OptionInputs {
+    pub model: PricingModel,
+    pub kind: OptionKind,
+    /// Spot price for [`PricingModel::BlackScholes`], or futures price for
+    /// [`PricingModel::Black76`].
+    pub underlying: f64,
+    pub strike: f64,
+    /// Time to expiry in years.
+    pub time_to_expiry: f64,
+    /// Continuously compounded annual risk free rate (eg/ 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Annualised volatility of the underlying (eg/ 0.6 for 60%).
+    pub volatility: f64,
+}
+
+impl OptionInputs {
+    /// Validate the [`OptionInputs`] can be priced. A zero volatility is valid, and prices the
+    /// option at it's discounted intrinsic value.
+    pub fn validate(&self) -> Result<(), OptionsError> {
+        if !(self.underlying.is_finite() && self.underlying > 0.0) {
+            return Err(OptionsError::InvalidInputs("underlying must be positive"));
+        }
+        if !(self.strike.is_finite() && self.strike > 0.0) {
+            return Err(OptionsError::InvalidInputs("strike must be positive"));
+        }
+        if !(self.volatility.is_finite() && self.volatility >= 0.0) {
+            return Err(OptionsError::InvalidInputs(
+                "volatility must be non-negative",
+            ));
+        }
+        if !self.risk_free_rate.is_finite() {
+            return Err(OptionsError::InvalidInputs("risk_free_rate must be finite"));
+        }
+        if !(self.time_to_expiry.is_finite() && self.time_to_expiry > 0.0) {
+            return Err(OptionsError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Theoretical price of the option.
+    ///
+    /// Call: S * e^((b-r)T) * N(d1) - K * e^(-rT) * N(d2)
+    /// Put: K * e^(-rT) * N(-d2) - S * e^((b-r)T) * N(-d1)
+    pub fn price(&self) -> f64 {
+        let (carry_discount, discount) = self.discount_factors();
+        let forward = self.underlying * carry_discount;
+        let strike = self.strike * discount;
+
+        match self.d1_d2() {
+            Some((d1, d2)) => match self.kind {
+                OptionKind::Call => forward * normal_cdf(d1) - strike * normal_cdf(d2),
+                OptionKind::Put => strike * normal_cdf(-d2) - forward * normal_cdf(-d1),
+            },
+            None => self.intrinsic(forward, strike),
+        }
+    }
+
+    /// [`Greeks`] of one option. Vega & rho are per unit change (ie/ 1.0 = 100%) in volatility &
+    /// rate, and theta is the change in price per year.
+    pub fn greeks(&self) -> Greeks {
+        let (carry_discount, discount) = self.discount_factors();
+        let carry = self.cost_of_carry();
+        let forward = self.underlying * carry_discount;
+        let strike = self.strike * discount;
+
+        let (d1, d2) = match self.d1_d2() {
+            Some(d) => d,
+            // Zero volatility - the option is worth it's discounted intrinsic value
+            None => {
+                let in_the_money = match self.kind {
+                    OptionKind::Call => forward > strike,
+                    OptionKind::Put => forward < strike,
+                };
+                let delta = match (in_the_money, self.kind) {
+                    (false, _) => 0.0,
+                    (true, OptionKind::Call) => carry_discount,
+                    (true, OptionKind::Put) => -carry_discount,
+                };
+                return Greeks {
+                    delta,
+                    ..Greeks::default()
+                };
+            }
+        };
+
+        let sqrt_time = self.time_to_expiry.sqrt();
+        let density = normal_pdf(d1);
+
+        let gamma = carry_discount * density / (self.underlying * self.volatility * sqrt_time);
+        let vega = forward * density * sqrt_time;
+        let decay = -forward * density * self.volatility / (2.0 * sqrt_time);
+
+        let (delta, theta) = match self.kind {
+            OptionKind::Call => (
+                carry_discount * normal_cdf(d1),
+                decay
+                    - (carry - self.risk_free_rate) * forward * normal_cdf(d1)
+                    - self.risk_free_rate * strike * normal_cdf(d2),
+            ),
+            OptionKind::Put => (
+                carry_discount * (normal_cdf(d1) - 1.0),
+                decay
+                    + (carry - self.risk_free_rate) * forward * normal_cdf(-d1)
+                    + self.risk_free_rate * strike * normal_cdf(-d2),
+            ),
+        };
+
+        let rho = match self.model {
+            PricingModel::BlackScholes => match self.kind {
+                OptionKind::Call => self.time_to_expiry * strike * normal_cdf(d2),
+                OptionKind::Put => -self.time_to_expiry * strike * normal_cdf(-d2),
+            },
+            // Futures price is independent of the rate, so only the discounting is sensitive
+            PricingModel::Black76 => -self.time_to_expiry * self.price(),
+        };
+
+        Greeks {
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+        }
+    }
+
+    /// No-arbitrage (lower, upper) bounds of the option price, corresponding to a volatility of
+    /// zero & infinity respectively.
+    pub fn price_bounds(&self) -> (f64, f64) {
+        let (carry_discount, discount) = self.discount_factors();
+        let forward = self.underlying * carry_discount;
+        let strike = self.strike * discount;
+
+        let upper = match self.kind {
+            OptionKind::Call => forward,
+            OptionKind::Put => strike,
+        };
+
+        (self.intrinsic(forward, strike), upper)
+    }
+
+    /// Cost of carry of the underlying for the [`PricingModel`].
+    fn cost_of_carry(&self) -> f64 {
+        match self.model {
+            PricingModel::BlackScholes => self.risk_free_rate,
+            PricingModel::Black76 => 0.0,
+        }
+    }
+
+    /// Returns the (e^((b-r)T), e^(-rT)) discount factors applied to the underlying & strike.
+    fn discount_factors(&self) -> (f64, f64) {
+        (
+            ((self.cost_of_carry() - self.risk_free_rate) * self.time_to_expiry).exp(),
+            (-self.risk_free_rate * self.time_to_expiry).exp(),
+        )
+    }
+
+    /// Returns the Black-Scholes d1 & d2 terms, or `None` if the option has no time value.
+    fn d1_d2(&self) -> Option<(f64, f64)> {
+        let deviation = self.volatility * self.time_to_expiry.sqrt();
+        if deviation <= 0.0 || !deviation.is_finite() {
+            return None;
+        }
+
+        let d1 = ((self.underlying / self.strike).ln()
+            + (self.cost_of_carry() + self.volatility * self.volatility / 2.0)
+                * self.time_to_expiry)
+            / deviation;
+
+        Some((d1, d1 - deviation))
+    }
+
+    /// Discounted intrinsic value given the discounted forward & strike.
+    fn intrinsic(&self, forward: f64, strike: f64) -> f64 {
+        match self.kind {
+            OptionKind::Call => (forward - strike).max(0.0),
+            OptionKind::Put => (strike - forward).max(0.0),
+        }
+    }
+}
+
+/// Standard normal probability density function.
+fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f64 = 1e-4;
+
+    fn inputs(model: PricingModel, kind: OptionKind) -> OptionInputs {
+        OptionInputs {
+            model,
+            kind,
+            underlying: 100.0,
+            strike: 100.0,
+            time_to_expiry: 1.0,
+            risk_free_rate: 0.05,
+            volatility: 0.2,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < TOLERANCE,
+            "actual: {actual}, expected: {expected}"
+        );
+    }
+
+    #[test]
+    fn test_black_scholes_price_and_greeks() {
+        let call = inputs(PricingModel::BlackScholes, OptionKind::Call);
+        let put = inputs(PricingModel::BlackScholes, OptionKind::Put);
+
+        assert_close(call.price(), 10.450584);
+        assert_close(put.price(), 5.573526);
+
+        let greeks = call.greeks();
+        assert_close(greeks.delta, 0.636831);
+        assert_close(greeks.gamma, 0.018762);
+        assert_close(greeks.vega, 37.524035);
+        assert_close(greeks.theta, -6.414028);
+        assert_close(greeks.rho, 53.232482);
+
+        let greeks = put.greeks();
+        assert_close(greeks.delta, -0.363169);
+        assert_close(greeks.theta, -1.657880);
+        assert_close(greeks.rho, -41.890461);
+    }
+
+    #[test]
+    fn test_black_76_put_call_parity() {
+        let call = inputs(PricingModel::Black76, OptionKind::Call);
+        let put = inputs(PricingModel::Black76, OptionKind::Put);
+
+        // e^(-rT) * (F*N(d1) - K*N(d2)) with d1 = 0.1 & d2 = -0.1
+        assert_close(call.price(), 7.577082);
+
+        // C - P = e^(-rT) * (F - K)
+        assert_close(call.price() - put.price(), 0.0);
+        assert_close(call.greeks().delta - put.greeks().delta, (-0.05_f64).exp());
+        assert_close(call.greeks().rho, -call.price());
+    }
+
+    #[test]
+    fn test_zero_volatility_prices_discounted_intrinsic_value() {
+        let mut call = inputs(PricingModel::Black76, OptionKind::Call);
+        call.volatility = 0.0;
+        call.strike = 90.0;
+
+        let discount = (-0.05_f64).exp();
+        assert_close(call.price(), 10.0 * discount);
+        assert_eq!(call.price_bounds().0, call.price());
+        assert_close(call.greeks().delta, discount);
+        assert_eq!(call.greeks().gamma, 0.0);
+    }
+
+    #[test]
+    fn test_validate_inputs() {
+        let mut expired = inputs(PricingModel::BlackScholes, OptionKind::Call);
+        expired.time_to_expiry = 0.0;
+        assert!(matches!(expired.validate(), Err(OptionsError::Expired)));
+
+        let mut negative = inputs(PricingModel::BlackScholes, OptionKind::Call);
+        negative.underlying = -1.0;
+        assert!(matches!(
+            negative.validate(),
+            Err(OptionsError::InvalidInputs(_))
+        ));
+
+        assert!(inputs(PricingModel::BlackScholes, OptionKind::Put)
+            .validate()
+            .is_ok());
+    }
+}
@@ -0,0 +1,123 @@
+use super::{error::OptionsError, pricing::OptionInputs};
+
+/// Absolute price difference at which an implied volatility is considered solved.
+const PRICE_TOLERANCE: f64 = 1e-8;
+
+/// Maximum number of solver iterations before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// Upper bound of the implied volatility search (ie/ 1000%).
+const MAX_VOLATILITY: f64 = 10.0;
+
+/// Solves the volatility that prices the option at the provided market price, ignoring the
+/// [`OptionInputs::volatility`].
+///
+/// Uses Newton-Raphson iterations on vega, falling back to bisection whenever a Newton step
+/// leaves the bracket known to contain the solution. Returns zero for prices at the discounted
+/// intrinsic value.
+pub fn implied_volatility(inputs: OptionInputs, price: f64) -> Result<f64, OptionsError> {
+    inputs.validate()?;
+
+    let (lower, upper) = inputs.price_bounds();
+    if !price.is_finite() || price < lower - PRICE_TOLERANCE || price >= upper {
+        return Err(OptionsError::PriceOutOfBounds {
+            price,
+            lower,
+            upper,
+        });
+    }
+    if price <= lower + PRICE_TOLERANCE {
+        return Ok(0.0);
+    }
+
+    // Brenner-Subrahmanyam at-the-money approximation as the initial guess
+    let mut volatility = ((2.0 * std::f64::consts::PI / inputs.time_to_expiry).sqrt() * price
+        / inputs.underlying)
+        .clamp(0.01, 5.0);
+    let (mut low, mut high) = (0.0, MAX_VOLATILITY);
+
+    for _ in 0..MAX_ITERATIONS {
+        let candidate = OptionInputs {
+            volatility,
+            ..inputs
+        };
+
+        let difference = candidate.price() - price;
+        if difference.abs() < PRICE_TOLERANCE {
+            return Ok(volatility);
+        }
+
+        // Price is monotonically increasing in volatility, so narrow the bracket
+        match difference > 0.0 {
+            true => high = volatility,
+            false => low = volatility,
+        }
+
+        let vega = candidate.greeks().vega;
+        let newton = volatility - difference / vega;
+
+        volatility = match vega > f64::EPSILON && newton > low && newton < high {
+            true => newton,
+            false => (low + high) / 2.0,
+        };
+    }
+
+    Err(OptionsError::NoConvergence {
+        iterations: MAX_ITERATIONS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::pricing::PricingModel;
+    use barter_integration::model::instrument::kind::OptionKind;
+
+    fn inputs(kind: OptionKind, strike: f64, volatility: f64) -> OptionInputs {
+        OptionInputs {
+            model: PricingModel::Black76,
+            kind,
+            underlying: 30000.0,
+            strike,
+            time_to_expiry: 30.0 / 365.0,
+            risk_free_rate: 0.03,
+            volatility,
+        }
+    }
+
+    #[test]
+    fn test_implied_volatility_recovers_pricing_volatility() {
+        let cases = vec![
+            inputs(OptionKind::Call, 30000.0, 0.55),
+            inputs(OptionKind::Put, 30000.0, 0.55),
+            inputs(OptionKind::Call, 45000.0, 0.9),
+            inputs(OptionKind::Put, 20000.0, 1.2),
+            inputs(OptionKind::Call, 32000.0, 0.15),
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = implied_volatility(test, test.price()).unwrap();
+            assert!(
+                (actual - test.volatility).abs() < 1e-6,
+                "TC{index} failed: actual {actual}, expected {}",
+                test.volatility
+            );
+        }
+    }
+
+    #[test]
+    fn test_implied_volatility_of_price_outside_bounds() {
+        let call = inputs(OptionKind::Call, 30000.0, 0.5);
+        let (lower, upper) = call.price_bounds();
+
+        assert!(matches!(
+            implied_volatility(call, upper + 1.0),
+            Err(OptionsError::PriceOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            implied_volatility(call, -1.0),
+            Err(OptionsError::PriceOutOfBounds { .. })
+        ));
+        assert_eq!(implied_volatility(call, lower).unwrap(), 0.0);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    portfolio::{position::Position, OrderEvent},
+    portfolio::{error::PortfolioError, position::Position, OrderEvent},
     strategy::{Decision, SignalStrength},
 };
+use rust_decimal::{prelude::FromPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Allocates an appropriate [`OrderEvent`] quantity.
@@ -13,14 +14,31 @@ pub trait OrderAllocator {
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
-    );
+    ) -> Result<(), PortfolioError>;
 }
 
 /// Default allocation manager that implements [`OrderAllocator`]. Order size is calculated by
 /// using the default_order_value, symbol close value, and [`SignalStrength`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct DefaultAllocator {
-    pub default_order_value: f64,
+    pub default_order_value: Decimal,
+}
+
+impl DefaultAllocator {
+    /// Decimal places the default order size is rounded down to.
+    const ORDER_SIZE_DP: u32 = 4;
+
+    /// Calculate the default order size from the [`OrderEvent`] close price, rounded down to
+    /// [`Self::ORDER_SIZE_DP`] decimal places. Entries cannot be sized from a non-positive close.
+    fn default_order_size(&self, order: &OrderEvent) -> Result<Decimal, PortfolioError> {
+        let close = order.market_meta.close;
+        if close <= Decimal::ZERO {
+            return Err(PortfolioError::InvalidClosePrice(close));
+        }
+
+        Ok((self.default_order_value / close)
+            .round_dp_with_strategy(Self::ORDER_SIZE_DP, RoundingStrategy::ToZero))
+    }
 }
 
 impl OrderAllocator for DefaultAllocator {
@@ -29,51 +47,61 @@ impl OrderAllocator for DefaultAllocator {
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
-    ) {
-        // Calculate exact order_size, then round it to a more appropriate decimal place
-        let default_order_size = self.default_order_value / order.market_meta.close;
-        let default_order_size = (default_order_size * 10000.0).floor() / 10000.0;
-
-        match order.decision {
+    ) -> Result<(), PortfolioError> {
+        order.quantity = match order.decision {
             // Entry
-            Decision::Long => order.quantity = default_order_size * signal_strength.0,
+            Decision::Long => {
+                self.default_order_size(order)? * signal_strength_decimal(signal_strength)
+            }
 
             // Entry
-            Decision::Short => order.quantity = -default_order_size * signal_strength.0,
+            Decision::Short => {
+                -self.default_order_size(order)? * signal_strength_decimal(signal_strength)
+            }
 
             // Exit
-            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
-        }
+            _ => -position.as_ref().unwrap().quantity,
+        };
+
+        Ok(())
     }
 }
 
+/// Convert a [`SignalStrength`] into a [`Decimal`] multiplier, treating non-finite strengths as zero.
+fn signal_strength_decimal(signal_strength: SignalStrength) -> Decimal {
+    Decimal::from_f64(signal_strength.0).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_util::{order_event, position};
+    use rust_decimal_macros::dec;
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
         let allocator = DefaultAllocator {
-            default_order_value: 1000.0,
+            default_order_value: dec!(1000.0),
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseLong;
 
         let mut input_position = position();
-        input_position.quantity = 100.0;
+        input_position.quantity = dec!(100.0);
 
         let input_signal_strength = SignalStrength(0.0);
 
-        allocator.allocate_order(
-            &mut input_order,
-            Some(&input_position),
-            input_signal_strength,
-        );
+        allocator
+            .allocate_order(
+                &mut input_order,
+                Some(&input_position),
+                input_signal_strength,
+            )
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -81,118 +109,148 @@ mod tests {
     #[test]
     fn should_allocate_order_to_exit_open_short_position() {
         let allocator = DefaultAllocator {
-            default_order_value: 1000.0,
+            default_order_value: dec!(1000.0),
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseShort;
 
         let mut input_position = position();
-        input_position.quantity = -100.0;
+        input_position.quantity = dec!(-100.0);
 
         let input_signal_strength = SignalStrength(0.0);
 
-        allocator.allocate_order(
-            &mut input_order,
-            Some(&input_position),
-            input_signal_strength,
-        );
+        allocator
+            .allocate_order(
+                &mut input_order,
+                Some(&input_position),
+                input_signal_strength,
+            )
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_long_position_with_correct_quantity() {
-        let default_order_value = 1000.0;
+        let default_order_value = dec!(1000.0);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 10.0;
+        let order_close = dec!(10.0);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Long;
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator
+            .allocate_order(&mut input_order, None, input_signal_strength)
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = dec!(100.0);
 
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_long_position_with_non_zero_quantity() {
-        let default_order_value = 200.0;
+        let default_order_value = dec!(200.0);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 226.753403;
+        let order_close = dec!(226.753403);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Long;
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator
+            .allocate_order(&mut input_order, None, input_signal_strength)
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
-        let expected_result = expected_order_size * input_signal_strength.0 as f64;
+        // 200.0 / 226.753403 = 0.88201..., rounded down to 4 decimal places
+        let expected_result = dec!(0.8820);
 
-        assert_ne!(actual_result, 0.0);
+        assert_ne!(actual_result, Decimal::ZERO);
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_short_position_with_correct_quantity() {
-        let default_order_value = 1000.0;
+        let default_order_value = dec!(1000.0);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 10.0;
+        let order_close = dec!(10.0);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Short;
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator
+            .allocate_order(&mut input_order, None, input_signal_strength)
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = dec!(-100.0);
 
         assert_eq!(actual_result, expected_result)
     }
 
+    #[test]
+    fn should_return_error_when_allocating_entry_order_with_zero_close() {
+        let allocator = DefaultAllocator {
+            default_order_value: dec!(1000.0),
+        };
+
+        let mut input_order = order_event();
+        input_order.market_meta.close = Decimal::ZERO;
+        input_order.decision = Decision::Long;
+        let input_quantity = input_order.quantity;
+
+        let actual_result = allocator.allocate_order(&mut input_order, None, SignalStrength(1.0));
+
+        assert!(matches!(
+            actual_result,
+            Err(PortfolioError::InvalidClosePrice(close)) if close.is_zero()
+        ));
+        assert_eq!(input_order.quantity, input_quantity);
+    }
+
     #[test]
     fn should_allocate_order_to_enter_short_position_with_with_non_zero_quantity() {
-        let default_order_value = 200.0;
+        let default_order_value = dec!(200.0);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 226.753403;
+        let order_close = dec!(226.753403);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Short;
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator
+            .allocate_order(&mut input_order, None, input_signal_strength)
+            .unwrap();
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
-        let expected_result = -expected_order_size * input_signal_strength.0;
+        // 200.0 / 226.753403 = 0.88201..., rounded down to 4 decimal places
+        let expected_result = dec!(-0.8820);
 
-        assert_ne!(actual_result, 0.0);
+        assert_ne!(actual_result, Decimal::ZERO);
         assert_eq!(actual_result, expected_result)
     }
 }
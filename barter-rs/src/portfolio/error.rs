@@ -1,4 +1,6 @@
 use crate::portfolio::repository::error::RepositoryError;
+use barter_integration::model::MarketId;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -19,6 +21,15 @@ pub enum PortfolioError {
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("Cannot allocate an OrderEvent quantity from a non-positive close price: {0}")]
+    InvalidClosePrice(Decimal),
+
+    #[error("Quanto Contract Market has no settlement rate source Market configured: {0}")]
+    SettlementRateSourceNotPresent(MarketId),
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
+
+    #[error("Failed to deserialise Portfolio configuration: {0}")]
+    Configuration(#[from] serde_json::Error),
 }
@@ -6,8 +6,9 @@ use crate::{
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -64,6 +65,19 @@ pub trait FillUpdater {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError>;
 }
 
+/// Reconfigures a Portfolio whilst it is trading, eg/ via a remote
+/// [`Command`](crate::engine::Command).
+pub trait PortfolioConfigurer {
+    /// Start tracking a new [`Market`], initialising any state it requires.
+    fn add_market(&mut self, market: &Market) -> Result<(), PortfolioError>;
+
+    /// Replace the allocation manager with one deserialised from the provided JSON config.
+    fn configure_allocator(&mut self, config: serde_json::Value) -> Result<(), PortfolioError>;
+
+    /// Replace the risk manager with one deserialised from the provided JSON config.
+    fn configure_risk(&mut self, config: serde_json::Value) -> Result<(), PortfolioError>;
+}
+
 /// Orders are generated by the portfolio and details work to be done by an Execution handler to
 /// open a trade.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -76,7 +90,7 @@ pub struct OrderEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
 }
@@ -113,7 +127,7 @@ pub struct OrderEventBuilder {
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub order_type: Option<OrderType>,
 }
 
@@ -157,7 +171,7 @@ impl OrderEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -203,23 +217,23 @@ pub type BalanceId = String;
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
     pub time: DateTime<Utc>,
-    pub total: f64,
-    pub available: f64,
+    pub total: Decimal,
+    pub available: Decimal,
 }
 
 impl Default for Balance {
     fn default() -> Self {
         Self {
             time: Utc::now(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         }
     }
 }
 
 impl Balance {
     /// Construct a new [`Balance`] using the provided total & available balance values.
-    pub fn new(time: DateTime<Utc>, total: f64, available: f64) -> Self {
+    pub fn new(time: DateTime<Utc>, total: Decimal, available: Decimal) -> Self {
         Self {
             time,
             total,
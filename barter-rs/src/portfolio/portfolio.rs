@@ -2,26 +2,31 @@ use super::{
     allocator::OrderAllocator,
     error::PortfolioError,
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
+        determine_position_id, Contract, Position, PositionEnterer, PositionExiter, PositionId,
         PositionUpdate, PositionUpdater,
     },
-    repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
+    repository::{
+        error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler,
+        TransactionHandler,
+    },
     risk::OrderEvaluator,
     Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    PortfolioConfigurer,
 };
 use crate::{
-    data::MarketMeta,
+    data::{market_close, MarketMeta},
     event::Event,
     execution::FillEvent,
     statistic::summary::{Initialiser, PositionSummariser},
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{Market, MarketId, Side};
+use barter_integration::model::{instrument::spec::Settlement, Market, MarketId, Side};
 use chrono::Utc;
-use serde::Serialize;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Serialize};
 use std::{collections::HashMap, marker::PhantomData};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing & initialising a [`MetaPortfolio`] via the init() constructor
@@ -29,7 +34,7 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct PortfolioLego<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -47,7 +52,14 @@ where
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
     /// Cash balance a [`MetaPortfolio`] starts with.
-    pub starting_cash: f64,
+    pub starting_cash: Decimal,
+    /// [`Contract`] terms of any non-linear [`Market`]s, used to calculate [`Position`] PnL.
+    /// [`Market`]s without an entry are assumed to be linear with a multiplier of one.
+    pub contracts: HashMap<MarketId, Contract>,
+    /// [`Market`] whose close price is the settlement rate (eg/ BTC-USD for a BTC settled ETH-USD
+    /// quanto perpetual) of each quanto [`Contract`] [`Market`]. Every quanto [`Contract`] requires
+    /// an entry.
+    pub settlement_sources: HashMap<MarketId, MarketId>,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -59,7 +71,7 @@ where
 #[derive(Debug)]
 pub struct MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -73,13 +85,21 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// [`Contract`] terms of any non-linear [`Market`]s, used to calculate [`Position`] PnL.
+    contracts: HashMap<MarketId, Contract>,
+    /// [`Market`] providing the settlement rate of each quanto [`Contract`] [`Market`].
+    settlement_sources: HashMap<MarketId, MarketId>,
+    /// Latest close price of every settlement rate source [`Market`].
+    settlement_rates: HashMap<MarketId, Decimal>,
+    /// Configuration used to initialise the Statistics of any [`Market`] added at runtime.
+    statistic_config: Statistic::Config,
     _statistic_marker: PhantomData<Statistic>,
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> MarketUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -88,12 +108,25 @@ where
         &mut self,
         market: &MarketEvent<DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        // Record the latest settlement rate if the MarketEvent is for a settlement rate source
+        let market_id = MarketId::new(&market.exchange, &market.instrument);
+        if self
+            .settlement_sources
+            .values()
+            .any(|source| source == &market_id)
+        {
+            if let Some(rate) = market_close(&market.kind) {
+                self.settlement_rates.insert(market_id.clone(), rate);
+            }
+        }
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
 
         // Update Position if Portfolio has an open Position for that Symbol-Exchange combination
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
+            self.apply_settlement_rate(&market_id, &mut position.contract);
             // Derive PositionUpdate event that communicates the open Position's change in state
             if let Some(position_update) = position.update(market) {
                 // Save updated open Position in the repository
@@ -109,7 +142,7 @@ where
 impl<Repository, Allocator, RiskManager, Statistic> OrderGenerator
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -140,13 +173,13 @@ where
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
             decision: *signal_decision,
-            quantity: 0.0,
+            quantity: Decimal::ZERO,
             order_type: OrderType::default(),
         };
 
         // Manage OrderEvent size allocation
         self.allocation_manager
-            .allocate_order(&mut order, position, *signal_strength);
+            .allocate_order(&mut order, position, *signal_strength)?;
 
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
         Ok(self.risk_manager.evaluate_order(order))
@@ -180,9 +213,11 @@ where
             market_meta: MarketMeta {
                 close: position.current_symbol_price,
                 time: position.meta.update_time,
+                trace: None,
+                attribution: None,
             },
             decision: position.determine_exit_decision(),
-            quantity: 0.0 - position.quantity,
+            quantity: -position.quantity,
             order_type: OrderType::Market,
         }))
     }
@@ -191,77 +226,40 @@ where
 impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser + Serialize,
 {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
-        // Allocate Vector<Event> to contain any update_from_fill generated events
-        let mut generated_events: Vec<Event> = Vec::with_capacity(2);
+        // Persist every side effect of the FillEvent atomically as a single unit of work
+        self.repository.begin()?;
 
-        // Get the Portfolio Balance from Repository & update timestamp
-        let mut balance = self.repository.get_balance(self.engine_id)?;
-        balance.time = fill.time;
-
-        // Determine the position_id that is related to the input FillEvent
-        let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
-
-        // Determine FillEvent context based on existence or absence of an open Position
-        match self.repository.remove_position(&position_id)? {
-            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
-            Some(mut position) => {
-                // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
-                let position_exit = position.exit(balance, fill)?;
-                generated_events.push(Event::PositionExit(position_exit));
-
-                // Update Portfolio balance on Position exit
-                // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
-
-                // Update statistics for exited Position market
-                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
-
-                let mut stats = self.repository.get_statistics(&market_id)?;
-                stats.update(&position);
-
-                // Persist exited Position & Updated Market statistics in Repository
-                self.repository.set_statistics(market_id, stats)?;
-                self.repository
-                    .set_exited_position(self.engine_id, position)?;
-            }
-
-            // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
-            None => {
-                // Enter new Position, & add the PositionNew event to Vec<Event>
-                let position = Position::enter(self.engine_id, fill)?;
-                generated_events.push(Event::PositionNew(position.clone()));
-
-                // Update Portfolio Balance.available on Position entry
-                balance.available += -position.enter_value_gross - position.enter_fees_total;
+        // Rollback if either applying the FillEvent or committing it's unit of work fails
+        let result = self.apply_fill(fill).and_then(|generated_events| {
+            self.repository.commit()?;
+            Ok(generated_events)
+        });
 
-                // Add to current Positions in Repository
-                self.repository.set_open_position(position)?;
+        match result {
+            Ok(generated_events) => Ok(generated_events),
+            Err(error) => {
+                if let Err(rollback_error) = self.repository.rollback() {
+                    warn!(
+                        ?rollback_error,
+                        "failed to rollback repository unit of work after FillEvent error"
+                    );
+                }
+                Err(error)
             }
-        };
-
-        // Add new Balance event to the Vec<Event>
-        generated_events.push(Event::Balance(balance));
-
-        // Persist updated Portfolio Balance in Repository
-        self.repository.set_balance(self.engine_id, balance)?;
-
-        Ok(generated_events)
+        }
     }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -285,6 +283,10 @@ where
         self.repository.get_open_positions(self.engine_id, markets)
     }
 
+    fn get_all_open_positions(&mut self, _: Uuid) -> Result<Vec<Position>, RepositoryError> {
+        self.repository.get_all_open_positions(self.engine_id)
+    }
+
     fn remove_position(
         &mut self,
         position_id: &PositionId,
@@ -305,7 +307,7 @@ where
 impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -323,10 +325,61 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> BalanceHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn set_balance(&mut self, _: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.repository.set_balance(self.engine_id, balance)
+    }
+
+    fn get_balance(&mut self, _: Uuid) -> Result<Balance, RepositoryError> {
+        self.repository.get_balance(self.engine_id)
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic> PortfolioConfigurer
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
+    Allocator: OrderAllocator + DeserializeOwned,
+    RiskManager: OrderEvaluator + DeserializeOwned,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn add_market(&mut self, market: &Market) -> Result<(), PortfolioError> {
+        // Statistics already persisted for the Market (eg/ it was traded before) are kept
+        let market_id = MarketId::from(market);
+        match self.repository.get_statistics(&market_id) {
+            Ok(_) => Ok(()),
+            Err(RepositoryError::ExpectedDataNotPresentError) => self
+                .repository
+                .set_statistics(market_id, Statistic::init(self.statistic_config))
+                .map_err(PortfolioError::RepositoryInteraction),
+            Err(error) => Err(PortfolioError::RepositoryInteraction(error)),
+        }
+    }
+
+    fn configure_allocator(&mut self, config: serde_json::Value) -> Result<(), PortfolioError> {
+        self.allocation_manager = serde_json::from_value(config)?;
+        info!(engine_id = %self.engine_id, "reconfigured MetaPortfolio allocation manager");
+        Ok(())
+    }
+
+    fn configure_risk(&mut self, config: serde_json::Value) -> Result<(), PortfolioError> {
+        self.risk_manager = serde_json::from_value(config)?;
+        info!(engine_id = %self.engine_id, "reconfigured MetaPortfolio risk manager");
+        Ok(())
+    }
+}
+
 impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -342,8 +395,13 @@ where
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            contracts: lego.contracts,
+            settlement_sources: lego.settlement_sources,
+            settlement_rates: HashMap::new(),
+            statistic_config: lego.statistic_config,
             _statistic_marker: PhantomData::default(),
         };
+        validate_settlement_sources(&portfolio.contracts, &portfolio.settlement_sources)?;
 
         // Persist initial state in the repository
         portfolio.bootstrap_repository(lego.starting_cash, &lego.markets, lego.statistic_config)?;
@@ -351,11 +409,40 @@ where
         Ok(portfolio)
     }
 
+    /// Constructs a [`MetaPortfolio`] using the provided [`PortfolioLego`] components, resuming
+    /// the state already persisted in the Repository for the engine_id rather than overwriting
+    /// it. The `starting_cash` is ignored.
+    ///
+    /// Returns a [`ResumeReport`] detailing the reloaded state, as well as any
+    /// [`ResumeConflict`]s found when reconciling it against the configured [`Market`]s.
+    pub fn resume(
+        lego: PortfolioLego<Repository, Allocator, RiskManager, Statistic>,
+    ) -> Result<(Self, ResumeReport), PortfolioError> {
+        // Construct MetaPortfolio instance
+        let mut portfolio = Self {
+            engine_id: lego.engine_id,
+            repository: lego.repository,
+            allocation_manager: lego.allocator,
+            risk_manager: lego.risk,
+            contracts: lego.contracts,
+            settlement_sources: lego.settlement_sources,
+            settlement_rates: HashMap::new(),
+            statistic_config: lego.statistic_config,
+            _statistic_marker: PhantomData,
+        };
+        validate_settlement_sources(&portfolio.contracts, &portfolio.settlement_sources)?;
+
+        // Reload & reconcile persisted state
+        let report = portfolio.resume_repository(&lego.markets, lego.statistic_config)?;
+
+        Ok((portfolio, report))
+    }
+
     /// Persist initial [`MetaPortfolio`] state in the repository. This includes initialised
     /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
-        starting_cash: f64,
+        starting_cash: Decimal,
         markets: Markets,
         statistic_config: Statistic::Config,
     ) -> Result<(), PortfolioError>
@@ -381,6 +468,174 @@ where
         })
     }
 
+    /// Reload the [`MetaPortfolio`] state persisted in the repository for the engine_id, and
+    /// reconcile it against the configured [`Market`]s. Persisted state is never overwritten,
+    /// only missing Statistics are initialised. Any discrepancies are returned as
+    /// [`ResumeConflict`]s in the [`ResumeReport`].
+    pub fn resume_repository(
+        &mut self,
+        markets: &[Market],
+        statistic_config: Statistic::Config,
+    ) -> Result<ResumeReport, PortfolioError> {
+        let mut conflicts = Vec::new();
+
+        // Persisted Balance is required to resume an existing engine_id
+        let balance = self.repository.get_balance(self.engine_id)?;
+
+        // Initialise Statistics for any configured Market without persisted Statistics
+        for market in markets {
+            let market_id = MarketId::from(market);
+            match self.repository.get_statistics(&market_id) {
+                Ok(_) => {}
+                Err(RepositoryError::ExpectedDataNotPresentError) => {
+                    self.repository
+                        .set_statistics(market_id.clone(), Statistic::init(statistic_config))?;
+                    conflicts.push(ResumeConflict::StatisticsNotPresent(market_id));
+                }
+                Err(error) => return Err(PortfolioError::RepositoryInteraction(error)),
+            }
+        }
+
+        // Cash committed to every persisted open Position, used to reconcile the Balance
+        let persisted_open_positions = self.repository.get_all_open_positions(self.engine_id)?;
+        let open_positions_committed = persisted_open_positions
+            .iter()
+            .map(|position| position.enter_notional + position.enter_fees_total)
+            .sum::<Decimal>();
+
+        // Reconcile every persisted open Position against the configured Markets
+        let mut open_positions = Vec::new();
+        for position in persisted_open_positions {
+            let is_configured = markets.iter().any(|market| {
+                market.exchange == position.exchange && market.instrument == position.instrument
+            });
+
+            match is_configured {
+                true => open_positions.push(position),
+                false => conflicts.push(ResumeConflict::UnconfiguredMarketPosition(Box::new(
+                    position,
+                ))),
+            }
+        }
+
+        if balance.total - balance.available != open_positions_committed {
+            conflicts.push(ResumeConflict::BalanceMismatch {
+                balance,
+                open_positions_committed,
+            });
+        }
+
+        let exited_positions = self.repository.get_exited_positions(self.engine_id)?;
+
+        for conflict in &conflicts {
+            warn!(
+                engine_id = %self.engine_id,
+                ?conflict,
+                "conflict found when resuming MetaPortfolio from persisted state"
+            );
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            ?balance,
+            open_positions = open_positions.len(),
+            exited_positions = exited_positions.len(),
+            conflicts = conflicts.len(),
+            "resumed MetaPortfolio from persisted state"
+        );
+
+        Ok(ResumeReport {
+            balance,
+            open_positions,
+            exited_positions,
+            conflicts,
+        })
+    }
+
+    /// Apply the [`FillEvent`] to the repository state, entering or exiting a [`Position`] &
+    /// updating the [`Balance`]. Any reads happen before the associated writes, as required by a
+    /// [`TransactionHandler`] unit of work.
+    fn apply_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError> {
+        // Allocate Vector<Event> to contain any update_from_fill generated events
+        let mut generated_events: Vec<Event> = Vec::with_capacity(2);
+
+        // Get the Portfolio Balance from Repository & update timestamp
+        let mut balance = self.repository.get_balance(self.engine_id)?;
+        balance.time = fill.time;
+
+        // Determine the position_id that is related to the input FillEvent
+        let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
+
+        // Determine FillEvent context based on existence or absence of an open Position
+        match self.repository.remove_position(&position_id)? {
+            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
+            Some(mut position) => {
+                // Exit Position (in place mutation) at the latest settlement rate, & add the
+                // PositionExit event to Vec<Event>
+                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+                self.apply_settlement_rate(&market_id, &mut position.contract);
+                let position_exit = position.exit(balance, fill)?;
+                generated_events.push(Event::PositionExit(position_exit));
+
+                // Update Portfolio balance on Position exit, refunding the cash committed on entry
+                // '--> available balance adds enter_total_fees since included in result PnL calc
+                balance.available += position.enter_notional
+                    + position.realised_profit_loss
+                    + position.enter_fees_total;
+                balance.total += position.realised_profit_loss;
+
+                // Update statistics for exited Position market
+                let mut stats = self.repository.get_statistics(&market_id)?;
+                stats.update(&position);
+
+                // Persist exited Position & Updated Market statistics in Repository
+                self.repository.set_statistics(market_id, stats)?;
+                self.repository
+                    .set_exited_position(self.engine_id, position)?;
+            }
+
+            // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
+            None => {
+                // Enter new Position with the Market Contract terms, & add the PositionNew
+                // event to Vec<Event>
+                let mut position = Position::enter(self.engine_id, fill)?;
+                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+                if let Some(contract) = self.contracts.get(&market_id) {
+                    position.contract = *contract;
+                }
+                self.apply_settlement_rate(&market_id, &mut position.contract);
+                position.enter_notional = position.calculate_enter_notional();
+                generated_events.push(Event::PositionNew(position.clone()));
+
+                // Update Portfolio Balance.available on Position entry
+                balance.available += -position.enter_notional - position.enter_fees_total;
+
+                // Add to current Positions in Repository
+                self.repository.set_open_position(position)?;
+            }
+        };
+
+        // Add new Balance event to the Vec<Event>
+        generated_events.push(Event::Balance(balance));
+
+        // Persist updated Portfolio Balance in Repository
+        self.repository.set_balance(self.engine_id, balance)?;
+
+        Ok(generated_events)
+    }
+
+    /// Replace the settlement rate of a quanto [`Contract`] with the latest close of it's
+    /// settlement rate source [`Market`], if one has been observed.
+    fn apply_settlement_rate(&self, market_id: &MarketId, contract: &mut Contract) {
+        if let Some(rate) = self
+            .settlement_sources
+            .get(market_id)
+            .and_then(|source| self.settlement_rates.get(source))
+        {
+            contract.settlement_rate = *rate;
+        }
+    }
+
     /// Returns a [`MetaPortfolioBuilder`] instance.
     pub fn builder() -> MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic> {
         MetaPortfolioBuilder::new()
@@ -390,7 +645,7 @@ where
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
             .get_balance(self.engine_id)
-            .map(|balance| balance.available == 0.0)
+            .map(|balance| balance.available.is_zero())
             .map_err(PortfolioError::RepositoryInteraction)
     }
 }
@@ -398,17 +653,19 @@ where
 #[derive(Debug, Default)]
 pub struct MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
 {
     engine_id: Option<Uuid>,
     markets: Option<Vec<Market>>,
-    starting_cash: Option<f64>,
+    starting_cash: Option<Decimal>,
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
+    contracts: Option<HashMap<MarketId, Contract>>,
+    settlement_sources: Option<HashMap<MarketId, MarketId>>,
     statistic_config: Option<Statistic::Config>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
@@ -416,7 +673,7 @@ where
 impl<Repository, Allocator, RiskManager, Statistic>
     MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic>
 where
-    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
     Allocator: OrderAllocator,
     RiskManager: OrderEvaluator,
     Statistic: Initialiser + PositionSummariser,
@@ -429,6 +686,8 @@ where
             repository: None,
             allocation_manager: None,
             risk_manager: None,
+            contracts: None,
+            settlement_sources: None,
             statistic_config: None,
             _statistic_marker: None,
         }
@@ -448,7 +707,7 @@ where
         }
     }
 
-    pub fn starting_cash(self, value: f64) -> Self {
+    pub fn starting_cash(self, value: Decimal) -> Self {
         Self {
             starting_cash: Some(value),
             ..self
@@ -476,6 +735,20 @@ where
         }
     }
 
+    pub fn contracts(self, value: HashMap<MarketId, Contract>) -> Self {
+        Self {
+            contracts: Some(value),
+            ..self
+        }
+    }
+
+    pub fn settlement_sources(self, value: HashMap<MarketId, MarketId>) -> Self {
+        Self {
+            settlement_sources: Some(value),
+            ..self
+        }
+    }
+
     pub fn statistic_config(self, value: Statistic::Config) -> Self {
         Self {
             statistic_config: Some(value),
@@ -500,22 +773,123 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            contracts: self.contracts.unwrap_or_default(),
+            settlement_sources: self.settlement_sources.unwrap_or_default(),
+            settlement_rates: HashMap::new(),
+            statistic_config: self
+                .statistic_config
+                .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
             _statistic_marker: PhantomData::default(),
         };
+        validate_settlement_sources(&portfolio.contracts, &portfolio.settlement_sources)?;
 
         // Persist initial state in the Repository
+        let statistic_config = portfolio.statistic_config;
         portfolio.bootstrap_repository(
             self.starting_cash
                 .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
             &self
                 .markets
                 .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
-            self.statistic_config
-                .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
+            statistic_config,
         )?;
 
         Ok(portfolio)
     }
+
+    /// Builds a [`MetaPortfolio`] that resumes the state already persisted in the Repository for
+    /// the engine_id. See [`MetaPortfolio::resume`].
+    pub fn build_and_resume(
+        self,
+    ) -> Result<
+        (
+            MetaPortfolio<Repository, Allocator, RiskManager, Statistic>,
+            ResumeReport,
+        ),
+        PortfolioError,
+    > {
+        // Construct Portfolio
+        let mut portfolio = MetaPortfolio {
+            engine_id: self
+                .engine_id
+                .ok_or(PortfolioError::BuilderIncomplete("engine_id"))?,
+            repository: self
+                .repository
+                .ok_or(PortfolioError::BuilderIncomplete("repository"))?,
+            allocation_manager: self
+                .allocation_manager
+                .ok_or(PortfolioError::BuilderIncomplete("allocation_manager"))?,
+            risk_manager: self
+                .risk_manager
+                .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            contracts: self.contracts.unwrap_or_default(),
+            settlement_sources: self.settlement_sources.unwrap_or_default(),
+            settlement_rates: HashMap::new(),
+            statistic_config: self
+                .statistic_config
+                .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
+            _statistic_marker: PhantomData,
+        };
+        validate_settlement_sources(&portfolio.contracts, &portfolio.settlement_sources)?;
+
+        // Reload & reconcile persisted state in the Repository
+        let statistic_config = portfolio.statistic_config;
+        let report = portfolio.resume_repository(
+            &self
+                .markets
+                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            statistic_config,
+        )?;
+
+        Ok((portfolio, report))
+    }
+}
+
+/// Summary of the persisted state reloaded by [`MetaPortfolio::resume`].
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct ResumeReport {
+    /// Persisted [`Balance`] the [`MetaPortfolio`] resumed with.
+    pub balance: Balance,
+    /// Persisted open [`Position`]s for the configured [`Market`]s.
+    pub open_positions: Vec<Position>,
+    /// Persisted exited [`Position`]s from previous trading sessions.
+    pub exited_positions: Vec<Position>,
+    /// Discrepancies found when reconciling the persisted state. These are reported rather than
+    /// resolved.
+    pub conflicts: Vec<ResumeConflict>,
+}
+
+/// Discrepancy found when reconciling persisted state against a resumed [`MetaPortfolio`]
+/// configuration.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub enum ResumeConflict {
+    /// Persisted open [`Position`] for a [`Market`] that is not configured. It is left untouched
+    /// in the repository, but will not be traded.
+    UnconfiguredMarketPosition(Box<Position>),
+    /// No Statistics were persisted for a configured [`Market`], so they were initialised.
+    StatisticsNotPresent(MarketId),
+    /// The committed cash implied by the persisted [`Balance`] (total - available) does not equal
+    /// the cash committed to the persisted open [`Position`]s.
+    BalanceMismatch {
+        balance: Balance,
+        open_positions_committed: Decimal,
+    },
+}
+
+/// Validates that every quanto [`Contract`] [`Market`] has a settlement rate source [`Market`]
+/// configured, since it's PnL cannot be converted into the reporting currency without one.
+pub fn validate_settlement_sources(
+    contracts: &HashMap<MarketId, Contract>,
+    settlement_sources: &HashMap<MarketId, MarketId>,
+) -> Result<(), PortfolioError> {
+    match contracts.iter().find(|(market_id, contract)| {
+        contract.settlement == Settlement::Quanto && !settlement_sources.contains_key(market_id)
+    }) {
+        Some((market_id, _)) => Err(PortfolioError::SettlementRateSourceNotPresent(
+            market_id.clone(),
+        )),
+        None => Ok(()),
+    }
 }
 
 /// Parses an incoming [`Signal`]'s signals map. Determines what the net signal [`Decision`]
@@ -554,17 +928,20 @@ pub mod tests {
     use crate::{
         execution::Fees,
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            position::PositionBuilder,
+            repository::{error::RepositoryError, in_memory::InMemoryRepository},
+            risk::DefaultRisk,
         },
         statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
         test_util::{fill_event, market_event_trade, position, signal},
     };
     use barter_integration::model::{
-        instrument::{kind::InstrumentKind, Instrument},
+        instrument::{kind::InstrumentKind, spec::Settlement, Instrument},
         Exchange, Side,
     };
+    use rust_decimal_macros::dec;
 
     #[derive(Default)]
     struct MockRepository<Statistic> {
@@ -574,6 +951,8 @@ pub mod tests {
         get_open_positions: Option<
             fn(engine_id: Uuid, markets: Vec<&Market>) -> Result<Vec<Position>, RepositoryError>,
         >,
+        get_all_open_positions:
+            Option<fn(engine_id: Uuid) -> Result<Vec<Position>, RepositoryError>>,
         remove_position:
             Option<fn(engine_id: &String) -> Result<Option<Position>, RepositoryError>>,
         set_exited_position:
@@ -593,10 +972,12 @@ pub mod tests {
             self.position = Some(
                 Position::builder()
                     .side(position.side.clone())
+                    .contract(position.contract)
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
                     .enter_fees_total(position.enter_fees_total)
                     .enter_value_gross(position.enter_value_gross)
+                    .enter_notional(position.enter_notional)
                     .enter_avg_price_gross(position.enter_avg_price_gross)
                     .exit_fees_total(position.exit_fees_total)
                     .exit_value_gross(position.exit_value_gross)
@@ -622,6 +1003,13 @@ pub mod tests {
             self.get_open_positions.unwrap()(engine_id, markets.into_iter().collect())
         }
 
+        fn get_all_open_positions(
+            &mut self,
+            engine_id: Uuid,
+        ) -> Result<Vec<Position>, RepositoryError> {
+            self.get_all_open_positions.unwrap()(engine_id)
+        }
+
         fn remove_position(
             &mut self,
             position_id: &String,
@@ -674,19 +1062,36 @@ pub mod tests {
         }
     }
 
+    impl<Statistic> TransactionHandler for MockRepository<Statistic> {
+        fn begin(&mut self) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        fn commit(&mut self) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
     fn new_mocked_portfolio<Repository, Statistic>(
         mock_repository: Repository,
     ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository:
+            PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
         Statistic: PositionSummariser + Initialiser,
+        Statistic::Config: Default,
     {
         let builder = MetaPortfolio::builder()
             .engine_id(Uuid::new_v4())
-            .starting_cash(1000.0)
+            .starting_cash(dec!(1000.0))
             .repository(mock_repository)
+            .statistic_config(Statistic::Config::default())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100.0),
             })
             .risk_manager(DefaultRisk {});
 
@@ -697,7 +1102,8 @@ pub mod tests {
         builder: MetaPortfolioBuilder<Repository, DefaultAllocator, DefaultRisk, Statistic>,
     ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
     where
-        Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        Repository:
+            PositionHandler + BalanceHandler + StatisticHandler<Statistic> + TransactionHandler,
         Statistic: PositionSummariser + Initialiser,
     {
         Ok(MetaPortfolio {
@@ -713,6 +1119,12 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            contracts: builder.contracts.unwrap_or_default(),
+            settlement_sources: builder.settlement_sources.unwrap_or_default(),
+            settlement_rates: HashMap::new(),
+            statistic_config: builder
+                .statistic_config
+                .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
             _statistic_marker: Default::default(),
         })
     }
@@ -733,11 +1145,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(1.0);
+                input_position.enter_fees_total = dec!(3.0);
+                input_position.current_symbol_price = dec!(100.0);
+                input_position.current_value_gross = dec!(100.0);
+                input_position.unrealised_profit_loss = dec!(-3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -750,7 +1162,7 @@ pub mod tests {
         match input_market.kind {
             // candle.close +100.0 on input_position.current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(200.0),
             _ => todo!(),
         };
 
@@ -760,17 +1172,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(200.0));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(200.0));
 
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            200.0 - 100.0 - 6.0
+            dec!(200.0) - dec!(100.0) - dec!(6.0)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            200.0 - 100.0 - 6.0
+            dec!(200.0) - dec!(100.0) - dec!(6.0)
         );
     }
 
@@ -782,11 +1194,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(1.0);
+                input_position.enter_fees_total = dec!(3.0);
+                input_position.current_symbol_price = dec!(100.0);
+                input_position.current_value_gross = dec!(100.0);
+                input_position.unrealised_profit_loss = dec!(-3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -798,7 +1210,7 @@ pub mod tests {
         match input_market.kind {
             // -50.0 on input_position.current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(50.0),
             _ => todo!(),
         };
 
@@ -808,14 +1220,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(50.0));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(50.0));
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            50.0 - 100.0 - 6.0
+            dec!(50.0) - dec!(100.0) - dec!(6.0)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            dec!(50.0) - dec!(100.0) - dec!(6.0)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 50.0 - 100.0 - 6.0);
     }
 
     #[test]
@@ -826,11 +1241,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(-1.0);
+                input_position.enter_fees_total = dec!(3.0);
+                input_position.current_symbol_price = dec!(100.0);
+                input_position.current_value_gross = dec!(100.0);
+                input_position.unrealised_profit_loss = dec!(-3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -843,7 +1258,7 @@ pub mod tests {
         match input_market.kind {
             // -50.0 on input_position.current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(50.0),
             _ => todo!(),
         };
 
@@ -853,14 +1268,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(50.0));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(50.0));
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 50.0 - 6.0
+            dec!(100.0) - dec!(50.0) - dec!(6.0)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            dec!(100.0) - dec!(50.0) - dec!(6.0)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 100.0 - 50.0 - 6.0);
     }
 
     #[test]
@@ -871,11 +1289,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(-1.0);
+                input_position.enter_fees_total = dec!(3.0);
+                input_position.current_symbol_price = dec!(100.0);
+                input_position.current_value_gross = dec!(100.0);
+                input_position.unrealised_profit_loss = dec!(-3.0); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -888,7 +1306,7 @@ pub mod tests {
         match input_market.kind {
             // +100.0 on input_position.current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(200.0),
             _ => todo!(),
         };
 
@@ -898,16 +1316,16 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(200.0));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(200.0));
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 200.0 - 6.0
+            dec!(100.0) - dec!(200.0) - dec!(6.0)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            100.0 - 200.0 - 6.0
+            dec!(100.0) - dec!(200.0) - dec!(6.0)
         );
     }
 
@@ -919,8 +1337,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: dec!(100.0),
+                available: dec!(0.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -941,8 +1359,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: dec!(100.0),
+                available: dec!(0.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -963,8 +1381,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100.0),
+                available: dec!(100.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -988,8 +1406,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100.0),
+                available: dec!(100.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1020,8 +1438,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100.0),
+                available: dec!(100.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1052,8 +1470,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100.0),
+                available: dec!(100.0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1078,7 +1496,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Buy;
-                position.quantity = 100.0;
+                position.quantity = dec!(100.0);
                 position
             }))
         });
@@ -1094,7 +1512,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseLong);
-        assert_eq!(actual.quantity, -100.0);
+        assert_eq!(actual.quantity, dec!(-100.0));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1106,7 +1524,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Sell;
-                position.quantity = -100.0;
+                position.quantity = dec!(-100.0);
                 position
             }))
         });
@@ -1122,7 +1540,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseShort);
-        assert_eq!(actual.quantity, 100.0);
+        assert_eq!(actual.quantity, dec!(100.0));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1148,8 +1566,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: dec!(200.0),
+                available: dec!(200.0),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1160,12 +1578,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1175,9 +1593,9 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Buy);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(entered_position.enter_value_gross.unwrap(), dec!(100.0));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), dec!(3.0));
+        assert_eq!(updated_cash, dec!(200.0) - dec!(100.0) - dec!(3.0)); // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1187,8 +1605,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: dec!(200.0),
+                available: dec!(200.0),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1199,12 +1617,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1214,9 +1632,217 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Sell);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(entered_position.enter_value_gross.unwrap(), dec!(100.0));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), dec!(3.0));
+        assert_eq!(updated_cash, dec!(200.0) - dec!(100.0) - dec!(3.0)); // cash += enter_value_gross - enter_fees
+    }
+
+    #[test]
+    fn update_from_fill_entering_position_with_market_contract() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: dec!(200.0),
+                    available: dec!(200.0),
+                })
+            }),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
+
+        let contract = Contract {
+            settlement: Settlement::Inverse,
+            multiplier: dec!(10),
+            settlement_rate: Decimal::ONE,
+        };
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .starting_cash(dec!(1000.0))
+            .repository(mock_repository)
+            .statistic_config(())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .contracts(HashMap::from([(
+                MarketId::new(&input_fill.exchange, &input_fill.instrument),
+                contract,
+            )]));
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let entered_position = portfolio.repository.position.unwrap();
+        let updated_cash = portfolio.repository.balance.unwrap().available;
+
+        assert!(result.is_ok());
+        assert_eq!(entered_position.contract, Some(contract));
+        // Inverse notional = abs(quantity) * multiplier
+        assert_eq!(updated_cash, dec!(200.0) - dec!(10.0));
+    }
+
+    #[test]
+    fn update_from_fill_entering_quanto_position_at_latest_settlement_rate() {
+        // Build Portfolio
+        let mock_repository = MockRepository::<PnLReturnSummary> {
+            get_open_position: Some(|_| Ok(None)),
+            get_balance: Some(|_| {
+                Ok(Balance {
+                    time: Utc::now(),
+                    total: dec!(200.0),
+                    available: dec!(200.0),
+                })
+            }),
+            remove_position: Some(|_| Ok(None)),
+            set_open_position: Some(|_| Ok(())),
+            set_balance: Some(|_, _| Ok(())),
+            ..Default::default()
+        };
+
+        // Input FillEvent for the quanto Market, & MarketEvent for it's settlement rate source
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
+        let input_market = market_event_trade(Side::Buy);
+
+        let quanto_market = MarketId::new(&input_fill.exchange, &input_fill.instrument);
+        let contract = Contract {
+            settlement: Settlement::Quanto,
+            multiplier: dec!(0.001),
+            settlement_rate: Decimal::ONE,
+        };
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .starting_cash(dec!(1000.0))
+            .repository(mock_repository)
+            .statistic_config(())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .contracts(HashMap::from([(quanto_market.clone(), contract)]))
+            .settlement_sources(HashMap::from([(
+                quanto_market,
+                MarketId::new(&input_market.exchange, &input_market.instrument),
+            )]));
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Settlement rate source trades at 1000.0
+        portfolio.update_from_market(&input_market).unwrap();
+        let result = portfolio.update_from_fill(&input_fill);
+        let entered_position = portfolio.repository.position.unwrap();
+        let updated_cash = portfolio.repository.balance.unwrap().available;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            entered_position.contract.unwrap().settlement_rate,
+            dec!(1000.0)
+        );
+        // Quanto notional = abs(quantity) * multiplier * enter_price * settlement_rate
+        assert_eq!(
+            updated_cash,
+            dec!(200.0) - dec!(1.0) * dec!(0.001) * dec!(100.0) * dec!(1000.0)
+        );
+    }
+
+    #[test]
+    fn update_from_fill_exiting_quanto_position_refunds_cash_committed_at_entry() {
+        let engine_id = Uuid::new_v4();
+        let quanto_market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let mut source_market = market_event_trade(Side::Buy);
+
+        let contract = Contract {
+            settlement: Settlement::Quanto,
+            multiplier: dec!(0.001),
+            settlement_rate: Decimal::ONE,
+        };
+        let mut portfolio = resumable_portfolio_builder(
+            engine_id,
+            InMemoryRepository::new(),
+            vec![quanto_market.clone()],
+        )
+        .starting_cash(dec!(1000.0))
+        .contracts(HashMap::from([(MarketId::from(&quanto_market), contract)]))
+        .settlement_sources(HashMap::from([(
+            MarketId::from(&quanto_market),
+            MarketId::new(&source_market.exchange, &source_market.instrument),
+        )]))
+        .build_and_init()
+        .unwrap();
+
+        // Enter at a settlement rate of 1000.0
+        portfolio.update_from_market(&source_market).unwrap();
+        let mut entry_fill = fill_event();
+        entry_fill.decision = Decision::Long;
+        entry_fill.quantity = dec!(1.0);
+        entry_fill.fill_value_gross = dec!(100.0);
+        portfolio.update_from_fill(&entry_fill).unwrap();
+
+        // Exit at a settlement rate of 1200.0
+        if let DataKind::Trade(ref mut trade) = source_market.kind {
+            trade.price = dec!(1200.0);
+        }
+        portfolio.update_from_market(&source_market).unwrap();
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.quantity = dec!(-1.0);
+        exit_fill.fill_value_gross = dec!(110.0);
+        portfolio.update_from_fill(&exit_fill).unwrap();
+
+        let balance = portfolio.repository.get_balance(engine_id).unwrap();
+        let exited = portfolio
+            .repository
+            .get_exited_positions(engine_id)
+            .unwrap();
+
+        // Quanto PnL = 1 * 0.001 * (110 - 100) * 1200
+        assert_eq!(exited[0].realised_profit_loss, dec!(12.0));
+        assert_eq!(balance.total, dec!(1012.0));
+        assert_eq!(balance.available, balance.total);
+    }
+
+    #[test]
+    fn build_portfolio_with_quanto_contract_without_settlement_source_fails() {
+        let market_id = MarketId::new(
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        );
+        let contract = Contract {
+            settlement: Settlement::Quanto,
+            multiplier: dec!(0.001),
+            settlement_rate: Decimal::ONE,
+        };
+
+        let result = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .markets(vec![])
+            .starting_cash(dec!(1000.0))
+            .repository(MockRepository::<PnLReturnSummary>::default())
+            .statistic_config(())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .contracts(HashMap::from([(market_id.clone(), contract)]))
+            .build_and_init();
+
+        match result {
+            Err(PortfolioError::SettlementRateSourceNotPresent(actual)) => {
+                assert_eq!(actual, market_id)
+            }
+            Err(error) => panic!("expected SettlementRateSourceNotPresent, got: {error:?}"),
+            Ok(_) => panic!("expected SettlementRateSourceNotPresent, got a MetaPortfolio"),
+        }
     }
 
     #[test]
@@ -1226,8 +1852,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200.0),
+                available: dec!(97.0),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1235,9 +1861,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(1.0);
+                    input_position.enter_fees_total = dec!(3.0);
+                    input_position.enter_value_gross = dec!(100.0);
                     input_position
                 })
             })
@@ -1251,12 +1877,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1267,9 +1893,15 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (200.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97.0) + dec!(100.0) + (dec!(200.0) - dec!(100.0) - dec!(6.0)) + dec!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            dec!(200.0) + (dec!(200.0) - dec!(100.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -1279,8 +1911,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200.0),
+                available: dec!(97.0),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1288,9 +1920,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(1.0);
+                    input_position.enter_fees_total = dec!(3.0);
+                    input_position.enter_value_gross = dec!(100.0);
                     input_position
                 })
             })
@@ -1304,12 +1936,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1320,9 +1952,15 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (50.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97.0) + dec!(100.0) + (dec!(50.0) - dec!(100.0) - dec!(6.0)) + dec!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            dec!(200.0) + (dec!(50.0) - dec!(100.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -1332,8 +1970,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200.0),
+                available: dec!(97.0),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1341,9 +1979,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(-1.0);
+                    input_position.enter_fees_total = dec!(3.0);
+                    input_position.enter_value_gross = dec!(100.0);
                     input_position
                 })
             })
@@ -1357,12 +1995,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1373,9 +2011,15 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 50.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97.0) + dec!(100.0) + (dec!(100.0) - dec!(50.0) - dec!(6.0)) + dec!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            dec!(200.0) + (dec!(100.0) - dec!(50.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -1385,8 +2029,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200.0),
+                available: dec!(97.0),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1394,9 +2038,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(-1.0);
+                    input_position.enter_fees_total = dec!(3.0);
+                    input_position.enter_value_gross = dec!(100.0);
                     input_position
                 })
             })
@@ -1410,12 +2054,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 150.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(150.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1426,9 +2070,15 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 150.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97.0) + dec!(100.0) + (dec!(100.0) - dec!(150.0) - dec!(6.0)) + dec!(3.0)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
+        assert_eq!(
+            updated_value,
+            dec!(200.0) + (dec!(100.0) - dec!(150.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -1589,4 +2239,233 @@ pub mod tests {
 
         assert_eq!(actual, None);
     }
+
+    fn resumable_portfolio_builder(
+        engine_id: Uuid,
+        repository: InMemoryRepository<PnLReturnSummary>,
+        markets: Vec<Market>,
+    ) -> MetaPortfolioBuilder<
+        InMemoryRepository<PnLReturnSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        PnLReturnSummary,
+    > {
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets)
+            .repository(repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+    }
+
+    #[test]
+    fn build_and_resume_reloads_persisted_state_and_reports_conflicts() {
+        let engine_id = Uuid::new_v4();
+        let eth_market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let btc_market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        // Previous session opened a Position in both eth & btc markets
+        let mut previous = resumable_portfolio_builder(
+            engine_id,
+            InMemoryRepository::new(),
+            vec![eth_market.clone(), btc_market.clone()],
+        )
+        .starting_cash(dec!(1000.0))
+        .build_and_init()
+        .unwrap();
+
+        previous.update_from_fill(&fill_event()).unwrap();
+        let mut btc_fill = fill_event();
+        btc_fill.instrument = btc_market.instrument.clone();
+        previous.update_from_fill(&btc_fill).unwrap();
+        let expected_balance = previous.repository.get_balance(engine_id).unwrap();
+
+        // Resume with only the eth market configured
+        let (mut resumed, report) =
+            resumable_portfolio_builder(engine_id, previous.repository, vec![eth_market.clone()])
+                .build_and_resume()
+                .unwrap();
+
+        assert_eq!(report.balance, expected_balance);
+        assert_eq!(report.open_positions.len(), 1);
+        assert_eq!(report.open_positions[0].instrument, eth_market.instrument);
+        assert!(report.exited_positions.is_empty());
+        assert_eq!(report.conflicts.len(), 1);
+        assert!(matches!(
+            &report.conflicts[0],
+            ResumeConflict::UnconfiguredMarketPosition(position)
+                if position.instrument == btc_market.instrument
+        ));
+
+        // Resumed state is untouched, including the unconfigured market Position
+        assert_eq!(
+            resumed.repository.get_balance(engine_id).unwrap(),
+            expected_balance
+        );
+        assert_eq!(resumed.get_all_open_positions(engine_id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn build_and_resume_reports_missing_statistics_and_balance_mismatch() {
+        let engine_id = Uuid::new_v4();
+        let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+        repository
+            .set_balance(
+                engine_id,
+                Balance {
+                    time: Utc::now(),
+                    total: dec!(1000.0),
+                    available: dec!(900.0),
+                },
+            )
+            .unwrap();
+
+        let (mut resumed, report) =
+            resumable_portfolio_builder(engine_id, repository, vec![market.clone()])
+                .build_and_resume()
+                .unwrap();
+
+        let market_id = MarketId::from(&market);
+        assert_eq!(
+            report.conflicts[0],
+            ResumeConflict::StatisticsNotPresent(market_id.clone())
+        );
+        assert!(matches!(
+            report.conflicts[1],
+            ResumeConflict::BalanceMismatch {
+                open_positions_committed,
+                ..
+            } if open_positions_committed == dec!(0.0)
+        ));
+        assert!(resumed.get_statistics(&market_id).is_ok());
+    }
+
+    #[test]
+    fn build_and_resume_fails_without_persisted_balance() {
+        let actual = resumable_portfolio_builder(
+            Uuid::new_v4(),
+            InMemoryRepository::new(),
+            vec![Market::new(
+                "binance",
+                ("eth", "usdt", InstrumentKind::Spot),
+            )],
+        )
+        .build_and_resume();
+
+        assert!(matches!(
+            actual,
+            Err(PortfolioError::RepositoryInteraction(
+                RepositoryError::ExpectedDataNotPresentError
+            ))
+        ));
+    }
+
+    #[test]
+    fn update_from_fill_rolls_back_every_write_if_the_fill_fails() {
+        let engine_id = Uuid::new_v4();
+        let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        let mut portfolio =
+            resumable_portfolio_builder(engine_id, InMemoryRepository::new(), vec![market.clone()])
+                .starting_cash(dec!(1000.0))
+                .build_and_init()
+                .unwrap();
+        portfolio.update_from_fill(&fill_event()).unwrap();
+        let expected_balance = portfolio.repository.get_balance(engine_id).unwrap();
+
+        // Statistics missing for the Market, so exiting fails after the Position is removed
+        portfolio.repository = {
+            let mut repository = InMemoryRepository::new();
+            repository.set_balance(engine_id, expected_balance).unwrap();
+            for position in portfolio.get_all_open_positions(engine_id).unwrap() {
+                repository.set_open_position(position).unwrap();
+            }
+            repository
+        };
+
+        let mut exit_fill = fill_event();
+        exit_fill.decision = Decision::CloseLong;
+        exit_fill.quantity = dec!(-1.0);
+        assert!(portfolio.update_from_fill(&exit_fill).is_err());
+
+        assert_eq!(
+            portfolio.get_all_open_positions(engine_id).unwrap().len(),
+            1
+        );
+        assert!(portfolio
+            .get_exited_positions(engine_id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            portfolio.repository.get_balance(engine_id).unwrap(),
+            expected_balance
+        );
+    }
+
+    #[test]
+    fn portfolio_configurer_adds_markets_and_replaces_allocator_and_risk() {
+        let engine_id = Uuid::new_v4();
+        let eth_market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let btc_market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+        let mut portfolio = resumable_portfolio_builder(
+            engine_id,
+            InMemoryRepository::new(),
+            vec![eth_market.clone()],
+        )
+        .starting_cash(dec!(1000.0))
+        .build_and_init()
+        .unwrap();
+
+        // Statistics already persisted for a Market are kept when it is added
+        let mut eth_statistics = portfolio
+            .get_statistics(&MarketId::from(&eth_market))
+            .unwrap();
+        eth_statistics.trades_per_day = 5.0;
+        portfolio
+            .set_statistics(MarketId::from(&eth_market), eth_statistics)
+            .unwrap();
+        portfolio.add_market(&eth_market).unwrap();
+        assert_eq!(
+            portfolio
+                .get_statistics(&MarketId::from(&eth_market))
+                .unwrap()
+                .trades_per_day,
+            5.0
+        );
+
+        // Statistics are initialised for a new Market
+        assert!(portfolio
+            .get_statistics(&MarketId::from(&btc_market))
+            .is_err());
+        portfolio.add_market(&btc_market).unwrap();
+        assert!(portfolio
+            .get_statistics(&MarketId::from(&btc_market))
+            .is_ok());
+
+        portfolio
+            .configure_allocator(serde_json::json!({ "default_order_value": 250.0 }))
+            .unwrap();
+        assert_eq!(
+            portfolio.allocation_manager,
+            DefaultAllocator {
+                default_order_value: dec!(250.0)
+            }
+        );
+        portfolio.configure_risk(serde_json::json!({})).unwrap();
+
+        assert!(matches!(
+            portfolio.configure_allocator(serde_json::json!({ "unknown": true })),
+            Err(PortfolioError::Configuration(_))
+        ));
+        assert_eq!(
+            portfolio.allocation_manager.default_order_value,
+            dec!(250.0)
+        );
+    }
 }
@@ -1,11 +1,19 @@
 use crate::{
+    data::Attribution,
     execution::{FeeAmount, Fees, FillEvent},
     portfolio::{error::PortfolioError, Balance},
     strategy::Decision,
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use barter_integration::model::{
+    instrument::{
+        spec::{InstrumentSpec, Settlement},
+        Instrument,
+    },
+    Exchange, Side,
+};
 use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use uuid::Uuid;
@@ -42,6 +50,99 @@ pub fn determine_position_id(
     format!("{}_{}_{}_position", engine_id, exchange, instrument)
 }
 
+/// Contract terms of the [`Instrument`] a [`Position`] is opened in, determining how it's PnL is
+/// calculated & converted into the reporting (quote) currency.
+///
+/// Defaults to a linear contract with a multiplier of one (eg/ spot).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Contract {
+    /// Linear, inverse or quanto settlement.
+    pub settlement: Settlement,
+    /// Value of one contract - see [`InstrumentSpec::contract_multiplier`].
+    pub multiplier: Decimal,
+    /// Rate converting the quanto settlement currency into the reporting currency (eg/ USD per BTC
+    /// for a BTC settled ETH-USD quanto perpetual). Unused for linear & inverse contracts.
+    ///
+    /// The [`MetaPortfolio`](crate::portfolio::portfolio::MetaPortfolio) replaces this with the
+    /// latest close of the quanto [`Market`](barter_integration::model::Market)'s settlement rate
+    /// source.
+    pub settlement_rate: Decimal,
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self {
+            settlement: Settlement::Linear,
+            multiplier: Decimal::ONE,
+            settlement_rate: Decimal::ONE,
+        }
+    }
+}
+
+impl From<&InstrumentSpec> for Contract {
+    fn from(spec: &InstrumentSpec) -> Self {
+        Self {
+            settlement: spec.settlement,
+            multiplier: spec.contract_multiplier,
+            settlement_rate: Decimal::ONE,
+        }
+    }
+}
+
+impl Contract {
+    /// Calculates the gross PnL in the reporting currency of holding a [`Side`] of `quantity`
+    /// contracts from the `enter_price` to the `price`. `enter_value` & `value` are the
+    /// abs(quantity) * price values used for linear contracts.
+    ///
+    /// Linear: (value - enter_value) * multiplier
+    /// Inverse: |quantity| * multiplier * (1/enter_price - 1/price) in base, * price in quote
+    /// Quanto: |quantity| * multiplier * (price - enter_price) * settlement_rate
+    pub fn profit_loss_gross(
+        &self,
+        side: Side,
+        quantity: Decimal,
+        enter_price: Decimal,
+        enter_value: Decimal,
+        price: Decimal,
+        value: Decimal,
+    ) -> Decimal {
+        let long_profit_loss = match self.settlement {
+            Settlement::Linear => (value - enter_value) * self.multiplier,
+            Settlement::Inverse => match price.checked_div(enter_price) {
+                Some(price_ratio) => {
+                    quantity.abs() * self.multiplier * (price_ratio - Decimal::ONE)
+                }
+                None => Decimal::ZERO,
+            },
+            Settlement::Quanto => {
+                quantity.abs() * self.multiplier * (price - enter_price) * self.settlement_rate
+            }
+        };
+
+        match side {
+            Side::Buy => long_profit_loss,
+            Side::Sell => -long_profit_loss,
+        }
+    }
+
+    /// Calculates the entry notional value in the reporting currency of `quantity` contracts
+    /// entered at the `enter_price`, with an abs(quantity) * enter_price of `enter_value`.
+    pub fn notional(
+        &self,
+        quantity: Decimal,
+        enter_price: Decimal,
+        enter_value: Decimal,
+    ) -> Decimal {
+        match self.settlement {
+            Settlement::Linear => enter_value * self.multiplier,
+            Settlement::Inverse => quantity.abs() * self.multiplier,
+            Settlement::Quanto => {
+                quantity.abs() * self.multiplier * enter_price * self.settlement_rate
+            }
+        }
+    }
+}
+
 /// Data encapsulating the state of an ongoing or closed [`Position`].
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Position {
@@ -65,7 +166,11 @@ pub struct Position {
     pub side: Side,
 
     /// +ve or -ve quantity of symbol contracts opened.
-    pub quantity: f64,
+    pub quantity: Decimal,
+
+    /// [`Contract`] terms used to calculate the PnL of this [`Position`].
+    #[serde(default)]
+    pub contract: Contract,
 
     /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
     pub enter_fees: Fees,
@@ -74,10 +179,15 @@ pub struct Position {
     pub enter_fees_total: FeeAmount,
 
     /// Enter average price excluding the entry_fees_total.
-    pub enter_avg_price_gross: f64,
+    pub enter_avg_price_gross: Decimal,
 
     /// abs(Quantity) * enter_avg_price_gross.
-    pub enter_value_gross: f64,
+    pub enter_value_gross: Decimal,
+
+    /// Entry notional value in the reporting currency per the [`Contract`] terms at entry. This is
+    /// the cash committed to the [`Position`] excluding the enter_fees_total, and is refunded on
+    /// exit even if the quanto settlement rate has since moved.
+    pub enter_notional: Decimal,
 
     /// All fees types incurred from exiting a [`Position`], and their associated [`FeeAmount`].
     pub exit_fees: Fees,
@@ -86,22 +196,22 @@ pub struct Position {
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    pub exit_value_gross: Decimal,
 
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Decimal,
 
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Decimal,
 
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Decimal,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    pub realised_profit_loss: Decimal,
 }
 
 impl PositionEnterer for Position {
@@ -111,6 +221,9 @@ impl PositionEnterer for Position {
             enter_time: fill.market_meta.time,
             update_time: fill.time,
             exit_balance: None,
+            attribution: fill.market_meta.attribution,
+            max_adverse_excursion: Decimal::ZERO,
+            max_favourable_excursion: Decimal::ZERO,
         };
 
         // Enter fees
@@ -120,7 +233,7 @@ impl PositionEnterer for Position {
         let enter_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
         // Unreal profit & loss
-        let unrealised_profit_loss = -enter_fees_total * 2.0;
+        let unrealised_profit_loss = -enter_fees_total * Decimal::TWO;
 
         Ok(Position {
             position_id: determine_position_id(engine_id, &fill.exchange, &fill.instrument),
@@ -129,18 +242,20 @@ impl PositionEnterer for Position {
             meta: metadata,
             side: Position::parse_entry_side(fill)?,
             quantity: fill.quantity,
+            contract: Contract::default(),
             enter_fees: fill.fees,
             enter_fees_total,
             enter_avg_price_gross,
             enter_value_gross: fill.fill_value_gross,
+            enter_notional: fill.fill_value_gross,
             exit_fees: Fees::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
             current_symbol_price: enter_avg_price_gross,
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
-            realised_profit_loss: 0.0,
+            realised_profit_loss: Decimal::ZERO,
         })
     }
 }
@@ -150,7 +265,8 @@ impl PositionUpdater for Position {
         // Determine close from MarketEvent
         let close = match &market.kind {
             DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            // Non-finite candle closes cannot be represented exactly, so are ignored
+            DataKind::Candle(candle) => Decimal::from_f64(candle.close)?,
             DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
             DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
             DataKind::Liquidation(_) => return None,
@@ -165,6 +281,7 @@ impl PositionUpdater for Position {
 
         // Unreal profit & loss
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+        self.update_excursions(self.unrealised_profit_loss);
 
         // Return a PositionUpdate event that communicates the change in state
         Some(PositionUpdate::from(self))
@@ -192,6 +309,7 @@ impl PositionExiter for Position {
         // Result profit & loss
         self.realised_profit_loss = self.calculate_realised_profit_loss();
         self.unrealised_profit_loss = self.realised_profit_loss;
+        self.update_excursions(self.realised_profit_loss);
 
         // Metadata
         balance.total += self.realised_profit_loss;
@@ -210,8 +328,11 @@ impl Position {
 
     /// Calculates the [`Position::enter_avg_price_gross`] or [`Position::exit_avg_price_gross`] of
     /// a [`FillEvent`].
-    pub fn calculate_avg_price_gross(fill: &FillEvent) -> f64 {
-        (fill.fill_value_gross / fill.quantity).abs()
+    pub fn calculate_avg_price_gross(fill: &FillEvent) -> Decimal {
+        fill.fill_value_gross
+            .checked_div(fill.quantity)
+            .unwrap_or_default()
+            .abs()
     }
 
     /// Determine the [`Position`] entry [`Side`] by analysing the input [`FillEvent`].
@@ -234,30 +355,58 @@ impl Position {
         }
     }
 
-    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
-    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
-        let approx_total_fees = self.enter_fees_total * 2.0;
+    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`] in the
+    /// reporting currency, using it's [`Contract`] terms.
+    pub fn calculate_unrealised_profit_loss(&self) -> Decimal {
+        let approx_total_fees = self.enter_fees_total * Decimal::TWO;
 
-        match self.side {
-            Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
-            Side::Sell => self.enter_value_gross - self.current_value_gross - approx_total_fees,
-        }
+        self.contract.profit_loss_gross(
+            self.side,
+            self.quantity,
+            self.enter_avg_price_gross,
+            self.enter_value_gross,
+            self.current_symbol_price,
+            self.current_value_gross,
+        ) - approx_total_fees
     }
 
-    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
-    pub fn calculate_realised_profit_loss(&self) -> f64 {
+    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`] in the reporting
+    /// currency, using it's [`Contract`] terms.
+    pub fn calculate_realised_profit_loss(&self) -> Decimal {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
-        match self.side {
-            Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
-            Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
-        }
+        self.contract.profit_loss_gross(
+            self.side,
+            self.quantity,
+            self.enter_avg_price_gross,
+            self.enter_value_gross,
+            self.exit_avg_price_gross,
+            self.exit_value_gross,
+        ) - total_fees
+    }
+
+    /// Update the [`PositionMeta`] maximum adverse & favourable excursions with the latest PnL.
+    pub(crate) fn update_excursions(&mut self, profit_loss: Decimal) {
+        self.meta.max_adverse_excursion = self.meta.max_adverse_excursion.min(profit_loss);
+        self.meta.max_favourable_excursion = self.meta.max_favourable_excursion.max(profit_loss);
+    }
+
+    /// Calculate the entry notional value of a [`Position`] in the reporting currency, using it's
+    /// current [`Contract`] terms. See [`Position::enter_notional`].
+    pub fn calculate_enter_notional(&self) -> Decimal {
+        self.contract.notional(
+            self.quantity,
+            self.enter_avg_price_gross,
+            self.enter_value_gross,
+        )
     }
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
     /// appropriately calculated.
-    pub fn calculate_profit_loss_return(&self) -> f64 {
-        self.realised_profit_loss / self.enter_value_gross
+    pub fn calculate_profit_loss_return(&self) -> Decimal {
+        self.realised_profit_loss
+            .checked_div(self.enter_notional)
+            .unwrap_or_default()
     }
 }
 
@@ -269,19 +418,21 @@ pub struct PositionBuilder {
     pub instrument: Option<Instrument>,
     pub meta: Option<PositionMeta>,
     pub side: Option<Side>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub contract: Option<Contract>,
     pub enter_fees: Option<Fees>,
     pub enter_fees_total: Option<FeeAmount>,
-    pub enter_avg_price_gross: Option<f64>,
-    pub enter_value_gross: Option<f64>,
+    pub enter_avg_price_gross: Option<Decimal>,
+    pub enter_value_gross: Option<Decimal>,
+    pub enter_notional: Option<Decimal>,
     pub exit_fees: Option<Fees>,
     pub exit_fees_total: Option<FeeAmount>,
-    pub exit_avg_price_gross: Option<f64>,
-    pub exit_value_gross: Option<f64>,
-    pub current_symbol_price: Option<f64>,
-    pub current_value_gross: Option<f64>,
-    pub unrealised_profit_loss: Option<f64>,
-    pub realised_profit_loss: Option<f64>,
+    pub exit_avg_price_gross: Option<Decimal>,
+    pub exit_value_gross: Option<Decimal>,
+    pub current_symbol_price: Option<Decimal>,
+    pub current_value_gross: Option<Decimal>,
+    pub unrealised_profit_loss: Option<Decimal>,
+    pub realised_profit_loss: Option<Decimal>,
 }
 
 impl PositionBuilder {
@@ -324,13 +475,20 @@ impl PositionBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
         }
     }
 
+    pub fn contract(self, value: Contract) -> Self {
+        Self {
+            contract: Some(value),
+            ..self
+        }
+    }
+
     pub fn enter_fees(self, value: Fees) -> Self {
         Self {
             enter_fees: Some(value),
@@ -345,20 +503,27 @@ impl PositionBuilder {
         }
     }
 
-    pub fn enter_avg_price_gross(self, value: f64) -> Self {
+    pub fn enter_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             enter_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn enter_value_gross(self, value: f64) -> Self {
+    pub fn enter_value_gross(self, value: Decimal) -> Self {
         Self {
             enter_value_gross: Some(value),
             ..self
         }
     }
 
+    pub fn enter_notional(self, value: Decimal) -> Self {
+        Self {
+            enter_notional: Some(value),
+            ..self
+        }
+    }
+
     pub fn exit_fees(self, value: Fees) -> Self {
         Self {
             exit_fees: Some(value),
@@ -373,42 +538,42 @@ impl PositionBuilder {
         }
     }
 
-    pub fn exit_avg_price_gross(self, value: f64) -> Self {
+    pub fn exit_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             exit_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn exit_value_gross(self, value: f64) -> Self {
+    pub fn exit_value_gross(self, value: Decimal) -> Self {
         Self {
             exit_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn current_symbol_price(self, value: f64) -> Self {
+    pub fn current_symbol_price(self, value: Decimal) -> Self {
         Self {
             current_symbol_price: Some(value),
             ..self
         }
     }
 
-    pub fn current_value_gross(self, value: f64) -> Self {
+    pub fn current_value_gross(self, value: Decimal) -> Self {
         Self {
             current_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn unrealised_profit_loss(self, value: f64) -> Self {
+    pub fn unrealised_profit_loss(self, value: Decimal) -> Self {
         Self {
             unrealised_profit_loss: Some(value),
             ..self
         }
     }
 
-    pub fn realised_profit_loss(self, value: f64) -> Self {
+    pub fn realised_profit_loss(self, value: Decimal) -> Self {
         Self {
             realised_profit_loss: Some(value),
             ..self
@@ -431,6 +596,7 @@ impl PositionBuilder {
             quantity: self
                 .quantity
                 .ok_or(PortfolioError::BuilderIncomplete("quantity"))?,
+            contract: self.contract.unwrap_or_default(),
             enter_fees: self
                 .enter_fees
                 .ok_or(PortfolioError::BuilderIncomplete("enter_fees"))?,
@@ -443,6 +609,9 @@ impl PositionBuilder {
             enter_value_gross: self
                 .enter_value_gross
                 .ok_or(PortfolioError::BuilderIncomplete("enter_value_gross"))?,
+            enter_notional: self
+                .enter_notional
+                .ok_or(PortfolioError::BuilderIncomplete("enter_notional"))?,
             exit_fees: self
                 .exit_fees
                 .ok_or(PortfolioError::BuilderIncomplete("exit_fees"))?,
@@ -483,6 +652,21 @@ pub struct PositionMeta {
 
     /// Portfolio [`Balance`] calculated at the point of exiting a [`Position`].
     pub exit_balance: Option<Balance>,
+
+    /// [`Ensemble`](crate::strategy::ensemble::Ensemble) members that contributed to the
+    /// [`Signal`](crate::strategy::Signal) that entered this [`Position`], if any.
+    #[serde(default)]
+    pub attribution: Option<Attribution>,
+
+    /// Maximum adverse excursion - the lowest PnL (unrealised or realised) of this [`Position`]
+    /// whilst it was open, capped at zero.
+    #[serde(default)]
+    pub max_adverse_excursion: Decimal,
+
+    /// Maximum favourable excursion - the highest PnL (unrealised or realised) of this
+    /// [`Position`] whilst it was open, floored at zero.
+    #[serde(default)]
+    pub max_favourable_excursion: Decimal,
 }
 
 impl Default for PositionMeta {
@@ -491,6 +675,9 @@ impl Default for PositionMeta {
             enter_time: Utc::now(),
             update_time: Utc::now(),
             exit_balance: None,
+            attribution: None,
+            max_adverse_excursion: Decimal::ZERO,
+            max_favourable_excursion: Decimal::ZERO,
         }
     }
 }
@@ -503,11 +690,11 @@ pub struct PositionUpdate {
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Decimal,
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Decimal,
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Decimal,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -541,13 +728,13 @@ pub struct PositionExit {
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    pub exit_value_gross: Decimal,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    pub realised_profit_loss: Decimal,
 }
 
 impl TryFrom<&mut Position> for PositionExit {
@@ -575,24 +762,25 @@ mod tests {
     use super::*;
     use crate::test_util::{fill_event, market_event_trade, position};
     use barter_integration::model::Side;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Buy);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, dec!(3.0));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -601,35 +789,35 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, dec!(0.0));
+        assert_eq!(position.exit_avg_price_gross, dec!(0.0));
+        assert_eq!(position.exit_value_gross, dec!(0.0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, dec!(-6.0)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, dec!(0.0));
     }
 
     #[test]
     fn enter_new_position_with_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Sell);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, dec!(3.0));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -638,28 +826,28 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, dec!(0.0));
+        assert_eq!(position.exit_avg_price_gross, dec!(0.0));
+        assert_eq!(position.exit_value_gross, dec!(0.0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, dec!(-6.0)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, dec!(0.0));
     }
 
     #[test]
     fn enter_new_position_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -676,12 +864,12 @@ mod tests {
     {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -698,12 +886,12 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -720,12 +908,12 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1.0);
+        input_fill.fill_value_gross = dec!(100.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
@@ -742,25 +930,25 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
         match input_market.kind {
             // +100.0 higher than current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(200.0),
             _ => todo!(),
         };
 
@@ -769,18 +957,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Candle(candle) => Decimal::from_f64(candle.close).unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -790,7 +978,15 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(200.0) - dec!(100.0) - dec!(6.0))
+        );
+        assert_eq!(position.meta.max_adverse_excursion, dec!(0.0));
+        assert_eq!(
+            position.meta.max_favourable_excursion,
+            (dec!(200.0) - dec!(100.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -798,18 +994,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -817,7 +1013,7 @@ mod tests {
         match input_market.kind {
             // -50.0 lower than current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(50.0),
             _ => todo!(),
         };
 
@@ -826,18 +1022,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Candle(candle) => Decimal::from_f64(candle.close).unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -847,7 +1043,15 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(50.0) - dec!(100.0) - dec!(6.0))
+        );
+        assert_eq!(
+            position.meta.max_adverse_excursion,
+            (dec!(50.0) - dec!(100.0) - dec!(6.0))
+        );
+        assert_eq!(position.meta.max_favourable_excursion, dec!(0.0));
     }
 
     #[test]
@@ -855,18 +1059,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -874,7 +1078,7 @@ mod tests {
         match input_market.kind {
             // -50.0 lower than current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(50.0),
             _ => todo!(),
         };
 
@@ -883,18 +1087,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Candle(candle) => Decimal::from_f64(candle.close).unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -904,7 +1108,10 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100.0) - dec!(50.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -912,18 +1119,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -931,7 +1138,7 @@ mod tests {
         match input_market.kind {
             // +100.0 higher than current_symbol_price
             DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Trade(ref mut trade) => trade.price = dec!(200.0),
             _ => todo!(),
         };
 
@@ -940,18 +1147,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
             DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Candle(candle) => Decimal::from_f64(candle.close).unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -961,7 +1168,10 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100.0) - dec!(200.0) - dec!(6.0))
+        );
     }
 
     #[test]
@@ -969,35 +1179,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1005,19 +1215,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3.0));
+        assert_eq!(position.exit_fees.exchange, dec!(1.0));
+        assert_eq!(position.exit_fees.slippage, dec!(1.0));
+        assert_eq!(position.exit_fees.network, dec!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1025,13 +1235,19 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (200.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(200.0) - dec!(100.0) - dec!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(200.0) - dec!(100.0) - dec!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (200.0 - 100.0 - 6.0)
+            current_balance.total + (dec!(200.0) - dec!(100.0) - dec!(6.0))
         )
     }
 
@@ -1040,35 +1256,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = dec!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1076,19 +1292,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3.0));
+        assert_eq!(position.exit_fees.exchange, dec!(1.0));
+        assert_eq!(position.exit_fees.slippage, dec!(1.0));
+        assert_eq!(position.exit_fees.network, dec!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1096,13 +1312,19 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (50.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(50.0) - dec!(100.0) - dec!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(50.0) - dec!(100.0) - dec!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (50.0 - 100.0 - 6.0)
+            current_balance.total + (dec!(50.0) - dec!(100.0) - dec!(6.0))
         )
     }
 
@@ -1111,35 +1333,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = dec!(50.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1147,19 +1369,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3.0));
+        assert_eq!(position.exit_fees.exchange, dec!(1.0));
+        assert_eq!(position.exit_fees.slippage, dec!(1.0));
+        assert_eq!(position.exit_fees.network, dec!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1167,13 +1389,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 50.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(100.0) - dec!(50.0) - dec!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100.0) - dec!(50.0) - dec!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 50.0 - 6.0)
+            current_balance.total + (dec!(100.0) - dec!(50.0) - dec!(6.0))
         )
     }
 
@@ -1182,35 +1410,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1218,19 +1446,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1.0));
+        assert_eq!(position.enter_fees_total, dec!(3.0));
+        assert_eq!(position.enter_fees.exchange, dec!(1.0));
+        assert_eq!(position.enter_fees.slippage, dec!(1.0));
+        assert_eq!(position.enter_fees.network, dec!(1.0));
+        assert_eq!(position.enter_avg_price_gross, dec!(100.0));
+        assert_eq!(position.enter_value_gross, dec!(100.0));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3.0));
+        assert_eq!(position.exit_fees.exchange, dec!(1.0));
+        assert_eq!(position.exit_fees.slippage, dec!(1.0));
+        assert_eq!(position.exit_fees.network, dec!(1.0));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1238,13 +1466,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 200.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(100.0) - dec!(200.0) - dec!(6.0))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100.0) - dec!(200.0) - dec!(6.0))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 200.0 - 6.0)
+            current_balance.total + (dec!(100.0) - dec!(200.0) - dec!(6.0))
         )
     }
 
@@ -1253,35 +1487,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
         input_fill.quantity = position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1299,35 +1533,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1.0);
+        position.enter_fees_total = dec!(3.0);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100.0);
+        position.enter_value_gross = dec!(100.0);
+        position.current_symbol_price = dec!(100.0);
+        position.current_value_gross = dec!(100.0);
+        position.unrealised_profit_loss = position.enter_fees_total * dec!(-2.0);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000.0),
+            available: dec!(10000.0),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200.0);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1.0),
+            slippage: dec!(1.0),
+            network: dec!(1.0),
         };
 
         // Exit Position
@@ -1340,33 +1574,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn exit_many_positions_without_balance_drift() {
+        let mut balance = Balance {
+            time: Utc::now(),
+            total: dec!(10000.0),
+            available: dec!(10000.0),
+        };
+
+        let fees = Fees {
+            exchange: dec!(0.01),
+            slippage: dec!(0.0),
+            network: dec!(0.0),
+        };
+
+        for _ in 0..10_000 {
+            let mut enter_fill = fill_event();
+            enter_fill.decision = Decision::Long;
+            enter_fill.quantity = dec!(0.1);
+            enter_fill.fill_value_gross = dec!(10.01);
+            enter_fill.fees = fees;
+
+            let mut exit_fill = fill_event();
+            exit_fill.decision = Decision::CloseLong;
+            exit_fill.quantity = dec!(-0.1);
+            exit_fill.fill_value_gross = dec!(10.05);
+            exit_fill.fees = fees;
+
+            let mut position = Position::enter(Uuid::new_v4(), &enter_fill).unwrap();
+            balance = position.exit(balance, &exit_fill).unwrap().exit_balance;
+        }
+
+        // Each Position realises 10.05 - 10.01 - 0.02 = 0.02
+        assert_eq!(balance.total, dec!(10200.0));
+    }
+
     #[test]
     fn calculate_avg_price_gross_correctly_with_positive_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = dec!(1000.0);
+        input_fill.quantity = dec!(1.0);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, dec!(1000.0))
     }
 
     #[test]
     fn calculate_avg_price_gross_correctly_with_negative_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = dec!(1000.0);
+        input_fill.quantity = dec!(-1.0);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, dec!(1000.0))
     }
 
     #[test]
     fn parse_entry_side_as_long_with_positive_quantity_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1.0);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1377,7 +1646,7 @@ mod tests {
     fn parse_entry_side_as_short_with_negative_quantity_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1.0);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1388,7 +1657,7 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1403,7 +1672,7 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_short_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1419,7 +1688,7 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1435,7 +1704,7 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1.0);
 
         if let Err(_) = Position::parse_entry_side(&input_fill) {
             Ok(())
@@ -1450,31 +1719,31 @@ mod tests {
     fn calculate_unreal_profit_loss() {
         let mut long_win = position(); // Expected PnL = +8.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.current_value_gross = 110.0;
+        long_win.enter_value_gross = dec!(100.0);
+        long_win.enter_fees_total = dec!(1.0);
+        long_win.current_value_gross = dec!(110.0);
 
         let mut long_lose = position(); // Expected PnL = -12.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.current_value_gross = 90.0;
+        long_lose.enter_value_gross = dec!(100.0);
+        long_lose.enter_fees_total = dec!(1.0);
+        long_lose.current_value_gross = dec!(90.0);
 
         let mut short_win = position(); // Expected PnL = +8.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.current_value_gross = 90.0;
+        short_win.enter_value_gross = dec!(100.0);
+        short_win.enter_fees_total = dec!(1.0);
+        short_win.current_value_gross = dec!(90.0);
 
         let mut short_lose = position(); // Expected PnL = -12.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.current_value_gross = 110.0;
+        short_lose.enter_value_gross = dec!(100.0);
+        short_lose.enter_fees_total = dec!(1.0);
+        short_lose.current_value_gross = dec!(110.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![8.0, -12.0, 8.0, -12.0];
+        let expected_pnl = vec![dec!(8.0), dec!(-12.0), dec!(8.0), dec!(-12.0)];
 
         for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
             let actual = position.calculate_unrealised_profit_loss();
@@ -1486,35 +1755,35 @@ mod tests {
     fn calculate_realised_profit_loss() {
         let mut long_win = position(); // Expected PnL = +18.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.exit_value_gross = 120.0;
-        long_win.exit_fees_total = 1.0;
+        long_win.enter_value_gross = dec!(100.0);
+        long_win.enter_fees_total = dec!(1.0);
+        long_win.exit_value_gross = dec!(120.0);
+        long_win.exit_fees_total = dec!(1.0);
 
         let mut long_lose = position(); // Expected PnL = -22.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.exit_value_gross = 80.0;
-        long_lose.exit_fees_total = 1.0;
+        long_lose.enter_value_gross = dec!(100.0);
+        long_lose.enter_fees_total = dec!(1.0);
+        long_lose.exit_value_gross = dec!(80.0);
+        long_lose.exit_fees_total = dec!(1.0);
 
         let mut short_win = position(); // Expected PnL = +18.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.exit_value_gross = 80.0;
-        short_win.exit_fees_total = 1.0;
+        short_win.enter_value_gross = dec!(100.0);
+        short_win.enter_fees_total = dec!(1.0);
+        short_win.exit_value_gross = dec!(80.0);
+        short_win.exit_fees_total = dec!(1.0);
 
         let mut short_lose = position(); // Expected PnL = -22.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.exit_value_gross = 120.0;
-        short_lose.exit_fees_total = 1.0;
+        short_lose.enter_value_gross = dec!(100.0);
+        short_lose.enter_fees_total = dec!(1.0);
+        short_lose.exit_value_gross = dec!(120.0);
+        short_lose.exit_fees_total = dec!(1.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![18.0, -22.0, 18.0, -22.0];
+        let expected_pnl = vec![dec!(18.0), dec!(-22.0), dec!(18.0), dec!(-22.0)];
 
         for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
             let actual = position.calculate_realised_profit_loss();
@@ -1522,31 +1791,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calculate_inverse_contract_profit_loss() {
+        // 10 BTC-USD inverse contracts worth 100 USD each, entered at 20000 & exited at 25000
+        // '--> PnL = 1000 * (1/20000 - 1/25000) = 0.01 BTC = 250 USD at the exit price
+        let mut long = position();
+        long.side = Side::Buy;
+        long.quantity = dec!(10);
+        long.contract = Contract {
+            settlement: Settlement::Inverse,
+            multiplier: dec!(100),
+            settlement_rate: Decimal::ONE,
+        };
+        long.enter_avg_price_gross = dec!(20000);
+        long.enter_value_gross = dec!(200000);
+        long.enter_fees_total = dec!(1.0);
+        long.exit_avg_price_gross = dec!(25000);
+        long.exit_value_gross = dec!(250000);
+        long.exit_fees_total = dec!(1.0);
+        long.current_symbol_price = dec!(16000);
+        long.current_value_gross = dec!(160000);
+
+        let mut short = long.clone();
+        short.side = Side::Sell;
+        short.quantity = dec!(-10);
+
+        assert_eq!(long.calculate_realised_profit_loss(), dec!(248.0));
+        assert_eq!(short.calculate_realised_profit_loss(), dec!(-252.0));
+
+        // 1000 * (16000/20000 - 1) - 2 * enter_fees_total
+        assert_eq!(long.calculate_unrealised_profit_loss(), dec!(-202.0));
+        assert_eq!(short.calculate_unrealised_profit_loss(), dec!(198.0));
+
+        // Return on the 1000 USD notional
+        long.realised_profit_loss = long.calculate_realised_profit_loss();
+        long.enter_notional = long.calculate_enter_notional();
+        assert_eq!(long.calculate_profit_loss_return(), dec!(0.248));
+    }
+
+    #[test]
+    fn calculate_quanto_contract_profit_loss() {
+        // 100 ETH-USD quanto contracts worth 0.000001 BTC per USD, reported in USD at 30000 USD/BTC
+        // '--> PnL = 100 * 0.000001 * (2100 - 2000) = 0.01 BTC = 300 USD
+        let mut long = position();
+        long.side = Side::Buy;
+        long.quantity = dec!(100);
+        long.contract = Contract {
+            settlement: Settlement::Quanto,
+            multiplier: dec!(0.000001),
+            settlement_rate: dec!(30000),
+        };
+        long.enter_avg_price_gross = dec!(2000);
+        long.enter_value_gross = dec!(200000);
+        long.enter_fees_total = dec!(1.0);
+        long.exit_avg_price_gross = dec!(2100);
+        long.exit_value_gross = dec!(210000);
+        long.exit_fees_total = dec!(1.0);
+        long.current_symbol_price = dec!(1900);
+        long.current_value_gross = dec!(190000);
+
+        let mut short = long.clone();
+        short.side = Side::Sell;
+        short.quantity = dec!(-100);
+
+        assert_eq!(long.calculate_realised_profit_loss(), dec!(298.0));
+        assert_eq!(short.calculate_realised_profit_loss(), dec!(-302.0));
+        assert_eq!(long.calculate_unrealised_profit_loss(), dec!(-302.0));
+        assert_eq!(short.calculate_unrealised_profit_loss(), dec!(298.0));
+
+        // Return on the 100 * 0.000001 * 2000 * 30000 = 6000 USD notional
+        short.realised_profit_loss = short.calculate_realised_profit_loss();
+        short.enter_notional = short.calculate_enter_notional();
+        assert_eq!(
+            short.calculate_profit_loss_return(),
+            dec!(-302.0) / dec!(6000)
+        );
+    }
+
     #[test]
     fn calculate_profit_loss_return() {
         let mut long_win = position(); // Expected Return = 0.08
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.realised_profit_loss = 8.0;
+        long_win.enter_value_gross = dec!(100.0);
+        long_win.realised_profit_loss = dec!(8.0);
 
         let mut long_lose = position(); // Expected Return = -0.12
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.realised_profit_loss = -12.0;
+        long_lose.enter_value_gross = dec!(100.0);
+        long_lose.realised_profit_loss = dec!(-12.0);
 
         let mut short_win = position(); // Expected Return = 0.08
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.realised_profit_loss = 8.0;
+        short_win.enter_value_gross = dec!(100.0);
+        short_win.realised_profit_loss = dec!(8.0);
 
         let mut short_lose = position(); // Expected Return = -0.12
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.realised_profit_loss = -12.0;
+        short_lose.enter_value_gross = dec!(100.0);
+        short_lose.realised_profit_loss = dec!(-12.0);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_return = vec![0.08, -0.12, 0.08, -0.12];
+        let expected_return = vec![dec!(0.08), dec!(-0.12), dec!(0.08), dec!(-0.12)];
 
         for (position, expected) in inputs.into_iter().zip(expected_return.into_iter()) {
             let actual = position.calculate_profit_loss_return();
@@ -1569,9 +1915,9 @@ mod tests {
     #[test]
     fn position_update_from_position() {
         let mut input_position = position();
-        input_position.current_symbol_price = 100.0;
-        input_position.current_value_gross = 200.0;
-        input_position.unrealised_profit_loss = 150.0;
+        input_position.current_symbol_price = dec!(100.0);
+        input_position.current_value_gross = dec!(200.0);
+        input_position.unrealised_profit_loss = dec!(150.0);
 
         let actual_update = PositionUpdate::from(&mut input_position);
 
@@ -1597,19 +1943,19 @@ mod tests {
         exited_position.meta.update_time = time;
         exited_position.meta.exit_balance = Some(Balance {
             time,
-            total: 0.0,
-            available: 0.0,
+            total: dec!(0.0),
+            available: dec!(0.0),
         });
 
         exited_position.exit_fees = Fees {
-            exchange: 0.0,
-            slippage: 0.0,
-            network: 0.0,
+            exchange: dec!(0.0),
+            slippage: dec!(0.0),
+            network: dec!(0.0),
         };
-        exited_position.exit_fees_total = 0.0;
-        exited_position.exit_avg_price_gross = 100.0;
-        exited_position.exit_value_gross = 100.0;
-        exited_position.realised_profit_loss = 100.0;
+        exited_position.exit_fees_total = dec!(0.0);
+        exited_position.exit_avg_price_gross = dec!(100.0);
+        exited_position.exit_value_gross = dec!(100.0);
+        exited_position.realised_profit_loss = dec!(100.0);
 
         let actual_exit = PositionExit::try_from(&mut exited_position).unwrap();
 
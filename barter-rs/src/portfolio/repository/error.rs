@@ -17,4 +17,16 @@ pub enum RepositoryError {
 
     #[error("Failed to retrieve expected data due to it not being present")]
     ExpectedDataNotPresentError,
+
+    #[error("Redis operation failed due to: {0}")]
+    RedisError(#[from] redis::RedisError),
+
+    #[error("SQLite operation failed due to: {0}")]
+    SqliteError(#[from] rusqlite::Error),
+
+    #[error("Postgres operation failed due to: {0}")]
+    PostgresError(#[from] postgres::Error),
+
+    #[error("Failed to manage repository unit of work: {0}")]
+    UnitOfWorkError(&'static str),
 }
@@ -3,7 +3,7 @@ use crate::{
         position::{determine_position_id, Position, PositionId},
         repository::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            StatisticHandler, TransactionHandler,
         },
         Balance, BalanceId,
     },
@@ -17,18 +17,32 @@ use uuid::Uuid;
 /// & [`StatisticHandler`]. Used by a Proof Of Concept Portfolio implementation to
 /// save the current equity, available cash, Positions, and market pair statistics.
 /// **Careful in production - no fault tolerant guarantees!**
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct InMemoryRepository<Statistic: PositionSummariser> {
     open_positions: HashMap<PositionId, Position>,
     closed_positions: HashMap<String, Vec<Position>>,
     current_balances: HashMap<BalanceId, Balance>,
     statistics: HashMap<MarketId, Statistic>,
+    /// Previous values of every key written during the current unit of work, restored in reverse
+    /// order on rollback. `None` when no unit of work is in progress.
+    undo_log: Option<Vec<Undo<Statistic>>>,
+}
+
+/// Previous value of a key written during an [`InMemoryRepository`] unit of work.
+#[derive(Debug, Clone)]
+enum Undo<Statistic> {
+    OpenPosition(PositionId, Option<Box<Position>>),
+    /// Number of exited Positions stored under the key before the write.
+    ExitedPositions(String, usize),
+    Balance(BalanceId, Option<Balance>),
+    Statistics(MarketId, Option<Statistic>),
 }
 
 impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Statistic> {
     fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
-        self.open_positions
-            .insert(position.position_id.clone(), position);
+        let position_id = position.position_id.clone();
+        let previous = self.open_positions.insert(position_id.clone(), position);
+        self.record(|| Undo::OpenPosition(position_id, previous.map(Box::new)));
         Ok(())
     }
 
@@ -57,11 +71,29 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
             .collect())
     }
 
+    fn get_all_open_positions(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<Position>, RepositoryError> {
+        let engine_prefix = format!("{}_", engine_id);
+
+        Ok(self
+            .open_positions
+            .iter()
+            .filter(|(position_id, _)| position_id.starts_with(&engine_prefix))
+            .map(|(_, position)| position.clone())
+            .collect())
+    }
+
     fn remove_position(
         &mut self,
         position_id: &String,
     ) -> Result<Option<Position>, RepositoryError> {
-        Ok(self.open_positions.remove(position_id))
+        let removed = self.open_positions.remove(position_id);
+        if removed.is_some() {
+            self.record(|| Undo::OpenPosition(position_id.clone(), removed.clone().map(Box::new)));
+        }
+        Ok(removed)
     }
 
     fn set_exited_position(
@@ -71,13 +103,14 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
     ) -> Result<(), RepositoryError> {
         let exited_positions_key = determine_exited_positions_id(engine_id);
 
-        match self.closed_positions.get_mut(&exited_positions_key) {
-            None => {
-                self.closed_positions
-                    .insert(exited_positions_key, vec![position]);
-            }
-            Some(closed_positions) => closed_positions.push(position),
-        }
+        let closed_positions = self
+            .closed_positions
+            .entry(exited_positions_key.clone())
+            .or_default();
+        let previous_len = closed_positions.len();
+        closed_positions.push(position);
+
+        self.record(|| Undo::ExitedPositions(exited_positions_key, previous_len));
         Ok(())
     }
 
@@ -92,8 +125,9 @@ impl<Statistic: PositionSummariser> PositionHandler for InMemoryRepository<Stati
 
 impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statistic> {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
-        self.current_balances
-            .insert(Balance::balance_id(engine_id), balance);
+        let balance_id = Balance::balance_id(engine_id);
+        let previous = self.current_balances.insert(balance_id.clone(), balance);
+        self.record(|| Undo::Balance(balance_id, previous));
         Ok(())
     }
 
@@ -111,18 +145,77 @@ impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepo
         market_id: MarketId,
         statistic: Statistic,
     ) -> Result<(), RepositoryError> {
-        self.statistics.insert(market_id, statistic);
+        let previous = self.statistics.insert(market_id.clone(), statistic);
+        self.record(|| Undo::Statistics(market_id, previous));
         Ok(())
     }
 
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
         self.statistics
             .get(market_id)
-            .copied()
+            .cloned()
             .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
 }
 
+impl<Statistic: PositionSummariser> TransactionHandler for InMemoryRepository<Statistic> {
+    fn begin(&mut self) -> Result<(), RepositoryError> {
+        if self.undo_log.is_some() {
+            return Err(RepositoryError::UnitOfWorkError(
+                "unit of work already in progress",
+            ));
+        }
+
+        self.undo_log = Some(Vec::new());
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), RepositoryError> {
+        self.undo_log
+            .take()
+            .map(|_| ())
+            .ok_or(RepositoryError::UnitOfWorkError(
+                "no unit of work in progress",
+            ))
+    }
+
+    fn rollback(&mut self) -> Result<(), RepositoryError> {
+        let undo_log = self
+            .undo_log
+            .take()
+            .ok_or(RepositoryError::UnitOfWorkError(
+                "no unit of work in progress",
+            ))?;
+
+        // Restore previous values in reverse order, so the earliest write of a key wins
+        for undo in undo_log.into_iter().rev() {
+            match undo {
+                Undo::OpenPosition(position_id, previous) => restore(
+                    &mut self.open_positions,
+                    position_id,
+                    previous.map(|position| *position),
+                ),
+                Undo::ExitedPositions(key, previous_len) => {
+                    if let Some(closed_positions) = self.closed_positions.get_mut(&key) {
+                        closed_positions.truncate(previous_len);
+                        if closed_positions.is_empty() {
+                            self.closed_positions.remove(&key);
+                        }
+                    }
+                }
+                Undo::Balance(balance_id, previous) => {
+                    restore(&mut self.current_balances, balance_id, previous)
+                }
+                Undo::Statistics(market_id, previous) => {
+                    restore(&mut self.statistics, market_id, previous)
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
     /// Constructs a new [`InMemoryRepository`] component.
     pub fn new() -> Self {
@@ -131,6 +224,103 @@ impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
             closed_positions: HashMap::new(),
             current_balances: HashMap::new(),
             statistics: HashMap::new(),
+            undo_log: None,
+        }
+    }
+
+    /// Record the previous value of a key in the undo log if a unit of work is in progress.
+    fn record<F>(&mut self, undo: F)
+    where
+        F: FnOnce() -> Undo<Statistic>,
+    {
+        if let Some(undo_log) = self.undo_log.as_mut() {
+            undo_log.push(undo());
+        }
+    }
+}
+
+/// Restore the previous value of a key, removing it if it did not previously exist.
+fn restore<Key, Value>(map: &mut HashMap<Key, Value>, key: Key, previous: Option<Value>)
+where
+    Key: std::hash::Hash + Eq,
+{
+    match previous {
+        Some(previous) => {
+            map.insert(key, previous);
+        }
+        None => {
+            map.remove(&key);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{statistic::summary::pnl::PnLReturnSummary, test_util::position};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn rollback_restores_only_keys_written_in_unit_of_work() {
+        let engine_id = Uuid::new_v4();
+        let mut repository = InMemoryRepository::<PnLReturnSummary>::new();
+
+        let balance = Balance {
+            time: Utc::now(),
+            total: dec!(100.0),
+            available: dec!(100.0),
+        };
+        let mut open = position();
+        open.position_id = "open".to_string();
+        repository.set_balance(engine_id, balance).unwrap();
+        repository.set_open_position(open.clone()).unwrap();
+
+        // Unit of work that exits the open Position & updates the Balance
+        repository.begin().unwrap();
+        let exited = repository
+            .remove_position(&open.position_id)
+            .unwrap()
+            .unwrap();
+        repository.set_exited_position(engine_id, exited).unwrap();
+        repository
+            .set_balance(
+                engine_id,
+                Balance {
+                    total: dec!(110.0),
+                    ..balance
+                },
+            )
+            .unwrap();
+        repository.rollback().unwrap();
+
+        assert_eq!(repository.get_balance(engine_id).unwrap(), balance);
+        assert_eq!(
+            repository.get_open_position(&open.position_id).unwrap(),
+            Some(open)
+        );
+        assert!(repository
+            .get_exited_positions(engine_id)
+            .unwrap()
+            .is_empty());
+
+        // Committed writes persist, and rollback without a unit of work is an error
+        repository.begin().unwrap();
+        repository
+            .set_balance(
+                engine_id,
+                Balance {
+                    total: dec!(120.0),
+                    ..balance
+                },
+            )
+            .unwrap();
+        repository.commit().unwrap();
+
+        assert_eq!(
+            repository.get_balance(engine_id).unwrap().total,
+            dec!(120.0)
+        );
+        assert!(repository.rollback().is_err());
+    }
+}
@@ -15,6 +15,9 @@ pub mod in_memory;
 /// Redis repository for state keeping.
 pub mod redis;
 
+/// SQL repository (SQLite & Postgres) for state keeping, with queryable history.
+pub mod sql;
+
 /// Handles the reading & writing of a [`Position`] to/from the persistence layer.
 pub trait PositionHandler {
     /// Upsert the open [`Position`] using it's [`PositionId`].
@@ -33,6 +36,10 @@ pub trait PositionHandler {
         markets: Markets,
     ) -> Result<Vec<Position>, RepositoryError>;
 
+    /// Get every open [`Position`] associated with the engine_id, regardless of [`Market`].
+    fn get_all_open_positions(&mut self, engine_id: Uuid)
+        -> Result<Vec<Position>, RepositoryError>;
+
     /// Remove the [`Position`] at the [`PositionId`].
     fn remove_position(
         &mut self,
@@ -71,6 +78,22 @@ pub trait StatisticHandler<Statistic> {
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError>;
 }
 
+/// Groups the repository writes of a multi-step Portfolio operation (eg/ updating from a
+/// [`FillEvent`](crate::execution::FillEvent)) into a unit of work that is persisted atomically.
+///
+/// Reads made during a unit of work are not guaranteed to observe the writes staged within it,
+/// so operations should read the state they require before writing.
+pub trait TransactionHandler {
+    /// Begin a unit of work. Subsequent writes are staged until committed.
+    fn begin(&mut self) -> Result<(), RepositoryError>;
+
+    /// Atomically persist every write staged since [`TransactionHandler::begin`].
+    fn commit(&mut self) -> Result<(), RepositoryError>;
+
+    /// Discard every write staged since [`TransactionHandler::begin`].
+    fn rollback(&mut self) -> Result<(), RepositoryError>;
+}
+
 /// Communicates a String represents a unique identifier for all a Portfolio's exited [`Position`]s.
 /// Used to append new exited [`Position`]s to the entry in the [`PositionHandler`].
 pub type ExitedPositionsId = String;
@@ -4,14 +4,17 @@ use crate::{
         position::{determine_position_id, Position, PositionId},
         repository::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
-            StatisticHandler,
+            StatisticHandler, TransactionHandler,
         },
         Balance,
     },
     statistic::summary::PositionSummariser,
 };
 use barter_integration::model::{Market, MarketId};
-use redis::{Commands, Connection, ErrorKind};
+use redis::{
+    Cmd, Commands, Connection, ConnectionLike, ErrorKind, FromRedisValue, Pipeline, RedisError,
+    RedisResult,
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
     fmt::{Debug, Formatter},
@@ -23,40 +26,112 @@ use uuid::Uuid;
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize)]
 pub struct Config {
     pub uri: String,
+    /// Namespace prepended to every key (eg/ "paper" -> "paper:{key}"). Used to isolate
+    /// environments that share a Redis instance.
+    #[serde(default)]
+    pub key_prefix: Option<String>,
+    /// Maximum number of exited [`Position`]s retained per engine_id, the oldest are trimmed. If
+    /// zero, exited [`Position`]s are not retained.
+    #[serde(default)]
+    pub exited_positions_max_len: Option<usize>,
+    /// Seconds an engine_id's exited [`Position`]s are retained after the last was appended.
+    #[serde(default)]
+    pub exited_positions_ttl_secs: Option<usize>,
+    /// Maximum number of connections managed by a pool, see
+    /// [`RedisRepository::setup_redis_pool`].
+    #[serde(default)]
+    pub pool_max_size: Option<u32>,
+}
+
+/// Pool of Redis connections that can be shared between [`RedisRepository`] instances.
+pub type RedisPool = r2d2::Pool<redis::Client>;
+
+/// Source of the Redis connection used by a [`RedisRepository`] for each operation.
+///
+/// Implemented for a single blocking [`Connection`], as well as for a [`RedisPool`] that checks
+/// out a pooled [`Connection`] per operation.
+pub trait RedisConnection {
+    /// Blocking Redis connection the operations are executed on.
+    type Connection: ConnectionLike;
+
+    /// Execute the operation using a Redis connection.
+    fn with_connection<T, Operation>(&mut self, operation: Operation) -> RedisResult<T>
+    where
+        Operation: FnOnce(&mut Self::Connection) -> RedisResult<T>;
+}
+
+impl RedisConnection for Connection {
+    type Connection = Connection;
+
+    fn with_connection<T, Operation>(&mut self, operation: Operation) -> RedisResult<T>
+    where
+        Operation: FnOnce(&mut Connection) -> RedisResult<T>,
+    {
+        operation(self)
+    }
+}
+
+impl RedisConnection for RedisPool {
+    type Connection = Connection;
+
+    fn with_connection<T, Operation>(&mut self, operation: Operation) -> RedisResult<T>
+    where
+        Operation: FnOnce(&mut Connection) -> RedisResult<T>,
+    {
+        let mut connection = self.get().map_err(|error| {
+            RedisError::from((
+                ErrorKind::IoError,
+                "failed to check out pooled Redis connection",
+                error.to_string(),
+            ))
+        })?;
+
+        operation(&mut connection)
+    }
 }
 
 /// Redis persisted repository that implements [`PositionHandler`], [`BalanceHandler`],
 /// & [`PositionSummariser`]. Used by a Portfolio implementation to persist the Portfolio state,
 /// including total equity, available cash & Positions.
-pub struct RedisRepository<Statistic>
+///
+/// Operates on a single [`Connection`] by default, or on a shared [`RedisPool`]. Every key is
+/// namespaced by the optional key prefix.
+///
+/// Writes made during a unit of work (see [`TransactionHandler`]) are queued in an atomic
+/// pipeline, and sent to Redis as a single `MULTI`/`EXEC` round trip on commit.
+///
+/// Every operation blocks the calling thread on a network round trip, which
+/// [`Trader::run_async`](crate::engine::trader::Trader::run_async) runs via
+/// [`tokio::task::block_in_place`] so it does not stall the tokio runtime worker threads.
+pub struct RedisRepository<Statistic, Conn = Connection>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
-    conn: Connection,
+    conn: Conn,
+    key_prefix: Option<String>,
+    exited_positions_max_len: Option<usize>,
+    exited_positions_ttl_secs: Option<usize>,
+    pipeline: Option<Pipeline>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
-impl<Statistic> PositionHandler for RedisRepository<Statistic>
+impl<Statistic, Conn> PositionHandler for RedisRepository<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
         let position_string = serde_json::to_string(&position)?;
 
-        self.conn
-            .set(position.position_id, position_string)
-            .map_err(|_| RepositoryError::WriteError)
+        self.write(Cmd::set(self.key(&position.position_id), position_string))
     }
 
     fn get_open_position(
         &mut self,
         position_id: &PositionId,
     ) -> Result<Option<Position>, RepositoryError> {
-        let redis_position_value: Option<String> = self.conn.get(&position_id).ok();
-        match redis_position_value {
-            Some(value) => Ok(Some(serde_json::from_str::<Position>(&value)?)),
-            None => Ok(None),
-        }
+        self.get_json(self.key(position_id))
     }
 
     fn get_open_positions<'a, Markets: Iterator<Item = &'a Market>>(
@@ -76,15 +151,29 @@ where
             .collect()
     }
 
+    fn get_all_open_positions(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<Position>, RepositoryError> {
+        let pattern = self.key(&format!("{}_*_position", engine_id));
+
+        let position_keys = self.conn.with_connection(|conn| {
+            Ok(conn.scan_match::<_, String>(pattern)?.collect::<Vec<_>>())
+        })?;
+
+        position_keys
+            .into_iter()
+            .filter_map(|position_key| self.get_json(position_key).transpose())
+            .collect()
+    }
+
     fn remove_position(
         &mut self,
-        position_id: &String,
+        position_id: &PositionId,
     ) -> Result<Option<Position>, RepositoryError> {
         let position = self.get_open_position(position_id)?;
 
-        self.conn
-            .del(position_id)
-            .map_err(|_| RepositoryError::DeleteError)?;
+        self.write(Cmd::del(self.key(position_id)))?;
 
         Ok(position)
     }
@@ -94,100 +183,205 @@ where
         engine_id: Uuid,
         position: Position,
     ) -> Result<(), RepositoryError> {
-        self.conn
-            .lpush(
-                determine_exited_positions_id(engine_id),
-                serde_json::to_string(&position)?,
-            )
-            .map_err(|_| RepositoryError::WriteError)
+        let exited_positions_key = self.key(&determine_exited_positions_id(engine_id));
+
+        // Exited Positions are not retained, so remove any retained under a previous policy
+        if self.exited_positions_max_len == Some(0) {
+            return self.write(Cmd::del(&exited_positions_key));
+        }
+
+        self.write(Cmd::lpush(
+            &exited_positions_key,
+            serde_json::to_string(&position)?,
+        ))?;
+
+        // Apply exited Positions retention policy
+        if let Some(max_len) = self.exited_positions_max_len {
+            self.write(Cmd::ltrim(&exited_positions_key, 0, max_len as isize - 1))?;
+        }
+        if let Some(ttl_secs) = self.exited_positions_ttl_secs {
+            self.write(Cmd::expire(&exited_positions_key, ttl_secs))?;
+        }
+
+        Ok(())
     }
 
     fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError> {
-        self.conn
-            .get(determine_exited_positions_id(engine_id))
-            .or_else(|err| match err.kind() {
-                ErrorKind::TypeError => Ok(Vec::<String>::new()),
-                _ => Err(RepositoryError::ReadError),
-            })?
+        let exited_positions_key = self.key(&determine_exited_positions_id(engine_id));
+
+        // Exited Positions are pushed to the head of the list, so reverse for oldest first
+        self.read::<Vec<String>>(Cmd::lrange(exited_positions_key, 0, -1))?
             .iter()
+            .rev()
             .map(|position| serde_json::from_str::<Position>(position))
             .collect::<Result<Vec<Position>, serde_json::Error>>()
             .map_err(RepositoryError::JsonSerDeError)
     }
 }
 
-impl<Statistic> BalanceHandler for RedisRepository<Statistic>
+impl<Statistic, Conn> BalanceHandler for RedisRepository<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
         let balance_string = serde_json::to_string(&balance)?;
 
-        self.conn
-            .set(Balance::balance_id(engine_id), balance_string)
-            .map_err(|_| RepositoryError::WriteError)
+        self.write(Cmd::set(
+            self.key(&Balance::balance_id(engine_id)),
+            balance_string,
+        ))
     }
 
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
-        let balance_value: String = self
-            .conn
-            .get(Balance::balance_id(engine_id))
-            .map_err(|_| RepositoryError::ReadError)?;
-
-        Ok(serde_json::from_str::<Balance>(&balance_value)?)
+        self.get_json(self.key(&Balance::balance_id(engine_id)))?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
 }
 
-impl<Statistic> StatisticHandler<Statistic> for RedisRepository<Statistic>
+impl<Statistic, Conn> StatisticHandler<Statistic> for RedisRepository<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     fn set_statistics(
         &mut self,
         market_id: MarketId,
         statistic: Statistic,
     ) -> Result<(), RepositoryError> {
-        self.conn
-            .set(market_id.0, serde_json::to_string(&statistic)?)
-            .map_err(|_| RepositoryError::WriteError)
+        self.write(Cmd::set(
+            self.key(&market_id.0),
+            serde_json::to_string(&statistic)?,
+        ))
     }
 
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
-        let statistics: String = self
-            .conn
-            .get(&market_id.0)
-            .map_err(|_| RepositoryError::ReadError)?;
+        self.get_json(self.key(&market_id.0))?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)
+    }
+}
+
+impl<Statistic, Conn> TransactionHandler for RedisRepository<Statistic, Conn>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
+{
+    fn begin(&mut self) -> Result<(), RepositoryError> {
+        if self.pipeline.is_some() {
+            return Err(RepositoryError::UnitOfWorkError(
+                "unit of work already in progress",
+            ));
+        }
+
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+        self.pipeline = Some(pipeline);
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), RepositoryError> {
+        let pipeline = self
+            .pipeline
+            .take()
+            .ok_or(RepositoryError::UnitOfWorkError(
+                "no unit of work in progress",
+            ))?;
+
+        self.conn
+            .with_connection(|conn| pipeline.query::<()>(conn))
+            .map_err(RepositoryError::from)
+    }
 
-        serde_json::from_str(&statistics).map_err(RepositoryError::JsonSerDeError)
+    fn rollback(&mut self) -> Result<(), RepositoryError> {
+        self.pipeline
+            .take()
+            .map(|_| ())
+            .ok_or(RepositoryError::UnitOfWorkError(
+                "no unit of work in progress",
+            ))
     }
 }
 
-impl<Statistic: PositionSummariser> Debug for RedisRepository<Statistic>
+impl<Statistic, Conn> Debug for RedisRepository<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("RedisRepository").finish()
+        f.debug_struct("RedisRepository")
+            .field("key_prefix", &self.key_prefix)
+            .field("exited_positions_max_len", &self.exited_positions_max_len)
+            .field("exited_positions_ttl_secs", &self.exited_positions_ttl_secs)
+            .finish()
     }
 }
 
-impl<Statistic: PositionSummariser> RedisRepository<Statistic>
+impl<Statistic, Conn> RedisRepository<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
-    /// Constructs a new [`RedisRepository`] component using the provided Redis connection struct.
-    pub fn new(connection: Connection) -> Self {
+    /// Constructs a new [`RedisRepository`] component using the provided Redis connection
+    /// source. Keys are not namespaced & exited [`Position`]s are retained indefinitely.
+    pub fn new(connection: Conn) -> Self {
         Self {
             conn: connection,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            key_prefix: None,
+            exited_positions_max_len: None,
+            exited_positions_ttl_secs: None,
+            pipeline: None,
+            _statistic_marker: PhantomData,
         }
     }
 
     /// Returns a [`RedisRepositoryBuilder`] instance.
-    pub fn builder() -> RedisRepositoryBuilder<Statistic> {
+    pub fn builder() -> RedisRepositoryBuilder<Statistic, Conn> {
         RedisRepositoryBuilder::new()
     }
 
+    /// Returns the Redis key for the provided identifier, namespaced by the key prefix.
+    fn key(&self, id: &str) -> String {
+        match &self.key_prefix {
+            Some(prefix) => format!("{}:{}", prefix, id),
+            None => id.to_owned(),
+        }
+    }
+
+    /// Execute the read [`Cmd`] immediately, bypassing any unit of work pipeline.
+    fn read<T: FromRedisValue>(&mut self, command: Cmd) -> Result<T, RepositoryError> {
+        self.conn
+            .with_connection(|conn| command.query(conn))
+            .map_err(RepositoryError::from)
+    }
+
+    /// Read & deserialise the JSON value at the provided key, if it is present.
+    fn get_json<T: DeserializeOwned>(&mut self, key: String) -> Result<Option<T>, RepositoryError> {
+        self.read::<Option<String>>(Cmd::get(key))?
+            .map(|value| serde_json::from_str::<T>(&value))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+
+    /// Queue the write [`Cmd`] in the unit of work pipeline if one is in progress, otherwise
+    /// execute it immediately.
+    fn write(&mut self, command: Cmd) -> Result<(), RepositoryError> {
+        match &mut self.pipeline {
+            Some(pipeline) => {
+                pipeline.add_command(command).ignore();
+                Ok(())
+            }
+            None => self
+                .conn
+                .with_connection(|conn| command.query(conn))
+                .map_err(RepositoryError::from),
+        }
+    }
+}
+
+impl<Statistic> RedisRepository<Statistic, Connection>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
     /// Establish & return a Redis connection.
     pub fn setup_redis_connection(cfg: Config) -> Connection {
         redis::Client::open(cfg.uri)
@@ -197,50 +391,367 @@ where
     }
 }
 
+impl<Statistic> RedisRepository<Statistic, RedisPool>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    /// Establish & return a [`RedisPool`]. Cloning the [`RedisPool`] shares the underlying
+    /// connections, so many [`RedisRepository`]s can be served by the same pool.
+    pub fn setup_redis_pool(cfg: Config) -> RedisPool {
+        let client = redis::Client::open(cfg.uri).expect("Failed to create Redis client");
+
+        let mut pool = r2d2::Pool::builder();
+        if let Some(max_size) = cfg.pool_max_size {
+            pool = pool.max_size(max_size);
+        }
+
+        pool.build(client)
+            .expect("Failed to create Redis connection pool")
+    }
+}
+
 /// Builder to construct [`RedisRepository`] instances.
 #[derive(Default)]
-pub struct RedisRepositoryBuilder<Statistic>
+pub struct RedisRepositoryBuilder<Statistic, Conn = Connection>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
-    conn: Option<Connection>,
+    conn: Option<Conn>,
+    key_prefix: Option<String>,
+    exited_positions_max_len: Option<usize>,
+    exited_positions_ttl_secs: Option<usize>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
-impl<Statistic: PositionSummariser> RedisRepositoryBuilder<Statistic>
+impl<Statistic, Conn> RedisRepositoryBuilder<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     pub fn new() -> Self {
         Self {
             conn: None,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            key_prefix: None,
+            exited_positions_max_len: None,
+            exited_positions_ttl_secs: None,
+            _statistic_marker: PhantomData,
         }
     }
 
-    pub fn conn(self, value: Connection) -> Self {
+    pub fn conn(self, value: Conn) -> Self {
         Self {
             conn: Some(value),
             ..self
         }
     }
 
-    pub fn build(self) -> Result<RedisRepository<Statistic>, PortfolioError> {
+    pub fn key_prefix(self, value: String) -> Self {
+        Self {
+            key_prefix: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exited_positions_max_len(self, value: usize) -> Self {
+        Self {
+            exited_positions_max_len: Some(value),
+            ..self
+        }
+    }
+
+    pub fn exited_positions_ttl_secs(self, value: usize) -> Self {
+        Self {
+            exited_positions_ttl_secs: Some(value),
+            ..self
+        }
+    }
+
+    /// Applies the key prefix & exited [`Position`]s retention policy from the [`Config`].
+    pub fn config(self, cfg: &Config) -> Self {
+        Self {
+            key_prefix: cfg.key_prefix.clone(),
+            exited_positions_max_len: cfg.exited_positions_max_len,
+            exited_positions_ttl_secs: cfg.exited_positions_ttl_secs,
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<RedisRepository<Statistic, Conn>, PortfolioError> {
         Ok(RedisRepository {
             conn: self.conn.ok_or(PortfolioError::BuilderIncomplete("conn"))?,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            key_prefix: self.key_prefix,
+            exited_positions_max_len: self.exited_positions_max_len,
+            exited_positions_ttl_secs: self.exited_positions_ttl_secs,
+            pipeline: None,
+            _statistic_marker: PhantomData,
         })
     }
 }
 
-impl<Statistic> Debug for RedisRepositoryBuilder<Statistic>
+impl<Statistic, Conn> Debug for RedisRepositoryBuilder<Statistic, Conn>
 where
     Statistic: PositionSummariser + Serialize + DeserializeOwned,
+    Conn: RedisConnection,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RedisRepositoryBuilder")
-            .field("conn", &"Option<redis::Connection>")
+            .field("conn", &"Option<RedisConnection>")
+            .field("key_prefix", &self.key_prefix)
+            .field("exited_positions_max_len", &self.exited_positions_max_len)
+            .field("exited_positions_ttl_secs", &self.exited_positions_ttl_secs)
             .field("_statistic_market", &self._statistic_marker)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{statistic::summary::pnl::PnLReturnSummary, test_util::position};
+    use redis::Value;
+    use std::collections::VecDeque;
+
+    /// Mock [`RedisConnection`] that records every command it receives, & replies with the
+    /// queued responses (or `OK` once they are exhausted).
+    #[derive(Debug, Default)]
+    struct MockConnection {
+        commands: Vec<Vec<String>>,
+        responses: VecDeque<Value>,
+        error: Option<ErrorKind>,
+    }
+
+    impl MockConnection {
+        /// Decode the RESP encoded commands (arrays of bulk strings) & record them.
+        fn record(&mut self, packed: &[u8]) -> RedisResult<usize> {
+            if let Some(kind) = self.error {
+                return Err(RedisError::from((kind, "mock connection error")));
+            }
+
+            let packed = String::from_utf8_lossy(packed);
+            let mut lines = packed.split("\r\n").filter(|line| !line.is_empty());
+            let mut recorded = 0;
+
+            while let Some(header) = lines.next() {
+                let len = header.trim_start_matches('*').parse::<usize>().unwrap();
+                let command = (0..len)
+                    .map(|_| {
+                        lines.next();
+                        lines.next().unwrap().to_owned()
+                    })
+                    .collect();
+
+                self.commands.push(command);
+                recorded += 1;
+            }
+
+            Ok(recorded)
+        }
+    }
+
+    impl ConnectionLike for MockConnection {
+        fn req_packed_command(&mut self, cmd: &[u8]) -> RedisResult<Value> {
+            self.record(cmd)?;
+            Ok(self.responses.pop_front().unwrap_or(Value::Okay))
+        }
+
+        fn req_packed_commands(
+            &mut self,
+            cmd: &[u8],
+            _: usize,
+            _: usize,
+        ) -> RedisResult<Vec<Value>> {
+            // Reply to an atomic pipeline with an EXEC result for every queued command
+            let queued = self.record(cmd)?.saturating_sub(2);
+            Ok(vec![Value::Bulk(vec![Value::Okay; queued])])
+        }
+
+        fn get_db(&self) -> i64 {
+            0
+        }
+
+        fn check_connection(&mut self) -> bool {
+            true
+        }
+
+        fn is_open(&self) -> bool {
+            true
+        }
+    }
+
+    impl RedisConnection for MockConnection {
+        type Connection = Self;
+
+        fn with_connection<T, Operation>(&mut self, operation: Operation) -> RedisResult<T>
+        where
+            Operation: FnOnce(&mut Self) -> RedisResult<T>,
+        {
+            operation(self)
+        }
+    }
+
+    fn repository(
+        config: &Config,
+        conn: MockConnection,
+    ) -> RedisRepository<PnLReturnSummary, MockConnection> {
+        RedisRepository::builder()
+            .conn(conn)
+            .config(config)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn set_exited_position_applies_key_prefix_and_retention_policy() {
+        let engine_id = Uuid::new_v4();
+        let config = Config {
+            key_prefix: Some("paper".to_owned()),
+            exited_positions_max_len: Some(10),
+            exited_positions_ttl_secs: Some(3600),
+            ..Config::default()
+        };
+        let mut repository = repository(&config, MockConnection::default());
+
+        repository
+            .set_exited_position(engine_id, position())
+            .unwrap();
+
+        let key = format!("paper:{}", determine_exited_positions_id(engine_id));
+        let commands = &repository.conn.commands;
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0][..2], ["LPUSH".to_owned(), key.clone()]);
+        assert_eq!(
+            commands[1],
+            [
+                "LTRIM".to_owned(),
+                key.clone(),
+                "0".to_owned(),
+                "9".to_owned()
+            ]
+        );
+        assert_eq!(commands[2], ["EXPIRE".to_owned(), key, "3600".to_owned()]);
+    }
+
+    #[test]
+    fn set_exited_position_retains_no_positions_if_max_len_is_zero() {
+        let engine_id = Uuid::new_v4();
+        let config = Config {
+            exited_positions_max_len: Some(0),
+            exited_positions_ttl_secs: Some(3600),
+            ..Config::default()
+        };
+        let mut repository = repository(&config, MockConnection::default());
+
+        repository
+            .set_exited_position(engine_id, position())
+            .unwrap();
+
+        let key = determine_exited_positions_id(engine_id);
+        assert_eq!(repository.conn.commands, vec![["DEL".to_owned(), key]]);
+    }
+
+    #[test]
+    fn set_exited_position_retains_latest_position_if_max_len_is_one() {
+        let engine_id = Uuid::new_v4();
+        let config = Config {
+            exited_positions_max_len: Some(1),
+            ..Config::default()
+        };
+        let mut repository = repository(&config, MockConnection::default());
+
+        repository
+            .set_exited_position(engine_id, position())
+            .unwrap();
+
+        let key = determine_exited_positions_id(engine_id);
+        let commands = &repository.conn.commands;
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0][..2], ["LPUSH".to_owned(), key.clone()]);
+        assert_eq!(
+            commands[1],
+            ["LTRIM".to_owned(), key, "0".to_owned(), "0".to_owned()]
+        );
+    }
+
+    #[test]
+    fn unit_of_work_writes_are_sent_as_atomic_pipeline_on_commit() {
+        let engine_id = Uuid::new_v4();
+        let mut repository = repository(&Config::default(), MockConnection::default());
+
+        repository.begin().unwrap();
+        repository.set_open_position(position()).unwrap();
+        repository
+            .set_exited_position(engine_id, position())
+            .unwrap();
+        assert!(repository.conn.commands.is_empty());
+
+        repository.commit().unwrap();
+
+        let commands = repository
+            .conn
+            .commands
+            .iter()
+            .map(|command| command[0].as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(commands, vec!["MULTI", "SET", "LPUSH", "EXEC"]);
+        assert_eq!(repository.conn.commands[1][1], position().position_id);
+
+        // Writes queued by a unit of work that is rolled back are never sent
+        repository.begin().unwrap();
+        repository.set_open_position(position()).unwrap();
+        repository.rollback().unwrap();
+        assert_eq!(repository.conn.commands.len(), 4);
+    }
+
+    #[test]
+    fn get_open_position_reads_prefixed_key() {
+        let position = position();
+        let config = Config {
+            key_prefix: Some("live".to_owned()),
+            ..Config::default()
+        };
+        let conn = MockConnection {
+            responses: VecDeque::from([
+                Value::Data(serde_json::to_vec(&position).unwrap()),
+                Value::Nil,
+            ]),
+            ..MockConnection::default()
+        };
+        let mut repository = repository(&config, conn);
+
+        let actual = repository.get_open_position(&position.position_id).unwrap();
+        assert_eq!(actual, Some(position.clone()));
+        assert_eq!(
+            repository.conn.commands[0],
+            ["GET".to_owned(), format!("live:{}", position.position_id)]
+        );
+
+        let actual = repository.get_open_position(&position.position_id).unwrap();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn redis_errors_are_mapped_to_repository_redis_error() {
+        let conn = MockConnection {
+            error: Some(ErrorKind::IoError),
+            ..MockConnection::default()
+        };
+        let mut repository = repository(&Config::default(), conn);
+
+        assert!(matches!(
+            repository.get_balance(Uuid::new_v4()),
+            Err(RepositoryError::RedisError(_))
+        ));
+        assert!(matches!(
+            repository.set_open_position(position()),
+            Err(RepositoryError::RedisError(_))
+        ));
+
+        repository.begin().unwrap();
+        repository.set_open_position(position()).unwrap();
+        assert!(matches!(
+            repository.commit(),
+            Err(RepositoryError::RedisError(_))
+        ));
+    }
+}
@@ -1,4 +1,6 @@
+use barter_integration::model::instrument::spec::InstrumentRegistry;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use crate::portfolio::{OrderEvent, OrderType};
 
@@ -34,3 +36,136 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Risk manager that implements [`OrderEvaluator`] by rounding each [`OrderEvent`] quantity down
+/// to the lot size of it's [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec),
+/// and refusing any order that breaks the exchange trading rules (eg/ min notional, max quantity).
+///
+/// Orders for markets without a registered `InstrumentSpec` are refused. Exit orders are passed
+/// through unchanged, since rounding them down would leave a residual
+/// [`Position`](crate::portfolio::position::Position) open.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct InstrumentRisk {
+    pub instruments: InstrumentRegistry,
+}
+
+impl OrderEvaluator for InstrumentRisk {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
+        // Exits close the full Position, which was entered with a valid quantity
+        if order.decision.is_exit() {
+            order.order_type = InstrumentRisk::DEFAULT_ORDER_TYPE;
+            return Some(order);
+        }
+
+        let spec = match self.instruments.find(&order.exchange, &order.instrument) {
+            Ok(spec) => spec,
+            Err(error) => {
+                warn!(?error, "refused OrderEvent without an InstrumentSpec");
+                return None;
+            }
+        };
+
+        // Market orders are valued at the last close, rounded to a valid tick
+        let price = order.market_meta.close;
+
+        match spec.normalise_order(price, order.quantity) {
+            Ok((_, quantity)) => {
+                order.quantity = quantity;
+                order.order_type = InstrumentRisk::DEFAULT_ORDER_TYPE;
+                Some(order)
+            }
+            Err(error) => {
+                warn!(
+                    market = %spec.market.instrument,
+                    ?error,
+                    "refused OrderEvent that breaks InstrumentSpec trading rules"
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{strategy::Decision, test_util::order_event};
+    use barter_integration::model::{
+        instrument::{
+            kind::InstrumentKind,
+            spec::{InstrumentSpec, InstrumentStatus, Settlement},
+        },
+        Market,
+    };
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn instrument_risk() -> InstrumentRisk {
+        InstrumentRisk {
+            instruments: InstrumentRegistry::from(vec![InstrumentSpec {
+                market: Market::from(("binance", "eth", "usdt", InstrumentKind::Spot)),
+                tick_size: dec!(0.01),
+                lot_size: dec!(0.001),
+                min_quantity: dec!(0.001),
+                max_quantity: Some(dec!(1000)),
+                min_notional: Some(dec!(10)),
+                contract_multiplier: Decimal::ONE,
+                settlement: Settlement::Linear,
+                status: InstrumentStatus::Trading,
+            }]),
+        }
+    }
+
+    #[test]
+    fn should_round_order_quantity_down_to_lot_size() {
+        let mut order = order_event();
+        order.quantity = dec!(-1.23456);
+
+        let actual = instrument_risk().evaluate_order(order).unwrap();
+
+        assert_eq!(actual.quantity, dec!(-1.234));
+        assert_eq!(actual.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn should_refuse_orders_that_break_instrument_spec_trading_rules() {
+        let risk = instrument_risk();
+
+        // Notional of 0.05 * 100.0 is below the min notional of 10
+        let mut below_min_notional = order_event();
+        below_min_notional.quantity = dec!(0.05);
+        assert!(risk.evaluate_order(below_min_notional).is_none());
+
+        // Rounds down to zero
+        let mut below_lot_size = order_event();
+        below_lot_size.quantity = dec!(0.0009);
+        assert!(risk.evaluate_order(below_lot_size).is_none());
+
+        // No InstrumentSpec registered
+        let mut unknown_market = order_event();
+        unknown_market.instrument.base = "btc".into();
+        assert!(risk.evaluate_order(unknown_market).is_none());
+    }
+
+    #[test]
+    fn should_pass_exit_orders_through_to_close_the_full_position() {
+        let risk = instrument_risk();
+
+        // Below the lot size & min notional, but closes the full Position
+        let mut close_long = order_event();
+        close_long.decision = Decision::CloseLong;
+        close_long.quantity = dec!(-0.0009);
+        let actual = risk.evaluate_order(close_long).unwrap();
+        assert_eq!(actual.quantity, dec!(-0.0009));
+        assert_eq!(actual.order_type, OrderType::Market);
+
+        // Not a multiple of the lot size
+        let mut close_short = order_event();
+        close_short.decision = Decision::CloseShort;
+        close_short.quantity = dec!(1.23456);
+        let actual = risk.evaluate_order(close_short).unwrap();
+        assert_eq!(actual.quantity, dec!(1.23456));
+    }
+}
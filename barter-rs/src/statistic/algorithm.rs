@@ -38,6 +38,83 @@ pub mod welford_online {
     }
 }
 
+/// Approximations of the standard normal distribution, shared by the statistical significance
+/// tests & the option pricing models.
+pub mod normal_distribution {
+    /// Standard normal cumulative distribution function, using the Abramowitz & Stegun 7.1.26
+    /// approximation of the error function (max error 1.5e-7).
+    pub fn normal_cdf(x: f64) -> f64 {
+        let z = x.abs() / std::f64::consts::SQRT_2;
+        let t = 1.0 / (1.0 + 0.327_591_1 * z);
+        let polynomial = t
+            * (0.254_829_592
+                + t * (-0.284_496_736
+                    + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+        let erf = 1.0 - polynomial * (-z * z).exp();
+
+        if x >= 0.0 {
+            0.5 * (1.0 + erf)
+        } else {
+            0.5 * (1.0 - erf)
+        }
+    }
+
+    /// Inverse of the standard normal cumulative distribution function, using Acklam's rational
+    /// approximation (max relative error 1.15e-9).
+    pub fn inverse_normal_cdf(p: f64) -> f64 {
+        const A: [f64; 6] = [
+            -3.969_683_028_665_376e1,
+            2.209_460_984_245_205e2,
+            -2.759_285_104_469_687e2,
+            1.383_577_518_672_69e2,
+            -3.066_479_806_614_716e1,
+            2.506_628_277_459_239,
+        ];
+        const B: [f64; 5] = [
+            -5.447_609_879_822_406e1,
+            1.615_858_368_580_409e2,
+            -1.556_989_798_598_866e2,
+            6.680_131_188_771_972e1,
+            -1.328_068_155_288_572e1,
+        ];
+        const C: [f64; 6] = [
+            -7.784_894_002_430_293e-3,
+            -3.223_964_580_411_365e-1,
+            -2.400_758_277_161_838,
+            -2.549_732_539_343_734,
+            4.374_664_141_464_968,
+            2.938_163_982_698_783,
+        ];
+        const D: [f64; 4] = [
+            7.784_695_709_041_462e-3,
+            3.224_671_290_700_398e-1,
+            2.445_134_137_142_996,
+            3.754_408_661_907_416,
+        ];
+        const P_LOW: f64 = 0.024_25;
+
+        let tail = |q: f64| {
+            (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+                / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+        };
+
+        if p <= 0.0 {
+            f64::NEG_INFINITY
+        } else if p >= 1.0 {
+            f64::INFINITY
+        } else if p < P_LOW {
+            tail((-2.0 * p.ln()).sqrt())
+        } else if p > 1.0 - P_LOW {
+            -tail((-2.0 * (1.0 - p).ln()).sqrt())
+        } else {
+            let q = p - 0.5;
+            let r = q * q;
+            (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+                / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +311,12 @@ mod tests {
             assert_eq!(actual_variance, expected);
         }
     }
+
+    #[test]
+    fn normal_distribution_functions() {
+        assert!((normal_distribution::normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_distribution::normal_cdf(1.96) - 0.975).abs() < 1e-4);
+        assert!((normal_distribution::inverse_normal_cdf(0.975) - 1.959_964).abs() < 1e-5);
+        assert!((normal_distribution::inverse_normal_cdf(0.01) - -2.326_348).abs() < 1e-5);
+    }
 }
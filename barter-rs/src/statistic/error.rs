@@ -8,4 +8,23 @@ pub enum StatisticError {
 
     #[error("Failed to build struct due to insufficient metrics provided")]
     BuilderNoMetricsProvided,
+
+    #[error("Insufficient data: requires at least {required} values, but found {actual}")]
+    InsufficientData { required: usize, actual: usize },
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(&'static str),
+}
+
+/// All errors generated when writing a [`SessionReport`](super::report::SessionReport).
+#[derive(Error, Debug)]
+pub enum ReportError {
+    #[error("failed to write report file {path}: {source}")]
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("failed to serialise report: {0}")]
+    Serialise(#[from] serde_json::Error),
 }
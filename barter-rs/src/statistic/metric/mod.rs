@@ -1,6 +1,6 @@
 use crate::{
     portfolio::{position::Position, Balance},
-    statistic::summary::PositionSummariser,
+    statistic::{decimal_to_f64, summary::PositionSummariser},
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -28,7 +28,7 @@ impl From<Balance> for EquityPoint {
     fn from(balance: Balance) -> Self {
         Self {
             time: balance.time,
-            total: balance.total,
+            total: decimal_to_f64(balance.total),
         }
     }
 }
@@ -40,11 +40,11 @@ impl PositionSummariser for EquityPoint {
             None => {
                 // Position is not exited, so simulate
                 self.time = position.meta.update_time;
-                self.total += position.unrealised_profit_loss;
+                self.total += decimal_to_f64(position.unrealised_profit_loss);
             }
             Some(exit_balance) => {
                 self.time = exit_balance.time;
-                self.total += position.realised_profit_loss;
+                self.total += decimal_to_f64(position.realised_profit_loss);
             }
         }
     }
@@ -55,16 +55,21 @@ mod tests {
     use super::*;
     use crate::test_util::position;
     use chrono::Duration;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
     use std::ops::Add;
 
     #[test]
     fn equity_point_update() {
-        fn equity_update_position_closed(exit_time: DateTime<Utc>, result_pnl: f64) -> Position {
+        fn equity_update_position_closed(
+            exit_time: DateTime<Utc>,
+            result_pnl: Decimal,
+        ) -> Position {
             let mut position = position();
             position.meta.exit_balance = Some(Balance {
                 time: exit_time,
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100.0),
+                available: dec!(100.0),
             });
             position.realised_profit_loss = result_pnl;
             position
@@ -72,7 +77,7 @@ mod tests {
 
         fn equity_update_position_open(
             last_update_time: DateTime<Utc>,
-            unreal_pnl: f64,
+            unreal_pnl: Decimal,
         ) -> Position {
             let mut position = position();
             position.meta.exit_balance = None;
@@ -96,32 +101,44 @@ mod tests {
 
         let test_cases = vec![
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(1)), 10.0),
+                position: equity_update_position_closed(
+                    base_time.add(Duration::days(1)),
+                    dec!(10.0),
+                ),
                 expected_equity: 110.0,
                 expected_time: base_time.add(Duration::days(1)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(2)), -10.0),
+                position: equity_update_position_open(
+                    base_time.add(Duration::days(2)),
+                    dec!(-10.0),
+                ),
                 expected_equity: 100.0,
                 expected_time: base_time.add(Duration::days(2)),
             },
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(3)), -55.9),
+                position: equity_update_position_closed(
+                    base_time.add(Duration::days(3)),
+                    dec!(-55.9),
+                ),
                 expected_equity: 44.1,
                 expected_time: base_time.add(Duration::days(3)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(4)), 68.7),
+                position: equity_update_position_open(base_time.add(Duration::days(4)), dec!(68.7)),
                 expected_equity: 112.8,
                 expected_time: base_time.add(Duration::days(4)),
             },
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(5)), 99999.0),
+                position: equity_update_position_closed(
+                    base_time.add(Duration::days(5)),
+                    dec!(99999.0),
+                ),
                 expected_equity: 100111.8,
                 expected_time: base_time.add(Duration::days(5)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(5)), 0.2),
+                position: equity_update_position_open(base_time.add(Duration::days(5)), dec!(0.2)),
                 expected_equity: 100112.0,
                 expected_time: base_time.add(Duration::days(5)),
             },
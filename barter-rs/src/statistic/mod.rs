@@ -1,12 +1,29 @@
 use chrono::Duration;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Deserializer, Serializer};
 
 pub mod algorithm;
 pub mod dispersion;
 pub mod error;
 pub mod metric;
+pub mod report;
+pub mod significance;
 pub mod summary;
 
+/// Convert a [`Decimal`] money or quantity value into an `f64` for use in statistical calculations.
+pub fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// Divide the numerator by the denominator, returning zero if the denominator is zero.
+pub fn divide(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
 /// Serialize a [`Duration`] into a `u64` representing the associated seconds.
 pub fn se_duration_as_secs<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
 where
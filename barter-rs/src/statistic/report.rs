@@ -0,0 +1,509 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        decimal_to_f64,
+        error::ReportError,
+        metric::{drawdown::Drawdown, EquityPoint},
+        summary::{
+            attribution::AttributionSummary,
+            benchmark::{BenchmarkStatistics, Benchmarked},
+            combine,
+            equity::EquityCurve,
+            TableBuilder,
+        },
+    },
+};
+use barter_integration::model::MarketId;
+use prettytable::Table;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+use uuid::Uuid;
+
+/// File names of a [`SessionReport`] written by [`SessionReport::write`].
+pub const REPORT_JSON: &str = "report.json";
+pub const POSITIONS_CSV: &str = "positions.csv";
+pub const EQUITY_CSV: &str = "equity.csv";
+pub const DRAWDOWNS_CSV: &str = "drawdowns.csv";
+pub const REPORT_HTML: &str = "report.html";
+
+/// Machine-readable report of a trading session, returned by the
+/// [`Engine`](crate::engine::Engine) once it has stopped.
+///
+/// Can be serialised to JSON, or written as JSON, CSV & a self-contained HTML report with charts
+/// via [`SessionReport::write`], eg/ for CI or notebooks to consume.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SessionReport<Statistic> {
+    pub engine_id: Uuid,
+    /// Statistics of each [`Market`](barter_integration::model::Market), keyed by [`MarketId`].
+    pub markets: BTreeMap<String, Statistic>,
+    /// Statistics across every market, generated using the exited [`Position`]s.
+    pub total: Statistic,
+    /// Comparison of the trading session to it's [`Benchmark`], if it had one.
+    ///
+    /// [`Benchmark`]: crate::statistic::summary::benchmark::Benchmark
+    pub benchmark: Option<BenchmarkStatistics>,
+    pub exited_positions: Vec<Position>,
+    /// Mark-to-market equity curve if the trading session recorded an [`EquityCurve`]. Otherwise
+    /// the realised equity curve, with a point for the Portfolio equity before the first exited
+    /// [`Position`] & after every exited [`Position`].
+    pub equity_curve: Vec<EquityPoint>,
+    /// Every drawdown period of the equity curve, including any that is ongoing.
+    pub drawdowns: Vec<Drawdown>,
+    /// Realised PnL attributed to each [`Ensemble`](crate::strategy::ensemble::Ensemble) member,
+    /// if the trading session was configured with one.
+    #[serde(default)]
+    pub attribution: Option<AttributionSummary>,
+}
+
+impl<Statistic> SessionReport<Statistic>
+where
+    Statistic: TableBuilder + Serialize + Clone,
+{
+    /// Constructs a new [`SessionReport`], generating the drawdown periods from the points of
+    /// the mark-to-market [`EquityCurve`] if one is provided, or otherwise from the realised
+    /// equity curve of the exited [`Position`]s.
+    pub fn new(
+        engine_id: Uuid,
+        markets: BTreeMap<String, Statistic>,
+        total: Statistic,
+        benchmark: Option<BenchmarkStatistics>,
+        mut exited_positions: Vec<Position>,
+        equity: Option<&EquityCurve>,
+    ) -> Self {
+        exited_positions.sort_by_key(|position| position.meta.update_time);
+        let equity_curve = match equity {
+            Some(equity) => equity.points().to_vec(),
+            None => equity_curve(&exited_positions),
+        };
+        let drawdowns = drawdowns(&equity_curve);
+
+        Self {
+            engine_id,
+            markets,
+            total,
+            benchmark,
+            exited_positions,
+            equity_curve,
+            drawdowns,
+            attribution: None,
+        }
+    }
+
+    /// Trading session summary [`Table`] of the per-market & total statistics, including the
+    /// [`BenchmarkStatistics`] if there are any.
+    pub fn table(&self) -> Table {
+        let markets = self
+            .markets
+            .iter()
+            .map(|(market_id, statistic)| (market_id.clone(), statistic.clone()));
+
+        match self.benchmark {
+            None => combine(markets.chain([("Total".to_owned(), self.total.clone())])),
+            Some(benchmark) => combine(
+                markets
+                    .map(|(market_id, summary)| {
+                        let benchmarked = Benchmarked {
+                            summary,
+                            benchmark: None,
+                        };
+                        (market_id, benchmarked)
+                    })
+                    .chain([(
+                        "Total".to_owned(),
+                        Benchmarked {
+                            summary: self.total.clone(),
+                            benchmark: Some(benchmark),
+                        },
+                    )]),
+            ),
+        }
+    }
+
+    /// Serialise the [`SessionReport`] as pretty JSON.
+    pub fn to_json(&self) -> Result<String, ReportError> {
+        serde_json::to_string_pretty(self).map_err(ReportError::from)
+    }
+
+    /// CSV of the exited [`Position`]s, one row per [`Position`].
+    pub fn positions_csv(&self) -> String {
+        let mut csv = String::from(
+            "market,side,quantity,enter_time,exit_time,enter_avg_price_gross,\
+             exit_avg_price_gross,fees,realised_profit_loss,profit_loss_return\n",
+        );
+
+        for position in &self.exited_positions {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{}",
+                MarketId::new(&position.exchange, &position.instrument),
+                position.side,
+                position.quantity,
+                position.meta.enter_time.to_rfc3339(),
+                position.meta.update_time.to_rfc3339(),
+                position.enter_avg_price_gross,
+                position.exit_avg_price_gross,
+                position.enter_fees_total + position.exit_fees_total,
+                position.realised_profit_loss,
+                position.calculate_profit_loss_return(),
+            );
+        }
+
+        csv
+    }
+
+    /// CSV of the equity curve.
+    pub fn equity_csv(&self) -> String {
+        let mut csv = String::from("time,equity\n");
+        for point in &self.equity_curve {
+            let _ = writeln!(csv, "{},{}", point.time.to_rfc3339(), point.total);
+        }
+        csv
+    }
+
+    /// CSV of the drawdown periods.
+    pub fn drawdowns_csv(&self) -> String {
+        let mut csv = String::from("start_time,duration_secs,peak,trough,drawdown\n");
+        for drawdown in &self.drawdowns {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                drawdown.start_time.to_rfc3339(),
+                drawdown.duration.num_seconds(),
+                drawdown.equity_range.high,
+                drawdown.equity_range.low,
+                drawdown.drawdown,
+            );
+        }
+        csv
+    }
+
+    /// Self-contained HTML report with the session summary, equity & drawdown charts, & tables
+    /// of the drawdown periods & exited [`Position`]s. Charts are inline SVG, so no external
+    /// assets are required.
+    pub fn to_html(&self) -> String {
+        let mut summary = Vec::new();
+        let _ = self.table().print_html(&mut summary);
+
+        let underwater = underwater(&self.equity_curve);
+
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Trading Session {engine_id}</title>\n<style>\n\
+             body {{ font-family: sans-serif; margin: 2em; }}\n\
+             table {{ border-collapse: collapse; margin-bottom: 2em; font-size: 0.85em; }}\n\
+             th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: right; }}\n\
+             svg {{ background: #fafafa; border: 1px solid #ccc; margin-bottom: 2em; }}\n\
+             </style>\n</head>\n<body>\n<h1>Trading Session {engine_id}</h1>\n\
+             <h2>Summary</h2>\n{summary}\n\
+             <h2>Equity</h2>\n{equity_chart}\n\
+             <h2>Drawdown</h2>\n{drawdown_chart}\n",
+            engine_id = self.engine_id,
+            summary = String::from_utf8_lossy(&summary),
+            equity_chart = svg_chart(&self.equity_curve, "#2a6fdb"),
+            drawdown_chart = svg_chart(&underwater, "#d9534f"),
+        );
+
+        if let Some(attribution) = &self.attribution {
+            let mut table = Vec::new();
+            let _ = attribution.table().print_html(&mut table);
+            let _ = write!(
+                html,
+                "<h2>Strategy Attribution</h2>\n{}\n",
+                String::from_utf8_lossy(&table)
+            );
+        }
+
+        html_table(&mut html, "Drawdown Periods", &self.drawdowns_csv());
+        html_table(&mut html, "Exited Positions", &self.positions_csv());
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Write the [`SessionReport`] to the provided directory (which is created if required) as
+    /// [`REPORT_JSON`], [`POSITIONS_CSV`], [`EQUITY_CSV`], [`DRAWDOWNS_CSV`] & [`REPORT_HTML`].
+    pub fn write(&self, directory: &Path) -> Result<(), ReportError> {
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| ReportError::Io { path, source }
+        };
+
+        std::fs::create_dir_all(directory).map_err(io_error(directory))?;
+
+        for (file, contents) in [
+            (REPORT_JSON, self.to_json()?),
+            (POSITIONS_CSV, self.positions_csv()),
+            (EQUITY_CSV, self.equity_csv()),
+            (DRAWDOWNS_CSV, self.drawdowns_csv()),
+            (REPORT_HTML, self.to_html()),
+        ] {
+            let path = directory.join(file);
+            std::fs::write(&path, contents).map_err(io_error(&path))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Realised equity curve of the provided exited [`Position`]s, sorted by exit time. The first
+/// point is the equity before the first [`Position`] exited, at the time it was entered.
+fn equity_curve(exited_positions: &[Position]) -> Vec<EquityPoint> {
+    let mut curve = Vec::with_capacity(exited_positions.len() + 1);
+
+    for position in exited_positions {
+        let exit_balance = match position.meta.exit_balance {
+            Some(exit_balance) => exit_balance,
+            None => continue,
+        };
+
+        if curve.is_empty() {
+            curve.push(EquityPoint {
+                time: position.meta.enter_time,
+                total: decimal_to_f64(exit_balance.total - position.realised_profit_loss),
+            });
+        }
+
+        curve.push(EquityPoint {
+            time: exit_balance.time,
+            total: decimal_to_f64(exit_balance.total),
+        });
+    }
+
+    curve
+}
+
+/// Every drawdown period of the equity curve, including any that is ongoing.
+fn drawdowns(equity_curve: &[EquityPoint]) -> Vec<Drawdown> {
+    let (first, rest) = match equity_curve.split_first() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+
+    let mut current = Drawdown::init(first.total);
+    let mut drawdowns = rest
+        .iter()
+        .filter_map(|point| current.update(*point))
+        .collect::<Vec<_>>();
+
+    if !current.is_waiting_for_peak() {
+        drawdowns.push(current);
+    }
+
+    drawdowns
+}
+
+/// Drawdown from the running equity peak at every point of the equity curve.
+fn underwater(equity_curve: &[EquityPoint]) -> Vec<EquityPoint> {
+    let mut peak = f64::MIN;
+    equity_curve
+        .iter()
+        .map(|point| {
+            peak = peak.max(point.total);
+            EquityPoint {
+                time: point.time,
+                total: if peak == 0.0 {
+                    0.0
+                } else {
+                    (point.total - peak) / peak
+                },
+            }
+        })
+        .collect()
+}
+
+/// Line chart of the provided points as an inline SVG, scaled to fit the chart.
+fn svg_chart(points: &[EquityPoint], colour: &str) -> String {
+    const WIDTH: f64 = 800.0;
+    const HEIGHT: f64 = 240.0;
+
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return "<p>No data</p>".to_owned(),
+    };
+
+    let (low, high) = points
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), point| {
+            (low.min(point.total), high.max(point.total))
+        });
+    let duration = (last.time - first.time).num_seconds().max(1) as f64;
+    let range = if high > low { high - low } else { 1.0 };
+
+    let mut polyline = String::new();
+    for point in points {
+        let x = (point.time - first.time).num_seconds() as f64 / duration * WIDTH;
+        let y = HEIGHT - (point.total - low) / range * HEIGHT;
+        let _ = write!(polyline, "{x:.1},{y:.1} ");
+    }
+
+    format!(
+        "<svg width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\" \
+         xmlns=\"http://www.w3.org/2000/svg\">\n\
+         <polyline fill=\"none\" stroke=\"{colour}\" stroke-width=\"1.5\" \
+         points=\"{polyline}\"/>\n\
+         <text x=\"4\" y=\"14\" font-size=\"12\">{high:.4}</text>\n\
+         <text x=\"4\" y=\"{bottom}\" font-size=\"12\">{low:.4}</text>\n</svg>",
+        polyline = polyline.trim_end(),
+        bottom = HEIGHT - 4.0,
+    )
+}
+
+/// Append a titled HTML table rendering of the provided CSV, whose fields contain no commas.
+/// Fields are HTML-escaped, since they include instrument & market names.
+fn html_table(html: &mut String, title: &str, csv: &str) {
+    let _ = writeln!(html, "<h2>{title}</h2>\n<table>");
+    for (index, line) in csv.lines().enumerate() {
+        let cell = if index == 0 { "th" } else { "td" };
+        html.push_str("<tr>");
+        for field in line.split(',') {
+            let _ = write!(html, "<{cell}>{}</{cell}>", escape_html(field));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+/// Escape the characters of the provided text that are significant in HTML.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Event,
+        portfolio::Balance,
+        statistic::summary::{
+            equity::{self, Sampling},
+            trading::{Config, TradingSummary},
+            Initialiser, PositionSummariser,
+        },
+        test_util::position,
+    };
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+    use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn exited(exit_minute: i64, realised_profit_loss: Decimal, total: Decimal) -> Position {
+        let base_time = Utc::now();
+        let mut position = position();
+        position.meta.enter_time = base_time + Duration::minutes(exit_minute - 1);
+        position.meta.update_time = base_time + Duration::minutes(exit_minute);
+        position.meta.exit_balance = Some(Balance::new(position.meta.update_time, total, total));
+        position.realised_profit_loss = realised_profit_loss;
+        position
+    }
+
+    #[test]
+    fn session_report_generates_equity_curve_drawdowns_and_exports() {
+        let positions = vec![
+            exited(3, dec!(-30.0), dec!(90.0)),
+            exited(1, dec!(20.0), dec!(120.0)),
+            exited(4, dec!(40.0), dec!(130.0)),
+            exited(5, dec!(-13.0), dec!(117.0)),
+        ];
+
+        let mut total = TradingSummary::init(Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+        total.generate_summary(&positions);
+
+        let report = SessionReport::new(
+            Uuid::new_v4(),
+            BTreeMap::from([("binance_eth_usdt_spot".to_owned(), total.clone())]),
+            total,
+            None,
+            positions,
+            None,
+        );
+
+        let equity = report
+            .equity_curve
+            .iter()
+            .map(|point| point.total)
+            .collect::<Vec<_>>();
+        assert_eq!(equity, vec![100.0, 120.0, 90.0, 130.0, 117.0]);
+
+        // Finished 120 -> 90 drawdown & ongoing 130 -> 117 drawdown
+        assert_eq!(report.drawdowns.len(), 2);
+        assert_eq!(report.drawdowns[0].drawdown, -0.25);
+        assert!((report.drawdowns[1].drawdown - -0.1).abs() < 1e-9);
+
+        assert_eq!(report.positions_csv().lines().count(), 5);
+        assert_eq!(report.equity_csv().lines().count(), 6);
+        assert_eq!(report.drawdowns_csv().lines().count(), 3);
+        assert!(report.to_html().contains("<polyline"));
+        assert!(!report.to_html().contains("Strategy Attribution"));
+
+        let report = SessionReport {
+            attribution: Some(AttributionSummary::new(vec!["trend".to_owned()])),
+            ..report
+        };
+        assert!(report.to_html().contains("Strategy Attribution"));
+
+        let json = report.to_json().unwrap();
+        let deserialised: SessionReport<TradingSummary> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialised.equity_curve, report.equity_curve);
+        assert_eq!(report.table().len(), 2);
+    }
+
+    #[test]
+    fn session_report_uses_mark_to_market_equity_curve_and_escapes_html() {
+        let mut position = exited(1, dec!(20.0), dec!(120.0));
+        position.instrument = Instrument::from(("<b>", "usdt", InstrumentKind::Spot));
+        let exit_time = position.meta.update_time;
+
+        let mut equity = EquityCurve::new(equity::Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            sampling: Sampling::EveryUpdate,
+        });
+        for (time, total) in [
+            (exit_time - Duration::minutes(1), dec!(100.0)),
+            (exit_time - Duration::seconds(30), dec!(80.0)),
+            (exit_time, dec!(120.0)),
+        ] {
+            equity.update(&Event::Balance(Balance::new(time, total, total)));
+        }
+
+        let total = TradingSummary::init(Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+        let report = SessionReport::new(
+            Uuid::new_v4(),
+            BTreeMap::new(),
+            total,
+            None,
+            vec![position],
+            Some(&equity),
+        );
+
+        // Intra-trade drawdown is only visible in the mark-to-market equity curve
+        assert_eq!(report.equity_curve, equity.points());
+        assert_eq!(report.drawdowns.len(), 1);
+        assert_eq!(report.drawdowns[0].drawdown, -0.2);
+
+        let html = report.to_html();
+        assert!(html.contains("&lt;b&gt;"));
+        assert!(!html.contains("<b>"));
+    }
+}
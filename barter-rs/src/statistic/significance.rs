@@ -0,0 +1,491 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        algorithm::normal_distribution::{inverse_normal_cdf, normal_cdf},
+        decimal_to_f64, divide,
+        error::StatisticError,
+        summary::TableBuilder,
+    },
+};
+use prettytable::Row;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Euler-Mascheroni constant, used to estimate the expected maximum Sharpe Ratio of many trials.
+const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+/// Configuration for a [`SignificanceTest`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub starting_equity: f64,
+    /// Risk free return per trade, used to calculate the Sharpe Ratio.
+    pub risk_free_return: f64,
+    /// Number of resampled trade sequences generated by each resampling method.
+    pub iterations: usize,
+    /// Confidence level of the [`ConfidenceInterval`]s, eg/ 0.95.
+    pub confidence: f64,
+    /// Number of parameter combinations (strategy variants) tried before selecting this one,
+    /// used to deflate the Sharpe Ratio.
+    pub trials: usize,
+    /// Variance of the Sharpe Ratios across every trial, if known. Defaults to the variance of
+    /// the Sharpe Ratio estimator of this trade sequence.
+    pub trial_sharpe_variance: Option<f64>,
+    /// Seed of the random number generator, for reproducible results.
+    pub seed: Option<u64>,
+}
+
+impl Config {
+    /// Validates the [`Config`], returning an error describing the first invalid field.
+    pub fn validate(&self) -> Result<(), StatisticError> {
+        if self.starting_equity.is_nan() || self.starting_equity <= 0.0 {
+            return Err(StatisticError::InvalidConfig(
+                "starting_equity must be greater than zero",
+            ));
+        }
+        if self.iterations == 0 {
+            return Err(StatisticError::InvalidConfig(
+                "iterations must be greater than zero",
+            ));
+        }
+        if self.confidence.is_nan() || self.confidence <= 0.0 || self.confidence >= 1.0 {
+            return Err(StatisticError::InvalidConfig(
+                "confidence must be between zero & one (exclusive)",
+            ));
+        }
+        if self.trials == 0 {
+            return Err(StatisticError::InvalidConfig("trials must be at least one"));
+        }
+        if self
+            .trial_sharpe_variance
+            .is_some_and(|variance| variance.is_nan() || variance < 0.0)
+        {
+            return Err(StatisticError::InvalidConfig(
+                "trial_sharpe_variance must not be negative",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Tests the statistical significance of a trading session by resampling it's exited
+/// [`Position`]s.
+///
+/// - Bootstrap resampling (with replacement) estimates the sampling distribution of the Sharpe
+///   Ratio, max drawdown & terminal equity.
+/// - Monte Carlo trade order shuffling estimates the max drawdown that could have been suffered
+///   with the same trades in a different order. The Sharpe Ratio & terminal equity do not depend
+///   on the trade order, so are unchanged by shuffling.
+/// - The probabilistic & deflated Sharpe Ratios (Bailey & López de Prado) estimate the
+///   probability the true Sharpe Ratio is positive, correcting for the non-normality of the
+///   returns, the number of trades & the number of trials.
+#[derive(Debug)]
+pub struct SignificanceTest {
+    config: Config,
+    rng: StdRng,
+}
+
+impl SignificanceTest {
+    /// Constructs a new [`SignificanceTest`] using the provided configuration struct, which is
+    /// validated first.
+    pub fn new(config: Config) -> Result<Self, StatisticError> {
+        config.validate()?;
+
+        Ok(Self {
+            rng: match config.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+            config,
+        })
+    }
+
+    /// Run the [`SignificanceTest`] on the provided exited [`Position`]s, which must be in exit
+    /// order & contain at least two [`Position`]s.
+    pub fn run(&mut self, positions: &[Position]) -> Result<SignificanceReport, StatisticError> {
+        if positions.len() < 2 {
+            return Err(StatisticError::InsufficientData {
+                required: 2,
+                actual: positions.len(),
+            });
+        }
+
+        let trades = positions
+            .iter()
+            .map(|position| Trade {
+                pnl_return: decimal_to_f64(position.calculate_profit_loss_return()),
+                pnl: decimal_to_f64(position.realised_profit_loss),
+            })
+            .collect::<Vec<_>>();
+
+        let observed = self.path_statistics(&trades);
+        let moments = Moments::new(trades.iter().map(|trade| trade.pnl_return));
+
+        // Bootstrap: resample trades with replacement
+        let mut bootstrap = Samples::with_capacity(self.config.iterations);
+        let mut resampled = trades.clone();
+        for _ in 0..self.config.iterations {
+            for trade in resampled.iter_mut() {
+                *trade = trades[self.rng.gen_range(0..trades.len())];
+            }
+            bootstrap.push(self.path_statistics(&resampled));
+        }
+
+        // Monte Carlo: shuffle the order of the trades
+        let mut monte_carlo = Samples::with_capacity(self.config.iterations);
+        let mut shuffled = trades.clone();
+        for _ in 0..self.config.iterations {
+            shuffled.shuffle(&mut self.rng);
+            monte_carlo.push(self.path_statistics(&shuffled));
+        }
+
+        // Deflated Sharpe Ratio benchmarks against the expected maximum Sharpe Ratio of the trials
+        let sharpe_variance = self
+            .config
+            .trial_sharpe_variance
+            .unwrap_or_else(|| moments.sharpe_estimator_variance(observed.sharpe_ratio));
+        let expected_max_sharpe = expected_max_sharpe(self.config.trials, sharpe_variance);
+
+        Ok(SignificanceReport {
+            trades: trades.len(),
+            sharpe_ratio: observed.sharpe_ratio,
+            max_drawdown: observed.max_drawdown,
+            terminal_equity: observed.terminal_equity,
+            probabilistic_sharpe_ratio: moments
+                .probabilistic_sharpe_ratio(observed.sharpe_ratio, 0.0),
+            expected_max_sharpe_ratio: expected_max_sharpe,
+            deflated_sharpe_ratio: moments
+                .probabilistic_sharpe_ratio(observed.sharpe_ratio, expected_max_sharpe),
+            bootstrap: bootstrap.summarise(self.config.confidence),
+            monte_carlo: monte_carlo.summarise(self.config.confidence),
+        })
+    }
+
+    /// Calculate the [`PathStatistics`] of a sequence of trades.
+    fn path_statistics(&self, trades: &[Trade]) -> PathStatistics {
+        let mut equity = self.config.starting_equity;
+        let mut peak = equity;
+        let mut max_drawdown: f64 = 0.0;
+
+        for trade in trades {
+            equity += trade.pnl;
+            peak = peak.max(equity);
+            if peak > 0.0 {
+                max_drawdown = max_drawdown.min((equity - peak) / peak);
+            }
+        }
+
+        let moments = Moments::new(trades.iter().map(|trade| trade.pnl_return));
+
+        PathStatistics {
+            sharpe_ratio: divide(moments.mean - self.config.risk_free_return, moments.std_dev),
+            max_drawdown,
+            terminal_equity: equity,
+        }
+    }
+}
+
+/// PnL of an exited [`Position`].
+#[derive(Copy, Clone, Debug)]
+struct Trade {
+    pnl_return: f64,
+    pnl: f64,
+}
+
+/// Statistics of one (observed or resampled) sequence of trades.
+#[derive(Copy, Clone, Debug)]
+struct PathStatistics {
+    sharpe_ratio: f64,
+    max_drawdown: f64,
+    terminal_equity: f64,
+}
+
+/// [`PathStatistics`] of every resampled sequence of trades.
+#[derive(Debug)]
+struct Samples {
+    sharpe_ratio: Vec<f64>,
+    max_drawdown: Vec<f64>,
+    terminal_equity: Vec<f64>,
+}
+
+impl Samples {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            sharpe_ratio: Vec::with_capacity(capacity),
+            max_drawdown: Vec::with_capacity(capacity),
+            terminal_equity: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, statistics: PathStatistics) {
+        self.sharpe_ratio.push(statistics.sharpe_ratio);
+        self.max_drawdown.push(statistics.max_drawdown);
+        self.terminal_equity.push(statistics.terminal_equity);
+    }
+
+    fn summarise(self, confidence: f64) -> ResamplingSummary {
+        ResamplingSummary {
+            sharpe_ratio: ConfidenceInterval::new(self.sharpe_ratio, confidence),
+            max_drawdown: ConfidenceInterval::new(self.max_drawdown, confidence),
+            terminal_equity: ConfidenceInterval::new(self.terminal_equity, confidence),
+        }
+    }
+}
+
+/// Sample moments of the trade returns.
+#[derive(Copy, Clone, Debug)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    std_dev: f64,
+    skewness: f64,
+    /// Non-excess kurtosis, ie/ 3.0 for normally distributed returns.
+    kurtosis: f64,
+}
+
+impl Moments {
+    fn new(values: impl Iterator<Item = f64> + Clone) -> Self {
+        let count = values.clone().count() as f64;
+        let mean = values.clone().sum::<f64>() / count;
+        let moment =
+            |power: i32| values.clone().map(|x| (x - mean).powi(power)).sum::<f64>() / count;
+
+        let variance = moment(2);
+        let std_dev = variance.sqrt();
+
+        Self {
+            count,
+            mean,
+            std_dev,
+            skewness: divide(moment(3), variance * std_dev),
+            kurtosis: divide(moment(4), variance * variance),
+        }
+    }
+
+    /// Variance of the Sharpe Ratio estimator, adjusted for the skewness & kurtosis.
+    fn sharpe_estimator_variance(&self, sharpe_ratio: f64) -> f64 {
+        (1.0 - self.skewness * sharpe_ratio + (self.kurtosis - 1.0) / 4.0 * sharpe_ratio.powi(2))
+            / (self.count - 1.0)
+    }
+
+    /// Probability the true Sharpe Ratio exceeds the benchmark Sharpe Ratio.
+    fn probabilistic_sharpe_ratio(&self, sharpe_ratio: f64, benchmark: f64) -> f64 {
+        let std_error = self.sharpe_estimator_variance(sharpe_ratio).sqrt();
+        if std_error.is_nan() || std_error == 0.0 {
+            return if sharpe_ratio > benchmark { 1.0 } else { 0.0 };
+        }
+
+        normal_cdf((sharpe_ratio - benchmark) / std_error)
+    }
+}
+
+/// Expected maximum Sharpe Ratio of many independent trials with a true Sharpe Ratio of zero.
+fn expected_max_sharpe(trials: usize, sharpe_variance: f64) -> f64 {
+    if trials <= 1 {
+        return 0.0;
+    }
+
+    let trials = trials as f64;
+    sharpe_variance.sqrt()
+        * ((1.0 - EULER_MASCHERONI) * inverse_normal_cdf(1.0 - 1.0 / trials)
+            + EULER_MASCHERONI * inverse_normal_cdf(1.0 - 1.0 / (trials * std::f64::consts::E)))
+}
+
+/// Two-sided confidence interval of a resampled statistic, alongside it's median.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub median: f64,
+    pub upper: f64,
+}
+
+impl ConfidenceInterval {
+    /// Constructs the [`ConfidenceInterval`] of the provided samples using percentiles. The
+    /// confidence is clamped between zero & one.
+    pub fn new(mut samples: Vec<f64>, confidence: f64) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        samples.sort_by(f64::total_cmp);
+        let tail = ((1.0 - confidence) / 2.0).clamp(0.0, 0.5);
+        let percentile = |q: f64| samples[((samples.len() - 1) as f64 * q).round() as usize];
+
+        Self {
+            lower: percentile(tail),
+            median: percentile(0.5),
+            upper: percentile(1.0 - tail),
+        }
+    }
+}
+
+/// [`ConfidenceInterval`]s of the statistics of a resampling method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ResamplingSummary {
+    pub sharpe_ratio: ConfidenceInterval,
+    pub max_drawdown: ConfidenceInterval,
+    pub terminal_equity: ConfidenceInterval,
+}
+
+/// Result of a [`SignificanceTest`]. Sharpe Ratios are per trade.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct SignificanceReport {
+    pub trades: usize,
+    pub sharpe_ratio: f64,
+    pub max_drawdown: f64,
+    pub terminal_equity: f64,
+    /// Probability the true Sharpe Ratio is greater than zero.
+    pub probabilistic_sharpe_ratio: f64,
+    /// Expected maximum Sharpe Ratio of the trials if none had any skill.
+    pub expected_max_sharpe_ratio: f64,
+    /// Probability the true Sharpe Ratio is greater than the expected maximum Sharpe Ratio of the
+    /// trials, ie/ the probabilistic Sharpe Ratio corrected for selection bias.
+    pub deflated_sharpe_ratio: f64,
+    pub bootstrap: ResamplingSummary,
+    pub monte_carlo: ResamplingSummary,
+}
+
+impl TableBuilder for SignificanceReport {
+    fn titles(&self) -> Row {
+        row![
+            "Trades",
+            "Sharpe Ratio",
+            "Probabilistic Sharpe",
+            "Deflated Sharpe",
+            "Bootstrap Sharpe CI",
+            "Bootstrap Max Drawdown CI",
+            "Bootstrap Terminal Equity CI",
+            "Shuffled Max Drawdown CI",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        let interval = |ci: ConfidenceInterval| format!("[{:.3}, {:.3}]", ci.lower, ci.upper);
+
+        row![
+            self.trades,
+            format!("{:.3}", self.sharpe_ratio),
+            format!("{:.3}", self.probabilistic_sharpe_ratio),
+            format!("{:.3}", self.deflated_sharpe_ratio),
+            interval(self.bootstrap.sharpe_ratio),
+            interval(self.bootstrap.max_drawdown),
+            interval(self.bootstrap.terminal_equity),
+            interval(self.monte_carlo.max_drawdown),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+    use rust_decimal::{prelude::FromPrimitive, Decimal};
+
+    fn positions(pnls: &[f64]) -> Vec<Position> {
+        pnls.iter()
+            .map(|pnl| {
+                let mut position = position();
+                position.realised_profit_loss = Decimal::from_f64(*pnl).unwrap();
+                position
+            })
+            .collect()
+    }
+
+    fn test(trials: usize) -> SignificanceTest {
+        SignificanceTest::new(Config {
+            starting_equity: 1000.0,
+            risk_free_return: 0.0,
+            iterations: 500,
+            confidence: 0.95,
+            trials,
+            trial_sharpe_variance: None,
+            seed: Some(42),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn significance_test_rejects_invalid_config() {
+        let valid = Config {
+            starting_equity: 1000.0,
+            risk_free_return: 0.0,
+            iterations: 500,
+            confidence: 0.95,
+            trials: 1,
+            trial_sharpe_variance: None,
+            seed: Some(42),
+        };
+
+        for invalid in [
+            Config {
+                confidence: 1.5,
+                ..valid
+            },
+            Config {
+                confidence: f64::NAN,
+                ..valid
+            },
+            Config {
+                iterations: 0,
+                ..valid
+            },
+            Config { trials: 0, ..valid },
+            Config {
+                starting_equity: 0.0,
+                ..valid
+            },
+            Config {
+                trial_sharpe_variance: Some(-1.0),
+                ..valid
+            },
+        ] {
+            assert!(matches!(
+                SignificanceTest::new(invalid),
+                Err(StatisticError::InvalidConfig(_))
+            ));
+        }
+
+        assert!(SignificanceTest::new(valid).is_ok());
+        assert_eq!(ConfidenceInterval::new(vec![1.0, 2.0, 3.0], 1.5).upper, 3.0);
+    }
+
+    #[test]
+    fn significance_test_resamples_trades_and_deflates_sharpe() {
+        let pnls = (0..40)
+            .map(|index| if index % 3 == 0 { -8.0 } else { 10.0 })
+            .collect::<Vec<_>>();
+        let positions = positions(&pnls);
+
+        let report = test(1).run(&positions).unwrap();
+        assert_eq!(report.trades, 40);
+        assert_eq!(report.terminal_equity, 1000.0 + 26.0 * 10.0 - 14.0 * 8.0);
+
+        // Order of trades does not change the terminal equity, but does change the max drawdown
+        let shuffled = report.monte_carlo;
+        assert_eq!(shuffled.terminal_equity.lower, report.terminal_equity);
+        assert_eq!(shuffled.terminal_equity.upper, report.terminal_equity);
+        assert!(shuffled.max_drawdown.lower < report.max_drawdown);
+
+        // Bootstrap interval contains the observed Sharpe Ratio
+        let sharpe = report.bootstrap.sharpe_ratio;
+        assert!(sharpe.lower < report.sharpe_ratio && report.sharpe_ratio < sharpe.upper);
+
+        // A single trial is not deflated, whereas many trials reduce the confidence
+        assert_eq!(report.expected_max_sharpe_ratio, 0.0);
+        assert_eq!(
+            report.deflated_sharpe_ratio,
+            report.probabilistic_sharpe_ratio
+        );
+        let deflated = test(1_000).run(&positions).unwrap();
+        assert!(deflated.expected_max_sharpe_ratio > 0.0);
+        assert!(deflated.deflated_sharpe_ratio < report.probabilistic_sharpe_ratio);
+
+        assert!(matches!(
+            test(1).run(&positions[..1]),
+            Err(StatisticError::InsufficientData {
+                required: 2,
+                actual: 1
+            })
+        ));
+    }
+}
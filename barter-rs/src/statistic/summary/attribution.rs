@@ -0,0 +1,146 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        decimal_to_f64,
+        summary::{combine, TableBuilder},
+    },
+};
+use prettytable::{Row, Table};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Realised PnL attributed to an [`Ensemble`](crate::strategy::ensemble::Ensemble) member.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct StrategyPnL {
+    /// Number of exited [`Position`]s the member contributed to.
+    pub positions: u64,
+    /// Number of those [`Position`]s that realised a profit.
+    pub wins: u64,
+    /// Member's share of the realised PnL of those [`Position`]s.
+    pub realised_pnl: f64,
+}
+
+/// Tracks the realised PnL of each [`Ensemble`](crate::strategy::ensemble::Ensemble) member
+/// using the [`Attribution`](crate::data::Attribution) recorded by exited
+/// [`Position`]s. The realised PnL of a [`Position`] is shared equally between the members that
+/// contributed to it.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct AttributionSummary {
+    members: Vec<String>,
+    pnl: Vec<StrategyPnL>,
+}
+
+impl AttributionSummary {
+    /// Constructs a new [`AttributionSummary`] for the provided member names, indexed
+    /// consistently with [`Attribution`](crate::data::Attribution), see
+    /// [`Ensemble::member_names`](crate::strategy::ensemble::Ensemble::member_names).
+    pub fn new(members: Vec<String>) -> Self {
+        Self {
+            pnl: vec![StrategyPnL::default(); members.len()],
+            members,
+        }
+    }
+
+    /// Attribute the realised PnL of an exited [`Position`] to the members that contributed to
+    /// it. [`Position`]s without an [`Attribution`](crate::data::Attribution) are
+    /// ignored.
+    pub fn update(&mut self, position: &Position) {
+        let contributors = match position.meta.attribution {
+            Some(attribution) => attribution
+                .members()
+                .filter(|index| *index < self.pnl.len())
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+
+        if contributors.is_empty() {
+            return;
+        }
+
+        let share = decimal_to_f64(position.realised_profit_loss) / contributors.len() as f64;
+        for index in contributors {
+            let pnl = &mut self.pnl[index];
+            pnl.positions += 1;
+            pnl.realised_pnl += share;
+            if position.realised_profit_loss > Decimal::ZERO {
+                pnl.wins += 1;
+            }
+        }
+    }
+
+    /// Attribute the realised PnL of every exited [`Position`] provided.
+    pub fn generate_summary(&mut self, positions: &[Position]) {
+        for position in positions {
+            self.update(position);
+        }
+    }
+
+    /// Returns the [`StrategyPnL`] attributed to the named member, if it exists.
+    pub fn statistics(&self, member: &str) -> Option<StrategyPnL> {
+        self.members
+            .iter()
+            .position(|name| name == member)
+            .map(|index| self.pnl[index])
+    }
+
+    /// Generates a [`Table`] with a row of [`StrategyPnL`] for every member.
+    pub fn table(&self) -> Table {
+        combine(self.members.iter().cloned().zip(self.pnl.iter().copied()))
+    }
+}
+
+impl TableBuilder for StrategyPnL {
+    fn titles(&self) -> Row {
+        row!["Positions", "Wins", "Realised PnL"]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.positions.to_string(),
+            self.wins.to_string(),
+            format!("{:.3}", self.realised_pnl),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data::Attribution, test_util::position};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn attribution_summary_shares_realised_pnl_between_contributors() {
+        let mut summary = AttributionSummary::new(vec!["trend".to_owned(), "carry".to_owned()]);
+
+        let mut shared = position();
+        shared.realised_profit_loss = dec!(10.0);
+        shared.meta.attribution = Some(Attribution(0b11));
+
+        let mut trend_only = position();
+        trend_only.realised_profit_loss = dec!(-4.0);
+        trend_only.meta.attribution = Some(Attribution(0b01));
+
+        let unattributed = position();
+
+        summary.generate_summary(&[shared, trend_only, unattributed]);
+
+        assert_eq!(
+            summary.statistics("trend"),
+            Some(StrategyPnL {
+                positions: 2,
+                wins: 1,
+                realised_pnl: 1.0
+            })
+        );
+        assert_eq!(
+            summary.statistics("carry"),
+            Some(StrategyPnL {
+                positions: 1,
+                wins: 1,
+                realised_pnl: 5.0
+            })
+        );
+        assert_eq!(summary.statistics("unknown"), None);
+    }
+}
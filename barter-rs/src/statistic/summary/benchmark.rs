@@ -0,0 +1,453 @@
+use crate::{
+    event::Event,
+    portfolio::position::PositionId,
+    statistic::{
+        decimal_to_f64, divide,
+        summary::{data::DataSummary, TableBuilder},
+    },
+    strategy::indicator::Input,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::MarketId;
+use chrono::{DateTime, NaiveDate, Utc};
+use prettytable::{Cell, Row};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Configuration for constructing a [`BenchmarkSummary`] via the new() constructor method.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub starting_equity: f64,
+    pub trading_days_per_year: usize,
+    /// Daily risk free return used to calculate alpha & beta.
+    pub risk_free_return: f64,
+    pub benchmark: Benchmark,
+}
+
+/// Benchmark portfolio the trading strategy is compared against.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "benchmark", rename_all = "snake_case")]
+pub enum Benchmark {
+    /// Equal weight buy-and-hold of every market, bought at it's first price.
+    BuyAndHold,
+    /// Buy-and-hold index of the provided markets, bought at their first price. Each market is
+    /// weighted by it's weight / the total weight of the markets priced so far.
+    Index { weights: HashMap<MarketId, f64> },
+}
+
+/// Compares the trading strategy equity curve to a [`Benchmark`] equity curve built from the same
+/// [`MarketEvent`] stream, eg/ to determine if a strategy beat holding the asset.
+///
+/// Both curves are compared using their daily returns, see [`BenchmarkStatistics`]. Only the
+/// daily closes are recorded, so the [`BenchmarkSummary`]s recorded by many
+/// [`Trader`](crate::engine::trader::Trader)s can be merged at the end of the trading session.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BenchmarkSummary {
+    config: Config,
+    /// First price of every market in the [`Benchmark`].
+    first_prices: HashMap<MarketId, f64>,
+    /// Latest price of every market in the [`Benchmark`] each day.
+    market_closes: BTreeMap<NaiveDate, HashMap<MarketId, f64>>,
+    /// Latest Portfolio balance each day, & the time it was updated.
+    balance_closes: BTreeMap<NaiveDate, (DateTime<Utc>, f64)>,
+    /// Latest unrealised PnL of every Position each day, or `None` if it was exited.
+    unrealised_closes: BTreeMap<NaiveDate, HashMap<PositionId, Option<f64>>>,
+}
+
+impl BenchmarkSummary {
+    /// Constructs a new [`BenchmarkSummary`] using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            first_prices: HashMap::new(),
+            market_closes: BTreeMap::new(),
+            balance_closes: BTreeMap::new(),
+            unrealised_closes: BTreeMap::new(),
+        }
+    }
+
+    /// Configuration of this [`BenchmarkSummary`].
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Update the [`Benchmark`] equity curve using the latest [`MarketEvent`].
+    pub fn update_market(&mut self, market: &MarketEvent<DataKind>) {
+        let price = match Input::Price.extract(&market.kind) {
+            Some(price) if price > 0.0 => price,
+            _ => return,
+        };
+
+        let market_id = MarketId::new(&market.exchange, &market.instrument);
+        if self.weight(&market_id) <= 0.0 {
+            return;
+        }
+
+        self.first_prices.entry(market_id.clone()).or_insert(price);
+        self.market_closes
+            .entry(market.exchange_time.date_naive())
+            .or_default()
+            .insert(market_id, price);
+    }
+
+    /// Update the strategy equity curve using a Position or Balance [`Event`]. Strategy equity is
+    /// the Portfolio balance plus the unrealised PnL of every open Position.
+    pub fn update_equity(&mut self, event: &Event) {
+        match event {
+            Event::PositionNew(position) => self.update_unrealised(
+                position.meta.update_time,
+                &position.position_id,
+                Some(decimal_to_f64(position.unrealised_profit_loss)),
+            ),
+            Event::PositionUpdate(update) => self.update_unrealised(
+                update.update_time,
+                &update.position_id,
+                Some(decimal_to_f64(update.unrealised_profit_loss)),
+            ),
+            Event::PositionExit(exit) => {
+                self.update_unrealised(exit.exit_time, &exit.position_id, None);
+                self.update_balance(exit.exit_time, decimal_to_f64(exit.exit_balance.total));
+            }
+            Event::Balance(balance) => {
+                self.update_balance(balance.time, decimal_to_f64(balance.total))
+            }
+            _ => {}
+        }
+    }
+
+    /// Merge the daily closes recorded by another [`BenchmarkSummary`] (eg/ of another
+    /// [`Trader`](crate::engine::trader::Trader)) into this one.
+    pub fn merge(&mut self, other: BenchmarkSummary) {
+        for (market_id, price) in other.first_prices {
+            self.first_prices.entry(market_id).or_insert(price);
+        }
+
+        for (date, closes) in other.market_closes {
+            self.market_closes.entry(date).or_default().extend(closes);
+        }
+
+        for (time, balance) in other.balance_closes.into_values() {
+            self.update_balance(time, balance);
+        }
+
+        for (date, closes) in other.unrealised_closes {
+            self.unrealised_closes
+                .entry(date)
+                .or_default()
+                .extend(closes);
+        }
+    }
+
+    /// Calculate the [`BenchmarkStatistics`] using the daily returns of every day with both a
+    /// strategy & benchmark equity.
+    pub fn statistics(&self) -> BenchmarkStatistics {
+        let mut strategy_returns = DataSummary::default();
+        let mut benchmark_returns = DataSummary::default();
+        let mut active_returns = DataSummary::default();
+        let mut covariance = 0.0;
+        let mut up_capture = (0.0, 0.0);
+        let mut down_capture = (0.0, 0.0);
+
+        // Strategy equity carries forward on days without a Position or Balance Event
+        let mut balance = self.config.starting_equity;
+        let mut unrealised = HashMap::new();
+        let mut prices = HashMap::new();
+
+        let dates = self
+            .market_closes
+            .keys()
+            .chain(self.balance_closes.keys())
+            .chain(self.unrealised_closes.keys())
+            .collect::<BTreeSet<_>>();
+
+        let mut prev_closes = (self.config.starting_equity, self.config.starting_equity);
+        for date in dates {
+            if let Some((_, balance_close)) = self.balance_closes.get(date) {
+                balance = *balance_close;
+            }
+
+            for (position_id, close) in self.unrealised_closes.get(date).into_iter().flatten() {
+                match close {
+                    Some(close) => unrealised.insert(position_id, *close),
+                    None => unrealised.remove(position_id),
+                };
+            }
+
+            let market_closes = match self.market_closes.get(date) {
+                Some(market_closes) => market_closes,
+                None => continue,
+            };
+            prices.extend(market_closes);
+
+            let strategy_close = balance + unrealised.values().sum::<f64>();
+            let benchmark_close = self.benchmark_equity(&prices);
+
+            let strategy_return = strategy_close / prev_closes.0 - 1.0;
+            let benchmark_return = benchmark_close / prev_closes.1 - 1.0;
+            prev_closes = (strategy_close, benchmark_close);
+
+            // Welford co-moment uses the strategy mean before & the benchmark mean after update
+            let prev_strategy_mean = strategy_returns.mean;
+            strategy_returns.update(strategy_return);
+            benchmark_returns.update(benchmark_return);
+            covariance += (strategy_return - prev_strategy_mean)
+                * (benchmark_return - benchmark_returns.mean);
+            active_returns.update(strategy_return - benchmark_return);
+
+            if benchmark_return > 0.0 {
+                up_capture.0 += strategy_return;
+                up_capture.1 += benchmark_return;
+            } else if benchmark_return < 0.0 {
+                down_capture.0 += strategy_return;
+                down_capture.1 += benchmark_return;
+            }
+        }
+
+        let days = strategy_returns.count;
+        let trading_days = self.config.trading_days_per_year as f64;
+        let covariance = divide(covariance, days as f64);
+        let strategy_std_dev = strategy_returns.dispersion.std_dev;
+        let benchmark_variance = benchmark_returns.dispersion.variance;
+        let beta = divide(covariance, benchmark_variance);
+        let risk_free = self.config.risk_free_return;
+        let tracking_error = active_returns.dispersion.std_dev;
+
+        BenchmarkStatistics {
+            days,
+            strategy_return: prev_closes.0 / self.config.starting_equity - 1.0,
+            benchmark_return: prev_closes.1 / self.config.starting_equity - 1.0,
+            alpha: ((strategy_returns.mean - risk_free)
+                - beta * (benchmark_returns.mean - risk_free))
+                * trading_days,
+            beta,
+            correlation: divide(covariance, strategy_std_dev * benchmark_variance.sqrt()),
+            tracking_error: tracking_error * trading_days.sqrt(),
+            information_ratio: divide(active_returns.mean, tracking_error) * trading_days.sqrt(),
+            up_capture: divide(up_capture.0, up_capture.1),
+            down_capture: divide(down_capture.0, down_capture.1),
+        }
+    }
+
+    /// Record the latest Portfolio balance of the day, ignoring balances older than the one
+    /// already recorded.
+    fn update_balance(&mut self, time: DateTime<Utc>, balance: f64) {
+        let close = self
+            .balance_closes
+            .entry(time.date_naive())
+            .or_insert((time, balance));
+
+        if time >= close.0 {
+            *close = (time, balance);
+        }
+    }
+
+    /// Record the latest unrealised PnL of a Position for the day, or `None` if it was exited.
+    fn update_unrealised(
+        &mut self,
+        time: DateTime<Utc>,
+        position_id: &PositionId,
+        unrealised: Option<f64>,
+    ) {
+        self.unrealised_closes
+            .entry(time.date_naive())
+            .or_default()
+            .insert(position_id.clone(), unrealised);
+    }
+
+    /// Equity of the [`Benchmark`] portfolio given the latest price of every market priced so far.
+    fn benchmark_equity(&self, prices: &HashMap<&MarketId, &f64>) -> f64 {
+        let total_weight = prices
+            .keys()
+            .map(|market_id| self.weight(market_id))
+            .sum::<f64>();
+
+        prices
+            .iter()
+            .map(|(market_id, latest)| {
+                let first = self
+                    .first_prices
+                    .get(*market_id)
+                    .copied()
+                    .unwrap_or(**latest);
+                self.weight(market_id) / total_weight * **latest / first
+            })
+            .sum::<f64>()
+            * self.config.starting_equity
+    }
+
+    /// Weight of a market in the [`Benchmark`].
+    fn weight(&self, market_id: &MarketId) -> f64 {
+        match &self.config.benchmark {
+            Benchmark::BuyAndHold => 1.0,
+            Benchmark::Index { weights } => weights.get(market_id).copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Statistics of the trading strategy relative to a [`Benchmark`], calculated using daily returns.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct BenchmarkStatistics {
+    pub days: u64,
+    pub strategy_return: f64,
+    pub benchmark_return: f64,
+    /// Annualised Jensen's alpha.
+    pub alpha: f64,
+    pub beta: f64,
+    pub correlation: f64,
+    /// Annualised standard deviation of the active (strategy - benchmark) returns.
+    pub tracking_error: f64,
+    /// Annualised mean active return / tracking error.
+    pub information_ratio: f64,
+    /// Strategy return / benchmark return, over the days the benchmark rose.
+    pub up_capture: f64,
+    /// Strategy return / benchmark return, over the days the benchmark fell.
+    pub down_capture: f64,
+}
+
+impl TableBuilder for BenchmarkStatistics {
+    fn titles(&self) -> Row {
+        row![
+            "Benchmark Return",
+            "Alpha",
+            "Beta",
+            "Correlation",
+            "Tracking Error",
+            "Information Ratio",
+            "Up Capture",
+            "Down Capture",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.benchmark_return),
+            format!("{:.3}", self.alpha),
+            format!("{:.3}", self.beta),
+            format!("{:.3}", self.correlation),
+            format!("{:.3}", self.tracking_error),
+            format!("{:.3}", self.information_ratio),
+            format!("{:.3}", self.up_capture),
+            format!("{:.3}", self.down_capture),
+        ]
+    }
+}
+
+/// Summary extended with [`BenchmarkStatistics`] columns, which are left empty if it has none.
+/// Used to add the [`Benchmark`] comparison to the trading session summary table.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Benchmarked<Summary> {
+    pub summary: Summary,
+    pub benchmark: Option<BenchmarkStatistics>,
+}
+
+impl<Summary> TableBuilder for Benchmarked<Summary>
+where
+    Summary: TableBuilder,
+{
+    fn titles(&self) -> Row {
+        let mut titles = self.summary.titles();
+        for title in &BenchmarkStatistics::default().titles() {
+            titles.add_cell(title.clone());
+        }
+        titles
+    }
+
+    fn row(&self) -> Row {
+        let mut cells = self.summary.row();
+        match &self.benchmark {
+            Some(benchmark) => {
+                for cell in &benchmark.row() {
+                    cells.add_cell(cell.clone());
+                }
+            }
+            None => {
+                for _ in 0..BenchmarkStatistics::default().titles().len() {
+                    cells.add_cell(Cell::new("-"));
+                }
+            }
+        }
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::market_event_trade};
+    use barter_integration::model::Side;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn benchmark_summary_compares_strategy_to_buy_and_hold() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let mut summary = BenchmarkSummary::new(Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            benchmark: Benchmark::BuyAndHold,
+        });
+
+        // Strategy daily returns are exactly twice the benchmark daily returns
+        let benchmark_prices = [dec!(100.0), dec!(110.0), dec!(99.0), dec!(108.9)];
+        let strategy_equity = [dec!(100.0), dec!(120.0), dec!(96.0), dec!(115.2)];
+        for (day, (price, equity)) in benchmark_prices.iter().zip(strategy_equity).enumerate() {
+            let time = start + Duration::days(day as i64);
+
+            let mut market = market_event_trade(Side::Buy);
+            market.exchange_time = time;
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = *price;
+            }
+
+            summary.update_market(&market);
+            summary.update_equity(&Event::Balance(Balance::new(time, equity, equity)));
+        }
+
+        let statistics = summary.statistics();
+        assert_eq!(statistics.days, 4);
+        assert!((statistics.benchmark_return - 0.089).abs() < 1e-9);
+        assert!((statistics.strategy_return - 0.152).abs() < 1e-9);
+        assert!((statistics.beta - 2.0).abs() < 1e-9);
+        assert!((statistics.correlation - 1.0).abs() < 1e-9);
+        assert!(statistics.alpha.abs() < 1e-9);
+        assert!((statistics.up_capture - 2.0).abs() < 1e-9);
+        assert!((statistics.down_capture - 2.0).abs() < 1e-9);
+        assert!(statistics.tracking_error > 0.0);
+    }
+
+    #[test]
+    fn benchmark_summary_merges_closes_recorded_by_many_traders() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let config = Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            benchmark: Benchmark::BuyAndHold,
+        };
+        let mut combined = BenchmarkSummary::new(config.clone());
+        let mut markets = BenchmarkSummary::new(config.clone());
+        let mut equity = BenchmarkSummary::new(config);
+
+        let benchmark_prices = [dec!(100.0), dec!(110.0), dec!(99.0)];
+        let strategy_equity = [dec!(100.0), dec!(105.0), dec!(101.0)];
+        for (day, (price, balance)) in benchmark_prices.iter().zip(strategy_equity).enumerate() {
+            let time = start + Duration::days(day as i64);
+
+            let mut market = market_event_trade(Side::Buy);
+            market.exchange_time = time;
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = *price;
+            }
+            let balance = Event::Balance(Balance::new(time, balance, balance));
+
+            combined.update_market(&market);
+            combined.update_equity(&balance);
+            markets.update_market(&market);
+            equity.update_equity(&balance);
+        }
+
+        markets.merge(equity);
+        assert_eq!(markets.statistics(), combined.statistics());
+    }
+}
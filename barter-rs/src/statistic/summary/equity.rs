@@ -0,0 +1,507 @@
+use crate::{
+    event::Event,
+    portfolio::position::PositionId,
+    statistic::{
+        de_duration_from_secs, decimal_to_f64, metric::EquityPoint, se_duration_as_secs,
+        summary::data::DataSummary, summary::TableBuilder,
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::Row;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Write};
+
+/// Configuration for constructing an [`EquityCurve`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub starting_equity: f64,
+    pub trading_days_per_year: usize,
+    /// Daily risk free return used to calculate the Sharpe & Sortino ratios.
+    pub risk_free_return: f64,
+    pub sampling: Sampling,
+}
+
+/// Determines when an [`EquityCurve`] samples the mark-to-market equity of the Portfolio.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sampling {
+    /// Sample every time the equity changes, eg/ for every [`MarketEvent`] in a backtest.
+    ///
+    /// [`MarketEvent`]: barter_data::event::MarketEvent
+    EveryUpdate,
+    /// Sample at most once per interval (in seconds) of [`Event`] time, which is the exchange
+    /// time whilst backtesting.
+    Interval(
+        #[serde(
+            deserialize_with = "de_duration_from_secs",
+            serialize_with = "se_duration_as_secs"
+        )]
+        Duration,
+    ),
+}
+
+/// Time-based equity curve of the Portfolio, marked-to-market using the unrealised PnL of every
+/// open [`Position`](crate::portfolio::position::Position).
+///
+/// Fed by the [`Event`]s of a trading session (see [`EquityCurve::update`]), the curve captures
+/// unrealised swings that per-trade statistics miss, & is used to calculate time-based returns,
+/// volatility, ratios & the maximum intraday drawdown, see [`EquityStatistics`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EquityCurve {
+    config: Config,
+    balance: f64,
+    unrealised: HashMap<PositionId, f64>,
+    points: Vec<EquityPoint>,
+}
+
+impl EquityCurve {
+    /// Constructs a new [`EquityCurve`] using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            balance: config.starting_equity,
+            config,
+            unrealised: HashMap::new(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Configuration of this [`EquityCurve`].
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Update the mark-to-market equity using a [`Position`](crate::portfolio::position::Position)
+    /// or [`Balance`](crate::portfolio::Balance) [`Event`], sampling it according to the
+    /// configured [`Sampling`]. Every other [`Event`] is ignored.
+    pub fn update(&mut self, event: &Event) {
+        if let Some(update) = EquityUpdate::from_event(event) {
+            self.apply(update);
+        }
+    }
+
+    /// Apply an [`EquityUpdate`] to the mark-to-market equity, sampling it according to the
+    /// configured [`Sampling`].
+    pub fn apply(&mut self, update: EquityUpdate) {
+        let time = update.time();
+        match update {
+            EquityUpdate::Unrealised {
+                position_id,
+                unrealised_profit_loss,
+                ..
+            } => {
+                self.unrealised.insert(position_id, unrealised_profit_loss);
+            }
+            EquityUpdate::Exit {
+                position_id,
+                balance,
+                ..
+            } => {
+                self.unrealised.remove(&position_id);
+                self.balance = balance;
+            }
+            EquityUpdate::Balance { balance, .. } => {
+                self.balance = balance;
+            }
+        }
+
+        self.sample(time);
+    }
+
+    /// Current mark-to-market equity of the Portfolio.
+    pub fn equity(&self) -> f64 {
+        self.balance + self.unrealised.values().sum::<f64>()
+    }
+
+    /// Sampled [`EquityPoint`]s of the curve, in time order.
+    pub fn points(&self) -> &[EquityPoint] {
+        &self.points
+    }
+
+    /// Export the curve as CSV with `time`, `equity` & `drawdown` columns, eg/ for plotting.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("time,equity,drawdown\n");
+        let mut peak = self.config.starting_equity;
+
+        for point in &self.points {
+            peak = peak.max(point.total);
+            let _ = writeln!(
+                csv,
+                "{},{},{}",
+                point.time.to_rfc3339(),
+                point.total,
+                drawdown(peak, point.total)
+            );
+        }
+
+        csv
+    }
+
+    /// Calculate the time-based [`EquityStatistics`] of the curve. Returns are calculated between
+    /// the last sampled equity of each (UTC) day.
+    pub fn statistics(&self) -> EquityStatistics {
+        let mut daily_returns = DataSummary::default();
+        let mut downside_returns = DataSummary::default();
+        let mut prev_close = self.config.starting_equity;
+        let mut peak = self.config.starting_equity;
+        let mut max_drawdown: f64 = 0.0;
+
+        for (index, point) in self.points.iter().enumerate() {
+            peak = peak.max(point.total);
+            max_drawdown = max_drawdown.min(drawdown(peak, point.total));
+
+            // Last sample of the day determines the daily return
+            let is_day_close = self
+                .points
+                .get(index + 1)
+                .is_none_or(|next| next.time.date_naive() != point.time.date_naive());
+
+            if is_day_close {
+                let daily_return = point.total / prev_close - 1.0;
+                daily_returns.update(daily_return);
+                downside_returns.update(daily_return.min(0.0));
+                prev_close = point.total;
+            }
+        }
+
+        let trading_days = self.config.trading_days_per_year as f64;
+        let excess_return = daily_returns.mean - self.config.risk_free_return;
+        let volatility = daily_returns.dispersion.std_dev;
+        let downside_deviation = downside_returns.dispersion.std_dev;
+
+        EquityStatistics {
+            start_time: self.points.first().map(|point| point.time),
+            end_time: self.points.last().map(|point| point.time),
+            days: daily_returns.count,
+            total_return: self
+                .points
+                .last()
+                .map_or(0.0, |point| point.total / self.config.starting_equity - 1.0),
+            daily_return: daily_returns.mean,
+            daily_volatility: volatility,
+            annualised_return: daily_returns.mean * trading_days,
+            annualised_volatility: volatility * trading_days.sqrt(),
+            sharpe_ratio: ratio(excess_return, volatility, trading_days),
+            sortino_ratio: ratio(excess_return, downside_deviation, trading_days),
+            max_drawdown,
+        }
+    }
+
+    /// Sample the current equity at the provided time if required by the [`Sampling`].
+    fn sample(&mut self, time: DateTime<Utc>) {
+        let due = match (self.config.sampling, self.points.last()) {
+            (Sampling::EveryUpdate, _) | (_, None) => true,
+            (Sampling::Interval(interval), Some(last)) => time >= last.time + interval,
+        };
+
+        if due {
+            self.points.push(EquityPoint {
+                time,
+                total: self.equity(),
+            });
+        }
+    }
+}
+
+/// Change in the mark-to-market equity of the Portfolio communicated by a Position or Balance
+/// [`Event`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum EquityUpdate {
+    /// Latest unrealised PnL of an open Position.
+    Unrealised {
+        time: DateTime<Utc>,
+        position_id: PositionId,
+        unrealised_profit_loss: f64,
+    },
+    /// Position exited, with the Portfolio balance after the exit.
+    Exit {
+        time: DateTime<Utc>,
+        position_id: PositionId,
+        balance: f64,
+    },
+    /// Latest Portfolio balance.
+    Balance { time: DateTime<Utc>, balance: f64 },
+}
+
+impl EquityUpdate {
+    /// Determines the [`EquityUpdate`] communicated by an [`Event`], if any.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::PositionNew(position) => Some(Self::Unrealised {
+                time: position.meta.update_time,
+                position_id: position.position_id.clone(),
+                unrealised_profit_loss: decimal_to_f64(position.unrealised_profit_loss),
+            }),
+            Event::PositionUpdate(update) => Some(Self::Unrealised {
+                time: update.update_time,
+                position_id: update.position_id.clone(),
+                unrealised_profit_loss: decimal_to_f64(update.unrealised_profit_loss),
+            }),
+            Event::PositionExit(exit) => Some(Self::Exit {
+                time: exit.exit_time,
+                position_id: exit.position_id.clone(),
+                balance: decimal_to_f64(exit.exit_balance.total),
+            }),
+            Event::Balance(balance) => Some(Self::Balance {
+                time: balance.time,
+                balance: decimal_to_f64(balance.total),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Time the equity changed.
+    pub fn time(&self) -> DateTime<Utc> {
+        match self {
+            Self::Unrealised { time, .. }
+            | Self::Exit { time, .. }
+            | Self::Balance { time, .. } => *time,
+        }
+    }
+}
+
+/// Records the [`EquityUpdate`]s encountered by a single
+/// [`Trader`](crate::engine::trader::Trader), so the [`EquityRecorder`]s of every
+/// [`Trader`](crate::engine::trader::Trader) can be merged into one [`EquityCurve`] at the end of
+/// the trading session.
+///
+/// With [`Sampling::Interval`], consecutive unrealised PnL updates of a Position within an
+/// interval are collapsed into the latest, since the [`EquityCurve`] samples at most one of them.
+#[derive(Clone, PartialEq, Debug)]
+pub struct EquityRecorder {
+    config: Config,
+    updates: Vec<EquityUpdate>,
+    /// Time of the first unrealised PnL update collapsed into the latest [`EquityUpdate`].
+    collapse_start: Option<DateTime<Utc>>,
+}
+
+impl EquityRecorder {
+    /// Constructs a new [`EquityRecorder`] using the provided [`EquityCurve`] configuration.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            updates: Vec::new(),
+            collapse_start: None,
+        }
+    }
+
+    /// Record the [`EquityUpdate`] communicated by an [`Event`], if any.
+    pub fn record(&mut self, event: &Event) {
+        let update = match EquityUpdate::from_event(event) {
+            Some(update) => update,
+            None => return,
+        };
+
+        let collapse = match (&update, self.updates.last(), self.config.sampling) {
+            (
+                EquityUpdate::Unrealised {
+                    time, position_id, ..
+                },
+                Some(EquityUpdate::Unrealised {
+                    position_id: last_position_id,
+                    ..
+                }),
+                Sampling::Interval(interval),
+            ) => {
+                position_id == last_position_id
+                    && self
+                        .collapse_start
+                        .is_some_and(|start| *time < start + interval)
+            }
+            _ => false,
+        };
+
+        if collapse {
+            self.updates.pop();
+        } else {
+            self.collapse_start = Some(update.time());
+        }
+        self.updates.push(update);
+    }
+
+    /// Configuration of the [`EquityCurve`] this [`EquityRecorder`] constructs.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Merge the [`EquityUpdate`]s recorded by another [`EquityRecorder`] into this one.
+    pub fn merge(&mut self, other: EquityRecorder) {
+        self.updates.extend(other.updates);
+        self.collapse_start = None;
+    }
+
+    /// Construct the [`EquityCurve`] by applying every recorded [`EquityUpdate`] in time order.
+    pub fn into_curve(mut self) -> EquityCurve {
+        self.updates.sort_by_key(EquityUpdate::time);
+
+        self.updates
+            .into_iter()
+            .fold(EquityCurve::new(self.config), |mut curve, update| {
+                curve.apply(update);
+                curve
+            })
+    }
+}
+
+/// Drawdown from the equity peak, as a negative fraction of the peak.
+fn drawdown(peak: f64, equity: f64) -> f64 {
+    if peak == 0.0 {
+        0.0
+    } else {
+        (equity - peak) / peak
+    }
+}
+
+/// Annualised ratio of a daily excess return to a daily measure of risk.
+fn ratio(excess_return: f64, risk: f64, trading_days: f64) -> f64 {
+    if risk == 0.0 {
+        0.0
+    } else {
+        excess_return / risk * trading_days.sqrt()
+    }
+}
+
+/// Time-based statistics of an [`EquityCurve`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct EquityStatistics {
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// Number of days with a sampled equity.
+    pub days: u64,
+    pub total_return: f64,
+    pub daily_return: f64,
+    pub daily_volatility: f64,
+    pub annualised_return: f64,
+    pub annualised_volatility: f64,
+    /// Annualised Sharpe ratio of the daily returns.
+    pub sharpe_ratio: f64,
+    /// Annualised Sortino ratio of the daily returns.
+    pub sortino_ratio: f64,
+    /// Largest peak-to-trough decline across every sample, including intraday.
+    pub max_drawdown: f64,
+}
+
+impl TableBuilder for EquityStatistics {
+    fn titles(&self) -> Row {
+        row![
+            "Days",
+            "Total Return",
+            "Annual Return",
+            "Annual Volatility",
+            "Sharpe Ratio",
+            "Sortino Ratio",
+            "Max Intraday Drawdown",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.days.to_string(),
+            format!("{:.3}", self.total_return),
+            format!("{:.3}", self.annualised_return),
+            format!("{:.3}", self.annualised_volatility),
+            format!("{:.3}", self.sharpe_ratio),
+            format!("{:.3}", self.sortino_ratio),
+            format!("{:.3}", self.max_drawdown),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::portfolio::{position::PositionUpdate, Balance};
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn update(time: DateTime<Utc>, unrealised_profit_loss: Decimal) -> Event {
+        Event::PositionUpdate(PositionUpdate {
+            position_id: "position".to_owned(),
+            update_time: time,
+            current_symbol_price: dec!(0.0),
+            current_value_gross: dec!(0.0),
+            unrealised_profit_loss,
+        })
+    }
+
+    #[test]
+    fn equity_curve_marks_to_market_and_samples_at_interval() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let mut curve = EquityCurve::new(Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            sampling: Sampling::Interval(Duration::hours(1)),
+        });
+
+        // Day 1: intraday unrealised swing is sampled at the interval, then closes +10%
+        curve.update(&Event::Balance(Balance::new(
+            start,
+            dec!(100.0),
+            dec!(100.0),
+        )));
+        curve.update(&update(start + Duration::minutes(30), dec!(-50.0)));
+        curve.update(&update(start + Duration::hours(1), dec!(-20.0)));
+        curve.update(&update(start + Duration::hours(23), dec!(10.0)));
+
+        // Day 2: closes -19%
+        curve.update(&update(start + Duration::hours(25), dec!(-1.0)));
+        curve.update(&update(start + Duration::hours(47), dec!(-11.0)));
+
+        let equity = curve
+            .points()
+            .iter()
+            .map(|point| point.total)
+            .collect::<Vec<_>>();
+        assert_eq!(equity, vec![100.0, 80.0, 110.0, 99.0, 89.0]);
+
+        let statistics = curve.statistics();
+        assert_eq!(statistics.days, 2);
+        assert!((statistics.total_return - -0.11).abs() < 1e-9);
+        assert!((statistics.max_drawdown - -0.2).abs() < 1e-9);
+        assert!(statistics.daily_volatility > 0.0);
+
+        let csv = curve.to_csv();
+        assert_eq!(csv.lines().count(), 6);
+        assert!(csv.starts_with("time,equity,drawdown\n2023-01-01T00:00:00+00:00,100,0\n"));
+    }
+
+    #[test]
+    fn equity_recorders_merge_into_curve_in_time_order() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let config = Config {
+            starting_equity: 100.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            sampling: Sampling::Interval(Duration::hours(1)),
+        };
+
+        // Unrealised updates within the same interval are collapsed into the latest
+        let mut first = EquityRecorder::new(config);
+        first.record(&update(start + Duration::minutes(10), dec!(-50.0)));
+        first.record(&update(start + Duration::minutes(50), dec!(-5.0)));
+        first.record(&update(start + Duration::hours(2), dec!(5.0)));
+        assert_eq!(first.updates.len(), 2);
+
+        let mut second = EquityRecorder::new(config);
+        second.record(&Event::Balance(Balance::new(
+            start,
+            dec!(100.0),
+            dec!(100.0),
+        )));
+        second.record(&Event::Balance(Balance::new(
+            start + Duration::hours(3),
+            dec!(120.0),
+            dec!(120.0),
+        )));
+
+        first.merge(second);
+        let equity = first
+            .into_curve()
+            .points()
+            .iter()
+            .map(|point| point.total)
+            .collect::<Vec<_>>();
+        assert_eq!(equity, vec![100.0, 105.0, 125.0]);
+    }
+}
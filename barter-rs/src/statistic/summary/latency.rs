@@ -0,0 +1,202 @@
+use crate::{
+    event::{EventTrace, LatencyStage},
+    statistic::summary::{combine, TableBuilder},
+};
+use chrono::Duration;
+use hdrhistogram::Histogram;
+use prettytable::{Row, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map::Entry, BTreeMap};
+
+/// Number of significant figures maintained by each [`LatencySummary`] histogram.
+const SIGNIFICANT_FIGURES: u8 = 3;
+
+/// Histograms of the microsecond latencies recorded for each [`LatencyStage`] of the
+/// [`Event`](crate::event::Event) chain, see [`EventTrace`]. Used to determine where the
+/// tick-to-trade time of a trading session goes.
+///
+/// Note that latencies relative to the exchange & received timestamps are only meaningful whilst
+/// live-trading, since historical market events carry historical timestamps.
+#[derive(Clone, Debug, Default)]
+pub struct LatencySummary {
+    stages: BTreeMap<LatencyStage, Histogram<u64>>,
+}
+
+/// Statistical summary of the microsecond latencies recorded for a [`LatencyStage`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LatencyStatistics {
+    pub count: u64,
+    pub mean: f64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+impl LatencySummary {
+    /// Constructs a new [`LatencySummary`] without any recorded latencies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latency of a [`LatencyStage`]. Negative latencies (eg/ due to exchange clock
+    /// skew) cannot be represented & are ignored.
+    pub fn record(&mut self, stage: LatencyStage, latency: Duration) {
+        let micros = match latency.num_microseconds() {
+            Some(micros) if micros >= 0 => micros as u64,
+            _ => return,
+        };
+
+        self.stages
+            .entry(stage)
+            .or_insert_with(|| {
+                Histogram::new(SIGNIFICANT_FIGURES)
+                    .expect("LatencySummary histogram significant figures are valid")
+            })
+            .saturating_record(micros);
+    }
+
+    /// Merge the latencies recorded by another [`LatencySummary`] (eg/ of another
+    /// [`Trader`](crate::engine::trader::Trader)) into this one.
+    pub fn merge(&mut self, other: LatencySummary) {
+        for (stage, histogram) in other.stages {
+            match self.stages.entry(stage) {
+                Entry::Vacant(entry) => {
+                    entry.insert(histogram);
+                }
+                Entry::Occupied(mut entry) => entry
+                    .get_mut()
+                    .add(histogram)
+                    .expect("LatencySummary histograms are auto-resizing"),
+            }
+        }
+    }
+
+    /// Record the latencies of the provided [`LatencyStage`]s that the [`EventTrace`] has both
+    /// timestamps for.
+    pub fn record_trace(&mut self, trace: &EventTrace, stages: &[LatencyStage]) {
+        for stage in stages {
+            if let Some(latency) = trace.latency(*stage) {
+                self.record(*stage, latency);
+            }
+        }
+    }
+
+    /// Returns the [`LatencyStatistics`] of a [`LatencyStage`], if any latencies have been
+    /// recorded for it.
+    pub fn statistics(&self, stage: LatencyStage) -> Option<LatencyStatistics> {
+        self.stages.get(&stage).map(LatencyStatistics::from)
+    }
+
+    /// Determines if no latencies have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Generates a [`Table`] with a row of [`LatencyStatistics`] for every [`LatencyStage`] that
+    /// has recorded latencies.
+    pub fn table(&self) -> Table {
+        combine(
+            self.stages
+                .iter()
+                .map(|(stage, histogram)| (stage.to_string(), LatencyStatistics::from(histogram))),
+        )
+    }
+}
+
+impl From<&Histogram<u64>> for LatencyStatistics {
+    fn from(histogram: &Histogram<u64>) -> Self {
+        Self {
+            count: histogram.len(),
+            mean: histogram.mean(),
+            p50: histogram.value_at_quantile(0.5),
+            p90: histogram.value_at_quantile(0.9),
+            p99: histogram.value_at_quantile(0.99),
+            max: histogram.max(),
+        }
+    }
+}
+
+impl TableBuilder for LatencyStatistics {
+    fn titles(&self) -> Row {
+        row![
+            "Count",
+            "Mean (us)",
+            "p50 (us)",
+            "p90 (us)",
+            "p99 (us)",
+            "Max (us)",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.count.to_string(),
+            format!("{:.1}", self.mean),
+            self.p50.to_string(),
+            self.p90.to_string(),
+            self.p99.to_string(),
+            self.max.to_string(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn latency_summary_records_stamped_trace_stages() {
+        let exchange_time = Utc::now();
+        let trace = EventTrace {
+            correlation_id: Uuid::new_v4(),
+            exchange_time,
+            received_time: exchange_time + Duration::microseconds(100),
+            signal_time: Some(exchange_time + Duration::microseconds(150)),
+            order_time: None,
+            fill_time: None,
+        };
+
+        let mut summary = LatencySummary::new();
+        summary.record_trace(&trace, &LatencyStage::ALL);
+        summary.record(LatencyStage::ExchangeToReceive, Duration::microseconds(300));
+        summary.record(LatencyStage::ExchangeToReceive, Duration::microseconds(-5));
+
+        let exchange_to_receive = summary.statistics(LatencyStage::ExchangeToReceive).unwrap();
+        assert_eq!(exchange_to_receive.count, 2);
+        assert_eq!(exchange_to_receive.mean, 200.0);
+        assert_eq!(exchange_to_receive.max, 300);
+
+        let receive_to_signal = summary.statistics(LatencyStage::ReceiveToSignal).unwrap();
+        assert_eq!(receive_to_signal.count, 1);
+        assert_eq!(receive_to_signal.p50, 50);
+
+        assert!(summary.statistics(LatencyStage::SignalToOrder).is_none());
+        assert!(summary.statistics(LatencyStage::TickToTrade).is_none());
+    }
+
+    #[test]
+    fn latency_summary_merges_histograms_of_every_stage() {
+        let mut summary = LatencySummary::new();
+        summary.record(LatencyStage::ExchangeToReceive, Duration::microseconds(100));
+
+        let mut other = LatencySummary::new();
+        other.record(LatencyStage::ExchangeToReceive, Duration::microseconds(300));
+        other.record(LatencyStage::SignalToOrder, Duration::microseconds(20));
+
+        summary.merge(other);
+
+        let exchange_to_receive = summary.statistics(LatencyStage::ExchangeToReceive).unwrap();
+        assert_eq!(exchange_to_receive.count, 2);
+        assert_eq!(exchange_to_receive.mean, 200.0);
+        assert_eq!(
+            summary
+                .statistics(LatencyStage::SignalToOrder)
+                .unwrap()
+                .count,
+            1
+        );
+    }
+}
@@ -1,6 +1,11 @@
+pub mod attribution;
+pub mod benchmark;
 pub mod data;
 pub mod drawdown;
+pub mod equity;
+pub mod latency;
 pub mod pnl;
+pub mod trade;
 pub mod trading;
 
 use crate::portfolio::position::Position;
@@ -11,7 +16,7 @@ pub trait Initialiser {
     fn init(config: Self::Config) -> Self;
 }
 
-pub trait PositionSummariser: Copy {
+pub trait PositionSummariser: Clone {
     fn update(&mut self, position: &Position);
     fn generate_summary(&mut self, positions: &[Position]) {
         for position in positions.iter() {
@@ -1,13 +1,14 @@
 use crate::{
     portfolio::position::Position,
     statistic::{
-        de_duration_from_secs, se_duration_as_secs,
+        de_duration_from_secs, decimal_to_f64, se_duration_as_secs,
         summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
     },
 };
 use barter_integration::model::Side;
 use chrono::{DateTime, Duration, Utc};
 use prettytable::Row;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -55,7 +56,7 @@ impl PositionSummariser for PnLReturnSummary {
         self.update_trades_per_day();
 
         // Calculate the Position PnL Return
-        let pnl_return = position.calculate_profit_loss_return();
+        let pnl_return = decimal_to_f64(position.calculate_profit_loss_return());
 
         // Update Total PnL Returns
         self.total.update(pnl_return);
@@ -131,33 +132,42 @@ impl PnLReturnSummary {
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct ProfitLossSummary {
-    pub long_contracts: f64,
-    pub long_pnl: f64,
-    pub long_pnl_per_contract: f64,
-    pub short_contracts: f64,
-    pub short_pnl: f64,
-    pub short_pnl_per_contract: f64,
-    pub total_contracts: f64,
-    pub total_pnl: f64,
-    pub total_pnl_per_contract: f64,
+    pub long_contracts: Decimal,
+    pub long_pnl: Decimal,
+    pub long_pnl_per_contract: Decimal,
+    pub short_contracts: Decimal,
+    pub short_pnl: Decimal,
+    pub short_pnl_per_contract: Decimal,
+    pub total_contracts: Decimal,
+    pub total_pnl: Decimal,
+    pub total_pnl_per_contract: Decimal,
 }
 
 impl PositionSummariser for ProfitLossSummary {
     fn update(&mut self, position: &Position) {
         self.total_contracts += position.quantity.abs();
         self.total_pnl += position.realised_profit_loss;
-        self.total_pnl_per_contract = self.total_pnl / self.total_contracts;
+        self.total_pnl_per_contract = self
+            .total_pnl
+            .checked_div(self.total_contracts)
+            .unwrap_or_default();
 
         match position.side {
             Side::Buy => {
                 self.long_contracts += position.quantity.abs();
                 self.long_pnl += position.realised_profit_loss;
-                self.long_pnl_per_contract = self.long_pnl / self.long_contracts;
+                self.long_pnl_per_contract = self
+                    .long_pnl
+                    .checked_div(self.long_contracts)
+                    .unwrap_or_default();
             }
             Side::Sell => {
                 self.short_contracts += position.quantity.abs();
                 self.short_pnl += position.realised_profit_loss;
-                self.short_pnl_per_contract = self.short_pnl / self.short_contracts;
+                self.short_pnl_per_contract = self
+                    .short_pnl
+                    .checked_div(self.short_contracts)
+                    .unwrap_or_default();
             }
         }
     }
@@ -238,8 +248,8 @@ mod tests {
         let mut input_position = position();
         input_position.meta.exit_balance = Some(Balance {
             time: base_time.checked_add_signed(Duration::days(15)).unwrap(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         });
 
         pnl_return_view.update_trading_session_duration(&input_position);
@@ -0,0 +1,450 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        de_duration_from_secs, decimal_to_f64, divide, se_duration_as_secs,
+        summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::{Cell, Row};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for initialising a [`TradeSummary`] via the init() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub starting_equity: f64,
+}
+
+/// Trade-level statistics of exited [`Position`]s, extending the
+/// [`TearSheet`](super::trading::TearSheet) ratios.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct TradeSummary {
+    pub win_loss: WinLossSummary,
+    pub streaks: StreakSummary,
+    pub exposure: ExposureSummary,
+    pub fees: FeeSummary,
+    pub excursions: ExcursionSummary,
+}
+
+impl Initialiser for TradeSummary {
+    type Config = Config;
+
+    fn init(config: Self::Config) -> Self {
+        Self {
+            win_loss: WinLossSummary::default(),
+            streaks: StreakSummary::default(),
+            exposure: ExposureSummary::new(config.starting_equity),
+            fees: FeeSummary::default(),
+            excursions: ExcursionSummary::default(),
+        }
+    }
+}
+
+impl PositionSummariser for TradeSummary {
+    fn update(&mut self, position: &Position) {
+        self.win_loss.update(position);
+        self.streaks.update(position);
+        self.exposure.update(position);
+        self.fees.update(position);
+        self.excursions.update(position);
+    }
+}
+
+impl TableBuilder for TradeSummary {
+    fn titles(&self) -> Row {
+        Row::new(
+            [
+                self.win_loss.titles(),
+                self.streaks.titles(),
+                self.exposure.titles(),
+                self.fees.titles(),
+                self.excursions.titles(),
+            ]
+            .iter()
+            .flat_map(|titles| titles.iter().cloned())
+            .collect::<Vec<Cell>>(),
+        )
+    }
+
+    fn row(&self) -> Row {
+        Row::new(
+            [
+                self.win_loss.row(),
+                self.streaks.row(),
+                self.exposure.row(),
+                self.fees.row(),
+                self.excursions.row(),
+            ]
+            .iter()
+            .flat_map(|row| row.iter().cloned())
+            .collect::<Vec<Cell>>(),
+        )
+    }
+}
+
+/// Win rate, profit factor, expectancy & average win/loss of exited [`Position`]s, measured
+/// using their realised PnL.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct WinLossSummary {
+    pub wins: DataSummary,
+    pub losses: DataSummary,
+    /// Fraction of [`Position`]s that were winners.
+    pub win_rate: f64,
+    /// Gross profit of the winners / absolute gross loss of the losers.
+    pub profit_factor: f64,
+    /// Mean realised PnL per [`Position`].
+    pub expectancy: f64,
+    /// Average win / absolute average loss.
+    pub win_loss_ratio: f64,
+}
+
+impl PositionSummariser for WinLossSummary {
+    fn update(&mut self, position: &Position) {
+        let profit_loss = decimal_to_f64(position.realised_profit_loss);
+        if profit_loss > 0.0 {
+            self.wins.update(profit_loss);
+        } else {
+            self.losses.update(profit_loss);
+        }
+
+        let trades = (self.wins.count + self.losses.count) as f64;
+        self.win_rate = self.wins.count as f64 / trades;
+        self.profit_factor = divide(self.wins.sum, self.losses.sum.abs());
+        self.expectancy = (self.wins.sum + self.losses.sum) / trades;
+        self.win_loss_ratio = divide(self.wins.mean, self.losses.mean.abs());
+    }
+}
+
+impl TableBuilder for WinLossSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Win Rate",
+            "Profit Factor",
+            "Expectancy",
+            "Avg. Win",
+            "Avg. Loss",
+            "Win/Loss Ratio",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.win_rate),
+            format!("{:.3}", self.profit_factor),
+            format!("{:.3}", self.expectancy),
+            format!("{:.3}", self.wins.mean),
+            format!("{:.3}", self.losses.mean),
+            format!("{:.3}", self.win_loss_ratio),
+        ]
+    }
+}
+
+/// Current & longest consecutive runs of winning & losing [`Position`]s.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Deserialize, Serialize)]
+pub struct StreakSummary {
+    pub current_wins: u64,
+    pub current_losses: u64,
+    pub longest_wins: u64,
+    pub longest_losses: u64,
+}
+
+impl PositionSummariser for StreakSummary {
+    fn update(&mut self, position: &Position) {
+        if position.realised_profit_loss > Decimal::ZERO {
+            self.current_wins += 1;
+            self.current_losses = 0;
+            self.longest_wins = self.longest_wins.max(self.current_wins);
+        } else {
+            self.current_losses += 1;
+            self.current_wins = 0;
+            self.longest_losses = self.longest_losses.max(self.current_losses);
+        }
+    }
+}
+
+impl TableBuilder for StreakSummary {
+    fn titles(&self) -> Row {
+        row!["Longest Win Streak", "Longest Loss Streak"]
+    }
+
+    fn row(&self) -> Row {
+        row![self.longest_wins, self.longest_losses]
+    }
+}
+
+/// Holding time, time-in-market exposure & turnover of exited [`Position`]s.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ExposureSummary {
+    pub starting_equity: f64,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// Total time spent holding [`Position`]s.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub holding_time: Duration,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub avg_holding_time: Duration,
+    /// Time spent holding at least one [`Position`] / trading session duration. Overlapping
+    /// [`Position`]s are only counted once, so this never exceeds 1.0.
+    pub exposure: f64,
+    /// Total gross value of every enter & exit.
+    pub traded_value: f64,
+    /// Traded value / starting equity.
+    pub turnover: f64,
+    positions: u64,
+    /// Sorted & disjoint union of every [`Position`] holding interval.
+    #[serde(default)]
+    holding_intervals: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+impl PositionSummariser for ExposureSummary {
+    fn update(&mut self, position: &Position) {
+        let exit_time = position
+            .meta
+            .exit_balance
+            .map_or(position.meta.update_time, |balance| balance.time);
+
+        let enter_time = position.meta.enter_time;
+        let start_time = self
+            .start_time
+            .map_or(enter_time, |start| start.min(enter_time));
+        let end_time = self.end_time.map_or(exit_time, |end| end.max(exit_time));
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+
+        self.positions += 1;
+        self.holding_time += exit_time - enter_time;
+        self.avg_holding_time = self.holding_time / self.positions as i32;
+
+        self.insert_holding_interval(enter_time, exit_time);
+        let time_in_market = self
+            .holding_intervals
+            .iter()
+            .fold(Duration::zero(), |total, (start, end)| {
+                total + (*end - *start)
+            });
+
+        let session = (end_time - start_time).num_seconds() as f64;
+        self.exposure = divide(time_in_market.num_seconds() as f64, session);
+
+        self.traded_value += decimal_to_f64(position.enter_value_gross + position.exit_value_gross);
+        self.turnover = divide(self.traded_value, self.starting_equity);
+    }
+}
+
+impl TableBuilder for ExposureSummary {
+    fn titles(&self) -> Row {
+        row!["Avg. Holding Time (mins)", "Exposure", "Turnover"]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.avg_holding_time.num_minutes().to_string(),
+            format!("{:.3}", self.exposure),
+            format!("{:.3}", self.turnover),
+        ]
+    }
+}
+
+impl ExposureSummary {
+    pub fn new(starting_equity: f64) -> Self {
+        Self {
+            starting_equity,
+            start_time: None,
+            end_time: None,
+            holding_time: Duration::zero(),
+            avg_holding_time: Duration::zero(),
+            exposure: 0.0,
+            traded_value: 0.0,
+            turnover: 0.0,
+            positions: 0,
+            holding_intervals: Vec::new(),
+        }
+    }
+
+    /// Merge the provided holding interval into the sorted & disjoint union of holding intervals.
+    fn insert_holding_interval(&mut self, enter_time: DateTime<Utc>, exit_time: DateTime<Utc>) {
+        let (mut start, mut end) = (enter_time, exit_time);
+
+        // Absorb every existing interval that overlaps or touches the new interval
+        self.holding_intervals
+            .retain(|&(existing_start, existing_end)| {
+                if existing_end < start || existing_start > end {
+                    true
+                } else {
+                    start = start.min(existing_start);
+                    end = end.max(existing_end);
+                    false
+                }
+            });
+
+        let index = self
+            .holding_intervals
+            .partition_point(|&(existing_start, _)| existing_start < start);
+        self.holding_intervals.insert(index, (start, end));
+    }
+}
+
+impl Default for ExposureSummary {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+/// Fees paid by exited [`Position`]s relative to their gross (pre-fee) PnL.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FeeSummary {
+    pub fees_total: f64,
+    pub gross_profit_loss: f64,
+    /// Total fees / absolute gross PnL, as a percentage.
+    pub fees_pct_of_gross: f64,
+}
+
+impl PositionSummariser for FeeSummary {
+    fn update(&mut self, position: &Position) {
+        let fees = position.enter_fees_total + position.exit_fees_total;
+        self.fees_total += decimal_to_f64(fees);
+        self.gross_profit_loss += decimal_to_f64(position.realised_profit_loss + fees);
+        self.fees_pct_of_gross = divide(self.fees_total, self.gross_profit_loss.abs()) * 100.0;
+    }
+}
+
+impl TableBuilder for FeeSummary {
+    fn titles(&self) -> Row {
+        row!["Fees", "Fees % Gross PnL"]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.fees_total),
+            format!("{:.3}", self.fees_pct_of_gross),
+        ]
+    }
+}
+
+/// Maximum adverse (MAE) & favourable (MFE) excursions of exited [`Position`]s, see
+/// [`PositionMeta`](crate::portfolio::position::PositionMeta).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ExcursionSummary {
+    pub adverse: DataSummary,
+    pub favourable: DataSummary,
+}
+
+impl PositionSummariser for ExcursionSummary {
+    fn update(&mut self, position: &Position) {
+        self.adverse
+            .update(decimal_to_f64(position.meta.max_adverse_excursion));
+        self.favourable
+            .update(decimal_to_f64(position.meta.max_favourable_excursion));
+    }
+}
+
+impl TableBuilder for ExcursionSummary {
+    fn titles(&self) -> Row {
+        row!["Avg. MAE", "Worst MAE", "Avg. MFE", "Best MFE"]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.adverse.mean),
+            format!("{:.3}", self.adverse.dispersion.range.low),
+            format!("{:.3}", self.favourable.mean),
+            format!("{:.3}", self.favourable.dispersion.range.high),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use rust_decimal_macros::dec;
+
+    fn exited(
+        enter_minute: i64,
+        exit_minute: i64,
+        realised_profit_loss: Decimal,
+        excursions: (Decimal, Decimal),
+    ) -> Position {
+        let base_time = Utc::now();
+        let mut position = position();
+        position.meta.enter_time = base_time + Duration::minutes(enter_minute);
+        position.meta.exit_balance = Some(Balance::new(
+            base_time + Duration::minutes(exit_minute),
+            Decimal::ZERO,
+            Decimal::ZERO,
+        ));
+        position.meta.max_adverse_excursion = excursions.0;
+        position.meta.max_favourable_excursion = excursions.1;
+        position.enter_value_gross = dec!(100.0);
+        position.exit_value_gross = dec!(100.0) + realised_profit_loss;
+        position.enter_fees_total = dec!(1.0);
+        position.exit_fees_total = dec!(1.0);
+        position.realised_profit_loss = realised_profit_loss;
+        position
+    }
+
+    #[test]
+    fn trade_summary_generates_trade_level_statistics() {
+        let mut summary = TradeSummary::init(Config {
+            starting_equity: 1000.0,
+        });
+
+        summary.generate_summary(&[
+            exited(0, 10, dec!(30.0), (dec!(-5.0), dec!(40.0))),
+            exited(10, 20, dec!(10.0), (dec!(0.0), dec!(10.0))),
+            exited(30, 40, dec!(-10.0), (dec!(-20.0), dec!(5.0))),
+            exited(40, 60, dec!(-5.0), (dec!(-5.0), dec!(0.0))),
+            exited(60, 80, dec!(-5.0), (dec!(-10.0), dec!(0.0))),
+            exited(90, 100, dec!(20.0), (dec!(0.0), dec!(20.0))),
+        ]);
+
+        // Win/loss
+        assert_eq!(summary.win_loss.win_rate, 0.5);
+        assert_eq!(summary.win_loss.profit_factor, 60.0 / 20.0);
+        assert_eq!(summary.win_loss.expectancy, 40.0 / 6.0);
+        assert_eq!(summary.win_loss.win_loss_ratio, 20.0 / (20.0 / 3.0));
+
+        // Streaks
+        assert_eq!(summary.streaks.longest_wins, 2);
+        assert_eq!(summary.streaks.longest_losses, 3);
+        assert_eq!(summary.streaks.current_wins, 1);
+
+        // Exposure: 80 of 100 session minutes in market
+        assert_eq!(summary.exposure.avg_holding_time, Duration::seconds(800));
+        assert_eq!(summary.exposure.exposure, 0.8);
+        assert_eq!(summary.exposure.turnover, 1.24);
+
+        // Fees: 12.0 of 52.0 gross PnL
+        assert_eq!(summary.fees.fees_total, 12.0);
+        assert_eq!(summary.fees.fees_pct_of_gross, 12.0 / 52.0 * 100.0);
+
+        // Excursions
+        assert_eq!(summary.excursions.adverse.dispersion.range.low, -20.0);
+        assert_eq!(summary.excursions.favourable.dispersion.range.high, 40.0);
+        assert_eq!(summary.excursions.favourable.mean, 12.5);
+
+        assert_eq!(summary.titles().len(), summary.row().len());
+    }
+
+    #[test]
+    fn exposure_summary_counts_overlapping_positions_once() {
+        let mut summary = ExposureSummary::new(1000.0);
+
+        // Two markets in Position over the same 0-60 minutes, then a third over 80-100 minutes
+        summary.generate_summary(&[
+            exited(0, 60, dec!(0.0), (dec!(0.0), dec!(0.0))),
+            exited(80, 100, dec!(0.0), (dec!(0.0), dec!(0.0))),
+            exited(10, 50, dec!(0.0), (dec!(0.0), dec!(0.0))),
+        ]);
+
+        assert_eq!(summary.holding_time, Duration::minutes(120));
+        assert_eq!(summary.exposure, 0.8);
+    }
+}
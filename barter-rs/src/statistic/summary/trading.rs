@@ -3,8 +3,8 @@ use crate::{
     statistic::{
         metric::ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
         summary::{
-            drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser, PositionSummariser,
-            TableBuilder,
+            drawdown::DrawdownSummary, pnl::PnLReturnSummary, trade, trade::TradeSummary,
+            Initialiser, PositionSummariser, TableBuilder,
         },
     },
 };
@@ -20,11 +20,13 @@ pub struct Config {
     pub risk_free_return: f64,
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct TradingSummary {
     pub pnl_returns: PnLReturnSummary,
     pub drawdown: DrawdownSummary,
     pub tear_sheet: TearSheet,
+    #[serde(default)]
+    pub trades: TradeSummary,
 }
 
 impl Initialiser for TradingSummary {
@@ -35,6 +37,9 @@ impl Initialiser for TradingSummary {
             pnl_returns: PnLReturnSummary::new(),
             drawdown: DrawdownSummary::new(config.starting_equity),
             tear_sheet: TearSheet::new(config.risk_free_return),
+            trades: TradeSummary::init(trade::Config {
+                starting_equity: config.starting_equity,
+            }),
         }
     }
 }
@@ -44,6 +49,7 @@ impl PositionSummariser for TradingSummary {
         self.pnl_returns.update(position);
         self.drawdown.update(position);
         self.tear_sheet.update(&self.pnl_returns, &self.drawdown);
+        self.trades.update(position);
     }
 }
 
@@ -63,6 +69,10 @@ impl TableBuilder for TradingSummary {
             titles.push(title.clone())
         }
 
+        for title in &self.trades.titles() {
+            titles.push(title.clone())
+        }
+
         Row::new(titles)
     }
 
@@ -81,6 +91,10 @@ impl TableBuilder for TradingSummary {
             cells.push(cell.clone())
         }
 
+        for cell in &self.trades.row() {
+            cells.push(cell.clone())
+        }
+
         Row::new(cells)
     }
 }
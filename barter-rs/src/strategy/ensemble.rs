@@ -0,0 +1,276 @@
+use super::{error::StrategyError, Decision, Signal, SignalGenerator, SignalStrength};
+use crate::data::Attribution;
+use barter_data::event::{DataKind, MarketEvent};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug};
+
+/// Method used by an [`Ensemble`] to combine the [`Signal`]s of it's members.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Aggregation {
+    /// Each member votes for the [`Decision`]s in it's [`Signal`]. A [`Decision`] is endorsed if
+    /// the fraction of members voting for it is at least the quorum, with that fraction as it's
+    /// [`SignalStrength`].
+    Vote { quorum: f64 },
+    /// The [`SignalStrength`] of each [`Decision`] is the weighted average across every member,
+    /// where members that did not endorse it contribute zero. A [`Decision`] is endorsed if it's
+    /// [`SignalStrength`] is at least the threshold.
+    WeightedAverage { threshold: f64 },
+    /// The [`Signal`] of the first member (in priority order) that generates one is used as is.
+    Priority,
+}
+
+/// Child strategy of an [`Ensemble`].
+pub struct EnsembleMember {
+    /// Name used to attribute PnL to this member, see [`Attribution`].
+    pub name: String,
+    /// Weight of this member when using [`Aggregation::WeightedAverage`].
+    pub weight: f64,
+    pub strategy: Box<dyn SignalGenerator + Send>,
+}
+
+impl Debug for EnsembleMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnsembleMember")
+            .field("name", &self.name)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EnsembleMember {
+    /// Constructs a new [`EnsembleMember`] using the provided configuration.
+    pub fn new<Strategy>(name: impl Into<String>, weight: f64, strategy: Strategy) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            weight,
+            strategy: Box::new(strategy),
+        }
+    }
+}
+
+/// [`SignalGenerator`] that runs several member strategies for a market & combines their
+/// [`Signal`]s using an [`Aggregation`] method. Every member sees every [`MarketEvent`], so each
+/// remains warm regardless of the [`Aggregation`].
+///
+/// The generated [`Signal`] records the [`Attribution`] of the members that endorsed it's
+/// [`Decision`]s in it's [`MarketMeta`](crate::data::MarketMeta).
+#[derive(Debug)]
+pub struct Ensemble {
+    members: Vec<EnsembleMember>,
+    aggregation: Aggregation,
+}
+
+impl SignalGenerator for Ensemble {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        let signals = self
+            .members
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, member)| {
+                member
+                    .strategy
+                    .generate_signal(market)
+                    .map(|signal| (index, signal))
+            })
+            .collect::<Vec<_>>();
+
+        let (mut signal, attribution) = match self.aggregation {
+            Aggregation::Priority => {
+                let (index, signal) = signals.into_iter().next()?;
+                let mut attribution = Attribution::default();
+                attribution.insert(index);
+                (signal, attribution)
+            }
+            Aggregation::Vote { quorum } => {
+                combine(signals, self.members.len() as f64, quorum, |_, _| 1.0)?
+            }
+            Aggregation::WeightedAverage { threshold } => {
+                let total_weight = self.members.iter().map(|member| member.weight).sum();
+                combine(signals, total_weight, threshold, |index, strength| {
+                    self.members[index].weight * strength.0
+                })?
+            }
+        };
+
+        signal.market_meta.attribution = Some(attribution);
+        Some(signal)
+    }
+}
+
+impl Ensemble {
+    /// Constructs a new [`Ensemble`] of the provided members, in priority order.
+    pub fn new(
+        members: Vec<EnsembleMember>,
+        aggregation: Aggregation,
+    ) -> Result<Self, StrategyError> {
+        if members.is_empty() || members.len() > Attribution::MAX_MEMBERS {
+            return Err(StrategyError::InvalidEnsemble(format!(
+                "requires between 1 & {} members, but found {}",
+                Attribution::MAX_MEMBERS,
+                members.len()
+            )));
+        }
+
+        if matches!(aggregation, Aggregation::WeightedAverage { .. })
+            && members.iter().map(|member| member.weight).sum::<f64>() <= 0.0
+        {
+            return Err(StrategyError::InvalidEnsemble(
+                "member weights must sum to a positive value".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            members,
+            aggregation,
+        })
+    }
+
+    /// Returns the name of every member, indexed consistently with [`Attribution`].
+    pub fn member_names(&self) -> Vec<String> {
+        self.members
+            .iter()
+            .map(|member| member.name.clone())
+            .collect()
+    }
+}
+
+/// Combine the member [`Signal`]s by weighing each [`Decision`] they endorse, returning the
+/// [`Decision`]s with a [`SignalStrength`] (total weight / maximum total weight) of at least the
+/// threshold, alongside the [`Attribution`] of the members that endorsed them.
+fn combine<Weigh>(
+    signals: Vec<(usize, Signal)>,
+    total_weight: f64,
+    threshold: f64,
+    weigh: Weigh,
+) -> Option<(Signal, Attribution)>
+where
+    Weigh: Fn(usize, SignalStrength) -> f64,
+{
+    let mut strengths = HashMap::<Decision, f64>::with_capacity(4);
+    for (index, signal) in &signals {
+        for (decision, strength) in &signal.signals {
+            *strengths.entry(*decision).or_default() += weigh(*index, *strength);
+        }
+    }
+
+    let endorsed = strengths
+        .into_iter()
+        .map(|(decision, strength)| (decision, SignalStrength(strength / total_weight)))
+        .filter(|(_, strength)| strength.0 > 0.0 && strength.0 >= threshold)
+        .collect::<HashMap<_, _>>();
+
+    let mut attribution = Attribution::default();
+    let mut combined = None;
+    for (index, signal) in signals {
+        if signal
+            .signals
+            .keys()
+            .any(|decision| endorsed.contains_key(decision))
+        {
+            attribution.insert(index);
+            combined.get_or_insert(signal);
+        }
+    }
+
+    let mut combined = combined?;
+    combined.signals = endorsed;
+    Some((combined, attribution))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_trade, signal};
+    use barter_integration::model::Side;
+
+    /// Always generates a [`Signal`] endorsing the provided [`Decision`]s.
+    struct Fixed(Vec<(Decision, f64)>);
+
+    impl SignalGenerator for Fixed {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            (!self.0.is_empty()).then(|| Signal {
+                signals: self
+                    .0
+                    .iter()
+                    .map(|(decision, strength)| (*decision, SignalStrength(*strength)))
+                    .collect(),
+                ..signal()
+            })
+        }
+    }
+
+    fn ensemble(aggregation: Aggregation) -> Ensemble {
+        Ensemble::new(
+            vec![
+                EnsembleMember::new("silent", 1.0, Fixed(vec![])),
+                EnsembleMember::new("trend", 3.0, Fixed(vec![(Decision::Long, 1.0)])),
+                EnsembleMember::new("mean_reversion", 1.0, Fixed(vec![(Decision::Short, 1.0)])),
+                EnsembleMember::new("momentum", 1.0, Fixed(vec![(Decision::Long, 0.5)])),
+            ],
+            aggregation,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ensemble_combines_member_signals_with_attribution() {
+        struct TestCase {
+            aggregation: Aggregation,
+            expected: Option<(Vec<(Decision, f64)>, Vec<usize>)>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Long endorsed by half the members meets quorum, Short does not
+                aggregation: Aggregation::Vote { quorum: 0.5 },
+                expected: Some((vec![(Decision::Long, 0.5)], vec![1, 3])),
+            },
+            TestCase {
+                // TC1: No Decision endorsed by enough members
+                aggregation: Aggregation::Vote { quorum: 0.75 },
+                expected: None,
+            },
+            TestCase {
+                // TC2: Long (3.0 + 0.5) / 6.0 meets threshold, Short 1.0 / 6.0 does not
+                aggregation: Aggregation::WeightedAverage { threshold: 0.5 },
+                expected: Some((vec![(Decision::Long, 3.5 / 6.0)], vec![1, 3])),
+            },
+            TestCase {
+                // TC3: First member to generate a Signal
+                aggregation: Aggregation::Priority,
+                expected: Some((vec![(Decision::Long, 1.0)], vec![1])),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = ensemble(test.aggregation)
+                .generate_signal(&market_event_trade(Side::Buy))
+                .map(|signal| {
+                    let attribution = signal.market_meta.attribution.unwrap();
+                    let mut signals = signal
+                        .signals
+                        .into_iter()
+                        .map(|(decision, strength)| (decision, strength.0))
+                        .collect::<Vec<_>>();
+                    signals.sort_by_key(|(decision, _)| *decision);
+                    (signals, attribution.members().collect::<Vec<_>>())
+                });
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn ensemble_rejects_invalid_members() {
+        assert!(Ensemble::new(vec![], Aggregation::Priority).is_err());
+        assert!(Ensemble::new(
+            vec![EnsembleMember::new("zero", 0.0, Fixed(vec![]))],
+            Aggregation::WeightedAverage { threshold: 0.0 }
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// All errors generated in the barter::strategy module.
+#[derive(Error, Debug)]
+pub enum StrategyError {
+    #[error("Invalid configuration for indicator {name}: {source}")]
+    InvalidIndicator {
+        name: String,
+        source: ta::errors::TaError,
+    },
+
+    #[error("Duplicate feature name: {0}")]
+    DuplicateFeature(String),
+
+    #[error("Failed to read script {path:?}: {source}")]
+    ScriptIo {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse script line {line}: {reason}")]
+    ScriptParse { line: usize, reason: String },
+
+    #[error("Invalid ensemble: {0}")]
+    InvalidEnsemble(String),
+
+    #[error("Script requires {operations} operations, exceeding the limit of {max_operations}")]
+    ScriptTooExpensive {
+        operations: usize,
+        max_operations: usize,
+    },
+}
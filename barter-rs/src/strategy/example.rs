@@ -2,6 +2,7 @@ use super::{Decision, Signal, SignalGenerator, SignalStrength};
 use crate::data::MarketMeta;
 use barter_data::event::{DataKind, MarketEvent};
 use chrono::Utc;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ta::{indicators::RelativeStrengthIndex, Next};
@@ -42,8 +43,10 @@ impl SignalGenerator for RSIStrategy {
             exchange: market.exchange.clone(),
             instrument: market.instrument.clone(),
             market_meta: MarketMeta {
-                close: candle_close,
+                close: Decimal::from_f64(candle_close)?,
                 time: market.exchange_time,
+                trace: None,
+                attribution: None,
             },
             signals,
         })
@@ -0,0 +1,179 @@
+use super::{
+    error::StrategyError,
+    indicator::{Indicator, IndicatorConfig, IndicatorValue},
+};
+use barter_data::event::{DataKind, MarketEvent};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Configuration for constructing a [`FeaturePipeline`] via the new() constructor method.
+///
+/// eg/
+/// ```json
+/// {
+///     "features": [
+///         { "name": "fast", "indicator": "ema", "period": 12 },
+///         { "name": "bands", "indicator": "bollinger", "period": 20, "multiplier": 2.0 },
+///         { "name": "imbalance", "indicator": "book_imbalance" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    pub features: Vec<FeatureConfig>,
+}
+
+/// Configuration of a named [`Indicator`] computed by a [`FeaturePipeline`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FeatureConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub indicator: IndicatorConfig,
+}
+
+/// Latest value of every [`Indicator`] in a [`FeaturePipeline`], keyed by feature name.
+///
+/// [`Indicator`]s with many outputs are keyed by `<name>.<output>`, eg/ `macd.histogram` or
+/// `bands.upper`.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Features(pub BTreeMap<String, f64>);
+
+impl Features {
+    /// Returns the value of a feature, if it exists.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.0.get(name).copied()
+    }
+
+    fn insert(&mut self, name: &str, value: IndicatorValue) {
+        match value {
+            IndicatorValue::Single(value) => {
+                self.0.insert(name.to_owned(), value);
+            }
+            IndicatorValue::Macd {
+                macd,
+                signal,
+                histogram,
+            } => {
+                self.0.insert(format!("{name}.macd"), macd);
+                self.0.insert(format!("{name}.signal"), signal);
+                self.0.insert(format!("{name}.histogram"), histogram);
+            }
+            IndicatorValue::Bands {
+                upper,
+                middle,
+                lower,
+            } => {
+                self.0.insert(format!("{name}.upper"), upper);
+                self.0.insert(format!("{name}.middle"), middle);
+                self.0.insert(format!("{name}.lower"), lower);
+            }
+        }
+    }
+}
+
+/// Composable pipeline of named [`Indicator`]s that a strategy updates with every
+/// [`MarketEvent`] it receives.
+///
+/// [`Features`] are only yielded once every [`Indicator`] has finished warming up, so a
+/// strategy driven by them does not generate signals from meaningless values.
+#[derive(Clone, Debug)]
+pub struct FeaturePipeline {
+    indicators: Vec<(String, Indicator)>,
+}
+
+impl FeaturePipeline {
+    /// Constructs a new [`FeaturePipeline`] using the provided configuration struct.
+    pub fn new(config: Config) -> Result<Self, StrategyError> {
+        let mut indicators: Vec<(String, Indicator)> = Vec::with_capacity(config.features.len());
+
+        for FeatureConfig { name, indicator } in config.features {
+            if indicators.iter().any(|(existing, _)| *existing == name) {
+                return Err(StrategyError::DuplicateFeature(name));
+            }
+
+            match Indicator::new(indicator) {
+                Ok(indicator) => indicators.push((name, indicator)),
+                Err(source) => return Err(StrategyError::InvalidIndicator { name, source }),
+            }
+        }
+
+        Ok(Self { indicators })
+    }
+
+    /// Update every [`Indicator`] with the [`MarketEvent`], returning the latest [`Features`]
+    /// once every [`Indicator`] has finished warming up.
+    pub fn update(&mut self, market: &MarketEvent<DataKind>) -> Option<Features> {
+        for (_, indicator) in self.indicators.iter_mut() {
+            indicator.update(&market.kind);
+        }
+
+        self.features()
+    }
+
+    /// Returns the latest [`Features`], if every [`Indicator`] has finished warming up.
+    pub fn features(&self) -> Option<Features> {
+        let mut features = Features::default();
+        for (name, indicator) in &self.indicators {
+            features.insert(name, indicator.value()?);
+        }
+
+        Some(features)
+    }
+
+    /// Determines if every [`Indicator`] has finished warming up.
+    pub fn is_ready(&self) -> bool {
+        self.indicators
+            .iter()
+            .all(|(_, indicator)| indicator.is_ready())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+
+    fn pipeline(config: &str) -> Result<FeaturePipeline, StrategyError> {
+        FeaturePipeline::new(serde_json::from_str(config).unwrap())
+    }
+
+    #[test]
+    fn feature_pipeline_yields_features_once_every_indicator_is_warm() {
+        let mut pipeline = pipeline(
+            r#"{ "features": [
+                { "name": "fast", "indicator": "sma", "period": 1 },
+                { "name": "bands", "indicator": "bollinger", "period": 2, "multiplier": 2.0 }
+            ]}"#,
+        )
+        .unwrap();
+
+        let market = market_event_trade(Side::Buy);
+        assert_eq!(pipeline.update(&market), None);
+        assert!(!pipeline.is_ready());
+
+        let features = pipeline.update(&market).unwrap();
+        assert_eq!(features.get("fast"), Some(1000.0));
+        assert_eq!(features.get("bands.middle"), Some(1000.0));
+        assert_eq!(features.get("bands.upper"), Some(1000.0));
+        assert_eq!(features.get("bands"), None);
+    }
+
+    #[test]
+    fn feature_pipeline_rejects_invalid_config() {
+        assert!(matches!(
+            pipeline(
+                r#"{ "features": [
+                    { "name": "a", "indicator": "vwap" },
+                    { "name": "a", "indicator": "book_imbalance" }
+                ]}"#
+            ),
+            Err(StrategyError::DuplicateFeature(name)) if name == "a"
+        ));
+
+        assert!(matches!(
+            pipeline(r#"{ "features": [{ "name": "slow", "indicator": "ema", "period": 0 }] }"#),
+            Err(StrategyError::InvalidIndicator { name, .. }) if name == "slow"
+        ));
+    }
+}
@@ -0,0 +1,415 @@
+use crate::statistic::decimal_to_f64;
+use barter_data::{event::DataKind, subscription::book::OrderBookL1};
+use serde::{Deserialize, Serialize};
+use ta::{
+    errors::TaError,
+    indicators::{
+        BollingerBands, ExponentialMovingAverage, MovingAverageConvergenceDivergence,
+        RelativeStrengthIndex, SimpleMovingAverage, StandardDeviation,
+    },
+    Next,
+};
+
+/// Value extracted from a [`DataKind`] to be used as the input of an [`Indicator`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Input {
+    /// Trade price, [`Candle`](barter_data::subscription::candle::Candle) close, or
+    /// [`OrderBookL1`] mid price - whichever the [`DataKind`] contains.
+    #[default]
+    Price,
+    TradePrice,
+    TradeAmount,
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+    MidPrice,
+}
+
+impl Input {
+    /// Extract the [`Input`] value from a [`DataKind`], if it contains one.
+    pub fn extract(&self, kind: &DataKind) -> Option<f64> {
+        match (self, kind) {
+            (Self::Price | Self::TradePrice, DataKind::Trade(trade)) => {
+                Some(decimal_to_f64(trade.price))
+            }
+            (Self::TradeAmount, DataKind::Trade(trade)) => Some(decimal_to_f64(trade.amount)),
+            (Self::Price | Self::Close, DataKind::Candle(candle)) => Some(candle.close),
+            (Self::Open, DataKind::Candle(candle)) => Some(candle.open),
+            (Self::High, DataKind::Candle(candle)) => Some(candle.high),
+            (Self::Low, DataKind::Candle(candle)) => Some(candle.low),
+            (Self::Volume, DataKind::Candle(candle)) => Some(candle.volume),
+            (Self::Price | Self::MidPrice, DataKind::OrderBookL1(book)) => {
+                Some(decimal_to_f64(book.mid_price()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Serde configuration of an [`Indicator`], tagged by it's `indicator` name.
+///
+/// eg/ `{ "indicator": "bollinger", "period": 20, "multiplier": 2.0, "input": "close" }`
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "indicator", rename_all = "snake_case")]
+pub enum IndicatorConfig {
+    Ema {
+        period: usize,
+        #[serde(default)]
+        input: Input,
+    },
+    Sma {
+        period: usize,
+        #[serde(default)]
+        input: Input,
+    },
+    Rsi {
+        period: usize,
+        #[serde(default)]
+        input: Input,
+    },
+    Macd {
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        #[serde(default)]
+        input: Input,
+    },
+    Bollinger {
+        period: usize,
+        multiplier: f64,
+        #[serde(default)]
+        input: Input,
+    },
+    /// Average true range of [`Candle`](barter_data::subscription::candle::Candle)s.
+    Atr { period: usize },
+    /// Volume weighted average price of every trade, or
+    /// [`Candle`](barter_data::subscription::candle::Candle) typical price, since the start of the
+    /// trading session.
+    Vwap,
+    /// Number of standard deviations the latest input is from it's rolling mean.
+    ZScore {
+        period: usize,
+        #[serde(default)]
+        input: Input,
+    },
+    /// Imbalance between the best bid & ask amounts of an [`OrderBookL1`], in the range -1 (all
+    /// ask) to 1 (all bid).
+    BookImbalance,
+}
+
+/// Latest value of an [`Indicator`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum IndicatorValue {
+    Single(f64),
+    Macd {
+        macd: f64,
+        signal: f64,
+        histogram: f64,
+    },
+    Bands {
+        upper: f64,
+        middle: f64,
+        lower: f64,
+    },
+}
+
+/// Technical indicator constructed from an [`IndicatorConfig`] that is updated with every
+/// [`DataKind`] containing it's input.
+///
+/// An [`Indicator`] is warming up until it has observed enough inputs for it's value to be
+/// meaningful (eg/ the period of a moving average), during which it yields no value.
+#[derive(Clone, Debug)]
+pub struct Indicator {
+    kind: IndicatorKind,
+    warm_up: usize,
+    observations: usize,
+    value: Option<IndicatorValue>,
+}
+
+#[derive(Clone, Debug)]
+enum IndicatorKind {
+    Ema(Input, ExponentialMovingAverage),
+    Sma(Input, SimpleMovingAverage),
+    Rsi(Input, RelativeStrengthIndex),
+    Macd(Input, MovingAverageConvergenceDivergence),
+    Bollinger(Input, BollingerBands),
+    Atr {
+        true_range: ExponentialMovingAverage,
+        prev_close: Option<f64>,
+    },
+    Vwap {
+        price_volume: f64,
+        volume: f64,
+    },
+    ZScore(Input, SimpleMovingAverage, StandardDeviation),
+    BookImbalance,
+}
+
+impl Indicator {
+    /// Constructs a new [`Indicator`] from the provided [`IndicatorConfig`].
+    pub fn new(config: IndicatorConfig) -> Result<Self, TaError> {
+        let (kind, warm_up) = match config {
+            IndicatorConfig::Ema { period, input } => (
+                IndicatorKind::Ema(input, ExponentialMovingAverage::new(period)?),
+                period,
+            ),
+            IndicatorConfig::Sma { period, input } => (
+                IndicatorKind::Sma(input, SimpleMovingAverage::new(period)?),
+                period,
+            ),
+            IndicatorConfig::Rsi { period, input } => (
+                IndicatorKind::Rsi(input, RelativeStrengthIndex::new(period)?),
+                period + 1,
+            ),
+            IndicatorConfig::Macd {
+                fast_period,
+                slow_period,
+                signal_period,
+                input,
+            } => (
+                IndicatorKind::Macd(
+                    input,
+                    MovingAverageConvergenceDivergence::new(
+                        fast_period,
+                        slow_period,
+                        signal_period,
+                    )?,
+                ),
+                slow_period + signal_period - 1,
+            ),
+            IndicatorConfig::Bollinger {
+                period,
+                multiplier,
+                input,
+            } => (
+                IndicatorKind::Bollinger(input, BollingerBands::new(period, multiplier)?),
+                period,
+            ),
+            IndicatorConfig::Atr { period } => (
+                IndicatorKind::Atr {
+                    true_range: ExponentialMovingAverage::new(period)?,
+                    prev_close: None,
+                },
+                period,
+            ),
+            IndicatorConfig::Vwap => (
+                IndicatorKind::Vwap {
+                    price_volume: 0.0,
+                    volume: 0.0,
+                },
+                1,
+            ),
+            IndicatorConfig::ZScore { period, input } => (
+                IndicatorKind::ZScore(
+                    input,
+                    SimpleMovingAverage::new(period)?,
+                    StandardDeviation::new(period)?,
+                ),
+                period,
+            ),
+            IndicatorConfig::BookImbalance => (IndicatorKind::BookImbalance, 1),
+        };
+
+        Ok(Self {
+            kind,
+            warm_up,
+            observations: 0,
+            value: None,
+        })
+    }
+
+    /// Update the [`Indicator`] with a [`DataKind`] if it contains it's input, returning it's
+    /// latest value if it has finished warming up.
+    pub fn update(&mut self, kind: &DataKind) -> Option<IndicatorValue> {
+        if let Some(value) = self.kind.next(kind) {
+            self.observations += 1;
+            self.value = Some(value);
+        }
+
+        self.value()
+    }
+
+    /// Returns the latest value of the [`Indicator`], if it has finished warming up.
+    pub fn value(&self) -> Option<IndicatorValue> {
+        self.value.filter(|_| self.is_ready())
+    }
+
+    /// Determines if the [`Indicator`] has observed enough inputs to finish warming up.
+    pub fn is_ready(&self) -> bool {
+        self.observations >= self.warm_up
+    }
+}
+
+impl IndicatorKind {
+    /// Calculates the next value of the indicator if the [`DataKind`] contains it's input.
+    fn next(&mut self, kind: &DataKind) -> Option<IndicatorValue> {
+        let value = match self {
+            Self::Ema(input, ema) => IndicatorValue::Single(ema.next(input.extract(kind)?)),
+            Self::Sma(input, sma) => IndicatorValue::Single(sma.next(input.extract(kind)?)),
+            Self::Rsi(input, rsi) => IndicatorValue::Single(rsi.next(input.extract(kind)?)),
+            Self::Macd(input, macd) => {
+                let output = macd.next(input.extract(kind)?);
+                IndicatorValue::Macd {
+                    macd: output.macd,
+                    signal: output.signal,
+                    histogram: output.histogram,
+                }
+            }
+            Self::Bollinger(input, bands) => {
+                let output = bands.next(input.extract(kind)?);
+                IndicatorValue::Bands {
+                    upper: output.upper,
+                    middle: output.average,
+                    lower: output.lower,
+                }
+            }
+            Self::Atr {
+                true_range,
+                prev_close,
+            } => {
+                let candle = match kind {
+                    DataKind::Candle(candle) => candle,
+                    _ => return None,
+                };
+
+                let range = match prev_close.replace(candle.close) {
+                    Some(prev_close) => (candle.high - candle.low)
+                        .max((candle.high - prev_close).abs())
+                        .max((candle.low - prev_close).abs()),
+                    None => candle.high - candle.low,
+                };
+
+                IndicatorValue::Single(true_range.next(range))
+            }
+            Self::Vwap {
+                price_volume,
+                volume,
+            } => {
+                let (price, amount) = match kind {
+                    DataKind::Trade(trade) => {
+                        (decimal_to_f64(trade.price), decimal_to_f64(trade.amount))
+                    }
+                    DataKind::Candle(candle) => (
+                        (candle.high + candle.low + candle.close) / 3.0,
+                        candle.volume,
+                    ),
+                    _ => return None,
+                };
+
+                *price_volume += price * amount;
+                *volume += amount;
+                if *volume == 0.0 {
+                    return None;
+                }
+
+                IndicatorValue::Single(*price_volume / *volume)
+            }
+            Self::ZScore(input, mean, std_dev) => {
+                let input = input.extract(kind)?;
+                let mean = mean.next(input);
+                let std_dev = std_dev.next(input);
+
+                IndicatorValue::Single(if std_dev == 0.0 {
+                    0.0
+                } else {
+                    (input - mean) / std_dev
+                })
+            }
+            Self::BookImbalance => match kind {
+                DataKind::OrderBookL1(book) => IndicatorValue::Single(book_imbalance(book)),
+                _ => return None,
+            },
+        };
+
+        Some(value)
+    }
+}
+
+/// Calculate the imbalance between the best bid & ask amounts of an [`OrderBookL1`].
+fn book_imbalance(book: &OrderBookL1) -> f64 {
+    let total = book.best_bid.amount + book.best_ask.amount;
+    (book.best_bid.amount - book.best_ask.amount)
+        .checked_div(total)
+        .map(decimal_to_f64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::subscription::{book::Level, trade::PublicTrade};
+    use barter_integration::model::Side;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn trade(price: Decimal, amount: Decimal) -> DataKind {
+        DataKind::Trade(PublicTrade {
+            id: "trade_id".to_owned(),
+            price,
+            amount,
+            side: Side::Buy,
+        })
+    }
+
+    #[test]
+    fn indicator_yields_no_value_until_warmed_up() {
+        let mut sma = Indicator::new(IndicatorConfig::Sma {
+            period: 3,
+            input: Input::Price,
+        })
+        .unwrap();
+
+        assert_eq!(sma.update(&trade(dec!(1.0), dec!(1.0))), None);
+        assert_eq!(sma.update(&trade(dec!(2.0), dec!(1.0))), None);
+
+        // OrderBookL1 mid price is also a Price input
+        let book = DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: Utc::now(),
+            best_bid: Level::new(5, 3),
+            best_ask: Level::new(7, 1),
+        });
+        assert_eq!(sma.update(&book), Some(IndicatorValue::Single(3.0)));
+        assert_eq!(
+            sma.update(&trade(dec!(10.0), dec!(1.0))),
+            Some(IndicatorValue::Single(6.0))
+        );
+
+        let mut imbalance = Indicator::new(IndicatorConfig::BookImbalance).unwrap();
+        assert_eq!(imbalance.update(&trade(dec!(1.0), dec!(1.0))), None);
+        assert_eq!(imbalance.update(&book), Some(IndicatorValue::Single(0.5)));
+    }
+
+    #[test]
+    fn vwap_weights_trade_prices_by_amount() {
+        let mut vwap = Indicator::new(IndicatorConfig::Vwap).unwrap();
+
+        vwap.update(&trade(dec!(100.0), dec!(1.0)));
+        assert_eq!(
+            vwap.update(&trade(dec!(200.0), dec!(3.0))),
+            Some(IndicatorValue::Single(175.0))
+        );
+    }
+
+    #[test]
+    fn indicator_config_deserialises_with_default_input() {
+        let config: IndicatorConfig =
+            serde_json::from_str(r#"{ "indicator": "ema", "period": 10 }"#).unwrap();
+        assert_eq!(
+            config,
+            IndicatorConfig::Ema {
+                period: 10,
+                input: Input::Price
+            }
+        );
+
+        assert!(Indicator::new(IndicatorConfig::Ema {
+            period: 0,
+            input: Input::Close
+        })
+        .is_err());
+    }
+}
@@ -8,6 +8,30 @@ use std::collections::HashMap;
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// Configurable technical [`Indicator`](indicator::Indicator)s (eg/ EMA, MACD, Bollinger Bands)
+/// that take their input from any [`DataKind`].
+pub mod indicator;
+
+/// Composable [`FeaturePipeline`](feature::FeaturePipeline) of named indicators with warm-up
+/// handling, configurable via serde.
+pub mod feature;
+
+/// Hot-reloadable [`ScriptedStrategy`](script::ScriptedStrategy) that evaluates a sandboxed
+/// strategy script, enabling strategy logic to be iterated on without recompiling.
+pub mod script;
+
+/// [`Ensemble`](ensemble::Ensemble) of member strategies whose [`Signal`]s are combined by
+/// voting, weighted averaging or priority, with [`Attribution`](crate::data::Attribution) of the
+/// contributing members.
+pub mod ensemble;
+
+/// Error types generated in the barter::strategy module.
+pub mod error;
+
+/// Portfolio level [`MultiMarketSignalGenerator`](multi::MultiMarketSignalGenerator) strategies
+/// that analyse many markets & may generate [`Signal`]s for any of them.
+pub mod multi;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
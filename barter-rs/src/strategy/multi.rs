@@ -0,0 +1,180 @@
+use super::{Signal, SignalGenerator};
+use crate::event::EventTrace;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Market;
+use chrono::Utc;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Portfolio level strategy that analyses the [`MarketEvent`]s of many [`Market`]s, & may generate
+/// advisory [`Signal`]s for any of them as a result, eg/ a pairs-trading or cross-exchange
+/// arbitrage strategy that must see both legs.
+///
+/// Each [`Signal`] is routed to the [`Trader`](crate::engine::trader::Trader) of the [`Market`]
+/// it was generated for, so it's `market_meta` must describe that [`Market`] (eg/ it's latest
+/// close), rather than the [`Market`] that ticked.
+pub trait MultiMarketSignalGenerator {
+    /// Return any number of [`Signal`]s given an input [`MarketEvent`] from one of the
+    /// [`Market`]s being traded.
+    fn generate_signals(&mut self, market: &MarketEvent<DataKind>) -> Vec<Signal>;
+}
+
+/// Shares a [`MultiMarketSignalGenerator`] between the [`Trader`](crate::engine::trader::Trader)s
+/// of many [`Market`]s by implementing [`SignalGenerator`] for one of them.
+///
+/// [`Signal`]s generated for the [`Market`] of this [`Trader`](crate::engine::trader::Trader)
+/// are returned to it directly. [`Signal`]s for any other [`Market`] are sent to the
+/// [`Engine`](crate::engine::Engine) `signal_rx`, which routes them to the relevant
+/// [`Trader`](crate::engine::trader::Trader) as a [`Command::Signal`](crate::engine::Command).
+#[derive(Debug)]
+pub struct SharedStrategy<Strategy>
+where
+    Strategy: MultiMarketSignalGenerator,
+{
+    market: Market,
+    strategy: Arc<Mutex<Strategy>>,
+    signal_tx: mpsc::UnboundedSender<Signal>,
+}
+
+impl<Strategy> Clone for SharedStrategy<Strategy>
+where
+    Strategy: MultiMarketSignalGenerator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            market: self.market.clone(),
+            strategy: Arc::clone(&self.strategy),
+            signal_tx: self.signal_tx.clone(),
+        }
+    }
+}
+
+impl<Strategy> SignalGenerator for SharedStrategy<Strategy>
+where
+    Strategy: MultiMarketSignalGenerator,
+{
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        let signals = self.strategy.lock().generate_signals(market);
+
+        // Signals for every Market generated by this MarketEvent share it's EventTrace
+        let mut trace = EventTrace::from(market);
+        trace.signal_time = Some(Utc::now());
+
+        let mut own_signal: Option<Signal> = None;
+        for mut signal in signals {
+            if signal.exchange == self.market.exchange
+                && signal.instrument == self.market.instrument
+            {
+                // Combine multiple Signals for this Market into one
+                match &mut own_signal {
+                    Some(own_signal) => own_signal.signals.extend(signal.signals),
+                    None => own_signal = Some(signal),
+                }
+                continue;
+            }
+
+            signal.market_meta.trace.get_or_insert(trace);
+            if self.signal_tx.send(signal).is_err() {
+                warn!(
+                    market = ?self.market,
+                    why = "Engine signal_rx dropped",
+                    "failed to route Signal to another Market"
+                );
+            }
+        }
+
+        own_signal
+    }
+}
+
+impl<Strategy> SharedStrategy<Strategy>
+where
+    Strategy: MultiMarketSignalGenerator,
+{
+    /// Constructs a new [`SharedStrategy`] for the provided [`Market`]. Every
+    /// [`SharedStrategy`] sharing the [`MultiMarketSignalGenerator`] should use a clone of the
+    /// same `mpsc::UnboundedSender`, the receiver of which is provided to the
+    /// [`Engine`](crate::engine::Engine).
+    pub fn new(
+        market: Market,
+        strategy: Arc<Mutex<Strategy>>,
+        signal_tx: mpsc::UnboundedSender<Signal>,
+    ) -> Self {
+        Self {
+            market,
+            strategy,
+            signal_tx,
+        }
+    }
+
+    /// Returns the [`Market`] this [`SharedStrategy`] generates [`Signal`]s for.
+    pub fn market(&self) -> &Market {
+        &self.market
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        strategy::{Decision, SignalStrength},
+        test_util::{market_event_trade, signal},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use std::collections::HashMap;
+
+    /// Pairs strategy going long the ticking Market & short the other leg.
+    struct Pairs {
+        legs: [Market; 2],
+    }
+
+    impl MultiMarketSignalGenerator for Pairs {
+        fn generate_signals(&mut self, market: &MarketEvent<DataKind>) -> Vec<Signal> {
+            self.legs
+                .iter()
+                .map(|leg| {
+                    let decision = if leg.instrument == market.instrument {
+                        Decision::Long
+                    } else {
+                        Decision::Short
+                    };
+
+                    Signal {
+                        exchange: leg.exchange.clone(),
+                        instrument: leg.instrument.clone(),
+                        signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                        ..signal()
+                    }
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn shared_strategy_returns_own_signal_and_routes_other_legs() {
+        let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let pairs = Arc::new(Mutex::new(Pairs {
+            legs: [btc.clone(), eth.clone()],
+        }));
+
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let mut strategy = SharedStrategy::new(btc.clone(), pairs, signal_tx);
+
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = btc.exchange.clone();
+        market.instrument = btc.instrument.clone();
+
+        let own_signal = strategy.generate_signal(&market).unwrap();
+        assert_eq!(own_signal.instrument, btc.instrument);
+        assert!(own_signal.signals.contains_key(&Decision::Long));
+
+        let routed = signal_rx.try_recv().unwrap();
+        assert_eq!(routed.instrument, eth.instrument);
+        assert!(routed.signals.contains_key(&Decision::Short));
+        assert!(routed.market_meta.trace.unwrap().signal_time.is_some());
+        assert!(signal_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use self::parser::Script;
+use super::{
+    error::StrategyError,
+    feature::{self, FeatureConfig, FeaturePipeline},
+    indicator::Input,
+    Decision, Signal, SignalGenerator, SignalStrength,
+};
+use crate::{
+    data::{market_close, MarketMeta},
+    statistic::decimal_to_f64,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::{debug, info, warn};
+
+/// Parser & evaluator of the strategy script language.
+pub mod parser;
+
+/// Default maximum number of operations a strategy script may require to evaluate.
+pub const DEFAULT_MAX_OPERATIONS: usize = 10_000;
+
+/// Default interval in milliseconds between checks for modifications to a strategy script.
+pub const DEFAULT_RELOAD_INTERVAL_MS: u64 = 1_000;
+
+/// Script variables that are assigned to in order to build a [`Signal`] [`Decision`].
+const DECISIONS: [(&str, Decision); 4] = [
+    ("long", Decision::Long),
+    ("close_long", Decision::CloseLong),
+    ("short", Decision::Short),
+    ("close_short", Decision::CloseShort),
+];
+
+/// Configuration for constructing a [`ScriptedStrategy`] via the new() constructor method.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Path of the strategy script, which is reloaded whenever it is modified.
+    pub path: PathBuf,
+    /// Indicators computed by a [`FeaturePipeline`] & exposed to the script by feature name.
+    #[serde(default)]
+    pub features: Vec<FeatureConfig>,
+    /// Maximum number of operations the script may require to evaluate. Scripts exceeding this
+    /// limit are rejected when they are loaded.
+    #[serde(default = "default_max_operations")]
+    pub max_operations: usize,
+    /// Interval in milliseconds between checks for modifications to the script.
+    #[serde(default = "default_reload_interval_ms")]
+    pub reload_interval_ms: u64,
+}
+
+fn default_max_operations() -> usize {
+    DEFAULT_MAX_OPERATIONS
+}
+
+fn default_reload_interval_ms() -> u64 {
+    DEFAULT_RELOAD_INTERVAL_MS
+}
+
+/// [`SignalGenerator`] that evaluates a hot-reloadable strategy [`Script`] for every
+/// [`MarketEvent`], enabling strategy logic to be iterated on without recompiling the engine.
+///
+/// A script is a sequence of `<variable> = <expression>` statements, eg/
+///
+/// ```text
+/// # Long whilst the fast EMA is above the slow EMA, unless overbought
+/// trend = fast - slow
+/// long = trend > 0 && rsi < 70
+/// close_long = trend < 0
+/// ```
+///
+/// Expressions support numbers, `true` & `false`, arithmetic (`+ - * /`), comparisons
+/// (`< <= > >= == !=`), logic (`&& || !`) & the functions `abs`, `sqrt`, `min` & `max`. They may
+/// reference any variable assigned on a previous line, every configured feature (eg/
+/// `bands.upper`), & the [`MarketEvent`] fields it contains: `price`, `trade_price`, `amount`,
+/// `open`, `high`, `low`, `close`, `volume`, `mid`, `bid`, `ask`, `bid_amount` & `ask_amount`.
+///
+/// Assigning a value greater than zero to `long`, `close_long`, `short` or `close_short` adds
+/// that [`Decision`] to the generated [`Signal`] with the value as it's [`SignalStrength`]
+/// (`true` is 1.0).
+///
+/// Scripts are sandboxed: they cannot perform any IO & contain no loops, so evaluation is
+/// bounded by the [`Config`] `max_operations`, & a bad script cannot stall the
+/// [`Trader`](crate::engine::trader::Trader) loop. No [`Signal`]s are generated until every
+/// feature has warmed up, or for a [`MarketEvent`] missing a variable the script references.
+#[derive(Debug)]
+pub struct ScriptedStrategy {
+    path: PathBuf,
+    max_operations: usize,
+    reload_interval: Duration,
+    script: Script,
+    modified: Option<SystemTime>,
+    last_reload_check: Instant,
+    features: FeaturePipeline,
+    variables: HashMap<String, f64>,
+}
+
+impl SignalGenerator for ScriptedStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        self.reload_if_modified();
+
+        // Features are only available once every indicator has warmed up
+        let features = self.features.update(market)?;
+        let close = market_close(&market.kind)?;
+
+        self.variables.clear();
+        self.variables.extend(features.0);
+        self.variables.extend(market_variables(&market.kind));
+
+        if let Err(error) = self.script.evaluate(&mut self.variables) {
+            debug!(path = ?self.path, %error, "failed to evaluate strategy script");
+            return None;
+        }
+
+        let signals = DECISIONS
+            .iter()
+            .filter_map(|(variable, decision)| match self.variables.get(*variable) {
+                Some(strength) if *strength > 0.0 => Some((*decision, SignalStrength(*strength))),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        if signals.is_empty() {
+            return None;
+        }
+
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals,
+            market_meta: MarketMeta {
+                close,
+                time: market.exchange_time,
+                trace: None,
+                attribution: None,
+            },
+        })
+    }
+}
+
+impl ScriptedStrategy {
+    /// Constructs a new [`ScriptedStrategy`] using the provided configuration struct, loading
+    /// the initial strategy script.
+    pub fn new(config: Config) -> Result<Self, StrategyError> {
+        let (script, modified) = load(&config.path, config.max_operations)?;
+
+        Ok(Self {
+            features: FeaturePipeline::new(feature::Config {
+                features: config.features,
+            })?,
+            path: config.path,
+            max_operations: config.max_operations,
+            reload_interval: Duration::from_millis(config.reload_interval_ms),
+            script,
+            modified,
+            last_reload_check: Instant::now(),
+            variables: HashMap::new(),
+        })
+    }
+
+    /// Reload the strategy script if it has been modified since it was last loaded. An invalid
+    /// modification is logged & the previously loaded script continues to be used.
+    fn reload_if_modified(&mut self) {
+        if self.last_reload_check.elapsed() < self.reload_interval {
+            return;
+        }
+        self.last_reload_check = Instant::now();
+
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        if modified == self.modified {
+            return;
+        }
+
+        match load(&self.path, self.max_operations) {
+            Ok((script, modified)) => {
+                info!(path = ?self.path, "reloaded modified strategy script");
+                self.script = script;
+                self.modified = modified;
+            }
+            Err(error) => {
+                warn!(
+                    path = ?self.path,
+                    %error,
+                    action = "continuing with previously loaded script",
+                    "failed to reload modified strategy script"
+                );
+                self.modified = modified;
+            }
+        }
+    }
+}
+
+/// Read & parse the strategy [`Script`] at the provided path, returning it alongside it's
+/// modification time.
+fn load(path: &Path, max_operations: usize) -> Result<(Script, Option<SystemTime>), StrategyError> {
+    let io_error = |source| StrategyError::ScriptIo {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let modified = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    let script = Script::parse(&std::fs::read_to_string(path).map_err(io_error)?)?;
+
+    if script.operations() > max_operations {
+        return Err(StrategyError::ScriptTooExpensive {
+            operations: script.operations(),
+            max_operations,
+        });
+    }
+
+    Ok((script, modified))
+}
+
+/// Script variables for the fields contained by a [`DataKind`].
+fn market_variables(kind: &DataKind) -> Vec<(String, f64)> {
+    let inputs = [
+        ("price", Input::Price),
+        ("trade_price", Input::TradePrice),
+        ("amount", Input::TradeAmount),
+        ("open", Input::Open),
+        ("high", Input::High),
+        ("low", Input::Low),
+        ("close", Input::Close),
+        ("volume", Input::Volume),
+        ("mid", Input::MidPrice),
+    ];
+
+    let mut variables = inputs
+        .into_iter()
+        .filter_map(|(name, input)| Some((name.to_owned(), input.extract(kind)?)))
+        .collect::<Vec<_>>();
+
+    if let DataKind::OrderBookL1(book) = kind {
+        variables.extend([
+            ("bid".to_owned(), decimal_to_f64(book.best_bid.price)),
+            ("ask".to_owned(), decimal_to_f64(book.best_ask.price)),
+            (
+                "bid_amount".to_owned(),
+                decimal_to_f64(book.best_bid.amount),
+            ),
+            (
+                "ask_amount".to_owned(),
+                decimal_to_f64(book.best_ask.amount),
+            ),
+        ]);
+    }
+
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+    use rust_decimal_macros::dec;
+    use std::fs::File;
+
+    #[test]
+    fn scripted_strategy_hot_reloads_modified_script() {
+        let path = std::env::temp_dir().join(format!("{}.strategy", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "long = price > fast\nshort = 0").unwrap();
+
+        let mut strategy = ScriptedStrategy::new(Config {
+            path: path.clone(),
+            features: serde_json::from_str(
+                r#"[{ "name": "fast", "indicator": "sma", "period": 2 }]"#,
+            )
+            .unwrap(),
+            max_operations: DEFAULT_MAX_OPERATIONS,
+            reload_interval_ms: 0,
+        })
+        .unwrap();
+
+        // No Signal until the fast SMA has warmed up, & price is never above it's own SMA
+        let market = market_event_trade(Side::Buy);
+        assert_eq!(strategy.generate_signal(&market), None);
+        assert_eq!(strategy.generate_signal(&market), None);
+
+        // Modified script is reloaded
+        let touch = |source: &str, offset: u64| {
+            std::fs::write(&path, source).unwrap();
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(SystemTime::now() + Duration::from_secs(offset))
+                .unwrap();
+        };
+        touch("long = price >= fast\nclose_short = 0.5", 10);
+        let signal = strategy.generate_signal(&market).unwrap();
+        assert_eq!(signal.signals[&Decision::Long], SignalStrength(1.0));
+        assert_eq!(signal.signals[&Decision::CloseShort], SignalStrength(0.5));
+        assert_eq!(signal.market_meta.close, dec!(1000));
+
+        // Invalid modification keeps the previously loaded script
+        touch("long = price >=", 20);
+        assert!(strategy.generate_signal(&market).is_some());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn scripted_strategy_rejects_scripts_exceeding_max_operations() {
+        let path = std::env::temp_dir().join(format!("{}.strategy", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "long = 1 + 2 + 3").unwrap();
+
+        let strategy = ScriptedStrategy::new(Config {
+            path: path.clone(),
+            features: vec![],
+            max_operations: 4,
+            reload_interval_ms: DEFAULT_RELOAD_INTERVAL_MS,
+        });
+
+        assert!(matches!(
+            strategy,
+            Err(StrategyError::ScriptTooExpensive {
+                operations: 5,
+                max_operations: 4
+            })
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
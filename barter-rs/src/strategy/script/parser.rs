@@ -0,0 +1,457 @@
+use super::super::error::StrategyError;
+use std::collections::HashMap;
+
+/// Maximum nesting depth of an expression (eg/ parentheses, unary operators & function calls),
+/// which bounds the recursion of the [`Parser`].
+const MAX_EXPRESSION_DEPTH: usize = 64;
+
+/// Maximum number of [`Token`]s in one expression, which bounds the depth of the expression tree
+/// recursed when it is evaluated (eg/ a long chain of `a + a + ... + a`).
+const MAX_EXPRESSION_TOKENS: usize = 512;
+
+/// Compiled strategy script consisting of one `<target> = <expression>` statement per line.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Script {
+    statements: Vec<Statement>,
+    operations: usize,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Statement {
+    target: String,
+    expr: Expr,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Expr {
+    Number(f64),
+    Variable(String),
+    Not(Box<Expr>),
+    Negate(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Function {
+    Abs,
+    Sqrt,
+    Min,
+    Max,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Script {
+    /// Parse the source of a strategy script, eg/
+    ///
+    /// ```text
+    /// # Comments start with '#'
+    /// trend = fast - slow
+    /// long = trend > 0 && rsi < 70
+    /// close_long = trend < 0
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, StrategyError> {
+        let statements = source
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let line = line.split('#').next().unwrap_or_default().trim();
+                (!line.is_empty()).then_some((index + 1, line))
+            })
+            .map(|(line_number, line)| {
+                parse_statement(line).map_err(|reason| StrategyError::ScriptParse {
+                    line: line_number,
+                    reason,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let operations = statements
+            .iter()
+            .map(|statement| statement.expr.operations())
+            .sum();
+
+        Ok(Self {
+            statements,
+            operations,
+        })
+    }
+
+    /// Number of operations required to evaluate every statement of the [`Script`].
+    pub fn operations(&self) -> usize {
+        self.operations
+    }
+
+    /// Evaluate every statement in order, assigning each result to it's target in the provided
+    /// variables. Booleans are represented as 1.0 (true) & 0.0 (false).
+    pub fn evaluate(&self, variables: &mut HashMap<String, f64>) -> Result<(), String> {
+        for statement in &self.statements {
+            let value = statement.expr.evaluate(variables)?;
+            variables.insert(statement.target.clone(), value);
+        }
+        Ok(())
+    }
+}
+
+impl Expr {
+    fn operations(&self) -> usize {
+        match self {
+            Self::Number(_) | Self::Variable(_) => 1,
+            Self::Not(expr) | Self::Negate(expr) => 1 + expr.operations(),
+            Self::Binary(_, lhs, rhs) => 1 + lhs.operations() + rhs.operations(),
+            Self::Call(_, args) => 1 + args.iter().map(Expr::operations).sum::<usize>(),
+        }
+    }
+
+    fn evaluate(&self, variables: &HashMap<String, f64>) -> Result<f64, String> {
+        let value = match self {
+            Self::Number(value) => *value,
+            Self::Variable(name) => *variables
+                .get(name)
+                .ok_or_else(|| format!("variable {name} is not available"))?,
+            Self::Not(expr) => bool_value(!truthy(expr.evaluate(variables)?)),
+            Self::Negate(expr) => -expr.evaluate(variables)?,
+            Self::Binary(BinaryOp::And, lhs, rhs) => {
+                bool_value(truthy(lhs.evaluate(variables)?) && truthy(rhs.evaluate(variables)?))
+            }
+            Self::Binary(BinaryOp::Or, lhs, rhs) => {
+                bool_value(truthy(lhs.evaluate(variables)?) || truthy(rhs.evaluate(variables)?))
+            }
+            Self::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate(variables)?, rhs.evaluate(variables)?);
+                match op {
+                    BinaryOp::Eq => bool_value(lhs == rhs),
+                    BinaryOp::NotEq => bool_value(lhs != rhs),
+                    BinaryOp::Lt => bool_value(lhs < rhs),
+                    BinaryOp::LtEq => bool_value(lhs <= rhs),
+                    BinaryOp::Gt => bool_value(lhs > rhs),
+                    BinaryOp::GtEq => bool_value(lhs >= rhs),
+                    BinaryOp::Add => lhs + rhs,
+                    BinaryOp::Sub => lhs - rhs,
+                    BinaryOp::Mul => lhs * rhs,
+                    BinaryOp::Div => lhs / rhs,
+                    BinaryOp::And | BinaryOp::Or => unreachable!("short-circuited above"),
+                }
+            }
+            Self::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.evaluate(variables))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match function {
+                    Function::Abs => args[0].abs(),
+                    Function::Sqrt => args[0].sqrt(),
+                    Function::Min => args[0].min(args[1]),
+                    Function::Max => args[0].max(args[1]),
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn truthy(value: f64) -> bool {
+    value != 0.0 && !value.is_nan()
+}
+
+fn bool_value(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn parse_statement(line: &str) -> Result<Statement, String> {
+    let (target, expr) = line
+        .split_once('=')
+        .filter(|(_, expr)| !expr.starts_with('='))
+        .ok_or_else(|| "expected '<target> = <expression>'".to_owned())?;
+
+    let target = target.trim();
+    if !is_identifier(target) {
+        return Err(format!("invalid assignment target '{target}'"));
+    }
+
+    let tokens = tokenise(expr)?;
+    if tokens.len() > MAX_EXPRESSION_TOKENS {
+        return Err(format!(
+            "expression exceeds the maximum of {MAX_EXPRESSION_TOKENS} tokens"
+        ));
+    }
+
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let expr = parser.expression(0)?;
+    match parser.tokens.get(parser.position) {
+        None => Ok(Statement {
+            target: target.to_owned(),
+            expr,
+        }),
+        Some(token) => Err(format!("unexpected token {token:?}")),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '.')
+}
+
+fn tokenise(expr: &str) -> Result<Vec<Token>, String> {
+    const OPERATORS: [&str; 14] = [
+        "&&", "||", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "!", "=",
+    ];
+
+    let mut tokens = Vec::new();
+    let mut rest = expr.trim_start();
+    while let Some(char) = rest.chars().next() {
+        let length = if char.is_ascii_digit() {
+            let length = rest
+                .find(|char: char| !char.is_ascii_digit() && char != '.')
+                .unwrap_or(rest.len());
+            let number = rest[..length]
+                .parse()
+                .map_err(|_| format!("invalid number '{}'", &rest[..length]))?;
+            tokens.push(Token::Number(number));
+            length
+        } else if char.is_ascii_alphabetic() || char == '_' {
+            let length = rest
+                .find(|char: char| !char.is_ascii_alphanumeric() && char != '_' && char != '.')
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..length].to_owned()));
+            length
+        } else if char == '(' {
+            tokens.push(Token::LParen);
+            1
+        } else if char == ')' {
+            tokens.push(Token::RParen);
+            1
+        } else if char == ',' {
+            tokens.push(Token::Comma);
+            1
+        } else {
+            let operator = OPERATORS
+                .iter()
+                .find(|operator| rest.starts_with(*operator))
+                .ok_or_else(|| format!("unexpected character '{char}'"))?;
+            if *operator == "=" {
+                return Err("unexpected '=', use '==' for equality".to_owned());
+            }
+            tokens.push(Token::Op(operator));
+            operator.len()
+        };
+
+        rest = rest[length..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+/// Pratt parser of an expression [`Token`] stream.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// Current nesting depth of [`Parser::expression`] calls.
+    depth: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, String> {
+        if self.depth >= MAX_EXPRESSION_DEPTH {
+            return Err(format!(
+                "expression exceeds the maximum nesting depth of {MAX_EXPRESSION_DEPTH}"
+            ));
+        }
+
+        self.depth += 1;
+        let expr = self.binary(min_precedence);
+        self.depth -= 1;
+        expr
+    }
+
+    fn binary(&mut self, min_precedence: u8) -> Result<Expr, String> {
+        let mut lhs = self.prefix()?;
+
+        while let Some(Token::Op(operator)) = self.peek() {
+            let (op, precedence) = binary_op(operator)?;
+            if precedence <= min_precedence {
+                break;
+            }
+            self.position += 1;
+
+            let rhs = self.expression(precedence)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn prefix(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(Expr::Number(value)),
+            Some(Token::Ident(name)) if self.peek() == Some(&Token::LParen) => self.call(&name),
+            Some(Token::Ident(name)) => Ok(match name.as_str() {
+                "true" => Expr::Number(1.0),
+                "false" => Expr::Number(0.0),
+                _ => Expr::Variable(name),
+            }),
+            Some(Token::Op("!")) => Ok(Expr::Not(Box::new(self.expression(UNARY_PRECEDENCE)?))),
+            Some(Token::Op("-")) => Ok(Expr::Negate(Box::new(self.expression(UNARY_PRECEDENCE)?))),
+            Some(Token::LParen) => {
+                let expr = self.expression(0)?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err("expected ')'".to_owned()),
+                }
+            }
+            Some(token) => Err(format!("unexpected token {token:?}")),
+            None => Err("unexpected end of expression".to_owned()),
+        }
+    }
+
+    fn call(&mut self, name: &str) -> Result<Expr, String> {
+        let (function, arity) = match name {
+            "abs" => (Function::Abs, 1),
+            "sqrt" => (Function::Sqrt, 1),
+            "min" => (Function::Min, 2),
+            "max" => (Function::Max, 2),
+            _ => return Err(format!("unknown function '{name}'")),
+        };
+
+        // Consume '('
+        self.position += 1;
+
+        let mut args = Vec::with_capacity(arity);
+        if self.peek() != Some(&Token::RParen) {
+            loop {
+                args.push(self.expression(0)?);
+                match self.next() {
+                    Some(Token::Comma) => continue,
+                    Some(Token::RParen) => break,
+                    _ => return Err(format!("expected ',' or ')' in call to {name}")),
+                }
+            }
+        } else {
+            self.position += 1;
+        }
+
+        if args.len() != arity {
+            return Err(format!("{name} expects {arity} argument(s)"));
+        }
+
+        Ok(Expr::Call(function, args))
+    }
+}
+
+const UNARY_PRECEDENCE: u8 = 7;
+
+fn binary_op(operator: &str) -> Result<(BinaryOp, u8), String> {
+    let op = match operator {
+        "||" => (BinaryOp::Or, 1),
+        "&&" => (BinaryOp::And, 2),
+        "==" => (BinaryOp::Eq, 3),
+        "!=" => (BinaryOp::NotEq, 3),
+        "<" => (BinaryOp::Lt, 4),
+        "<=" => (BinaryOp::LtEq, 4),
+        ">" => (BinaryOp::Gt, 4),
+        ">=" => (BinaryOp::GtEq, 4),
+        "+" => (BinaryOp::Add, 5),
+        "-" => (BinaryOp::Sub, 5),
+        "*" => (BinaryOp::Mul, 6),
+        "/" => (BinaryOp::Div, 6),
+        _ => return Err(format!("unexpected operator '{operator}'")),
+    };
+    Ok(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_evaluates_statements_in_order_with_precedence() {
+        let script = Script::parse(
+            "# Trend following
+            trend = fast - slow   # may be negative
+            long = trend > 0 && !(rsi >= 70) || max(rsi, 10) == 5
+            size = 2 + 3 * -abs(trend) / 2",
+        )
+        .unwrap();
+
+        let mut variables = HashMap::from([
+            ("fast".to_owned(), 12.0),
+            ("slow".to_owned(), 10.0),
+            ("rsi".to_owned(), 50.0),
+        ]);
+        script.evaluate(&mut variables).unwrap();
+
+        assert_eq!(variables["trend"], 2.0);
+        assert_eq!(variables["long"], 1.0);
+        assert_eq!(variables["size"], -1.0);
+
+        variables.remove("rsi");
+        assert!(script.evaluate(&mut variables).is_err());
+    }
+
+    #[test]
+    fn script_parse_reports_line_of_invalid_statement() {
+        for (source, expected_line) in [
+            ("long = 1 +", 1),
+            ("\n# comment\nlong = rsi = 2", 3),
+            ("long = unknown(1)", 1),
+            ("long = min(1)", 1),
+            ("1long = 1", 1),
+            ("long = (1 + 2", 1),
+            (&format!("long = {}1", "-(".repeat(100_000)), 1),
+            (&format!("long = {}1", "1 + ".repeat(1_000)), 1),
+        ] {
+            match Script::parse(source) {
+                Err(StrategyError::ScriptParse { line, .. }) => {
+                    assert_eq!(line, expected_line, "{source:.50}")
+                }
+                other => panic!("{source:.50} parsed unexpectedly: {other:?}"),
+            }
+        }
+    }
+}
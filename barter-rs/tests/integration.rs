@@ -1,7 +1,13 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    data::{historical, live, MarketMeta},
+    engine::{
+        error::EngineError,
+        http::HttpApi,
+        policy::{ErrorAction, ErrorPolicy, StagePolicy, TraderStage},
+        trader::Trader,
+        Command, Engine, TraderFactory,
+    },
+    event::{Event, EventBroadcastTx, EventTrace, EventTx, LatencyStage},
     execution::{
         simulated::{Config as ExecutionConfig, SimulatedExecution},
         Fees,
@@ -11,16 +17,27 @@ use barter::{
         repository::in_memory::InMemoryRepository, risk::DefaultRisk,
     },
     statistic::summary::{
+        benchmark::{Benchmark, Config as BenchmarkConfig},
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
-    test_util::market_event_trade,
+    strategy::{
+        example::{Config as StrategyConfig, RSIStrategy},
+        multi::{MultiMarketSignalGenerator, SharedStrategy},
+        Decision, Signal, SignalGenerator, SignalStrength,
+    },
+    test_util::{fill_event, market_event_trade},
 };
-use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::{
+    metric::prometheus::PrometheusExporter,
+    model::{instrument::kind::InstrumentKind, Market, Side},
+};
+use chrono::Utc;
 use parking_lot::Mutex;
+use rust_decimal_macros::dec;
 use std::{collections::HashMap, sync::Arc, time::Duration};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -43,10 +60,10 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(dec!(10_000.0))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100.0),
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -77,9 +94,9 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: dec!(0.1),
+                    slippage: dec!(0.05),
+                    network: dec!(0.0),
                 },
             }))
             .build()
@@ -101,6 +118,7 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
             trading_days_per_year: 365,
             risk_free_return: 0.0,
         }))
+        .attribution(vec!["rsi".to_owned()])
         .build()
         .expect("failed to build engine");
 
@@ -111,8 +129,1069 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
     let engine_run_future = engine.run();
     let actual = tokio::time::timeout(timeout, engine_run_future).await;
 
+    let report = actual
+        .expect("failed because Engine's command_rx.await is blocking the Engine from stopping");
+
+    // Ensemble member attribution is included in the SessionReport
+    let attribution = report
+        .attribution
+        .expect("SessionReport has no attribution");
+    assert!(attribution.statistics("rsi").is_some());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn engine_run_async_actions_fills_and_stops_after_market_stream_finished() {
+    // Create channel to distribute Commands to the Engine & it's Traders (eg/ Command::Terminate)
+    let (_command_tx, command_rx) = mpsc::channel(20);
+
+    // Create Event channel to listen to all Engine Events in real-time
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+    let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Live MarketEvent stream that finishes after one MarketEvent
+    let (market_tx, market_rx) = mpsc::unbounded_channel();
+    market_tx.send(market_event_trade(Side::Buy)).unwrap();
+    drop(market_tx);
+
+    // Asynchronous execution response
+    let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+    fill_tx.send(fill_event()).unwrap();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .fill_rx(fill_rx)
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .benchmark(BenchmarkConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+            benchmark: Benchmark::BuyAndHold,
+        })
+        .build()
+        .expect("failed to build engine");
+
+    let actual = tokio::time::timeout(Duration::from_millis(100), engine.run_async()).await;
     assert!(
         actual.is_ok(),
-        "failed because Engine's command_rx.await is blocking the Engine from stopping"
-    )
+        "Engine::run_async did not stop after the market stream finished"
+    );
+
+    // SessionReport is returned to the caller
+    let report = actual.unwrap();
+    assert_eq!(report.engine_id, engine_id);
+    assert!(report.markets.contains_key("binance_eth_usdt_spot"));
+    assert!(report.exited_positions.is_empty());
+    assert_eq!(report.benchmark.map(|benchmark| benchmark.days), Some(1));
+
+    let mut events = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        events.push(event);
+    }
+
+    assert!(matches!(events[0], Event::Fill(_)));
+    assert!(matches!(events[1], Event::PositionNew(_)));
+    assert!(matches!(events[2], Event::Balance(_)));
+    assert!(matches!(events[3], Event::Market(_)));
+}
+
+#[tokio::test]
+async fn engine_halts_when_trader_stage_fails_with_halt_engine_policy() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Live MarketEvent stream that never finishes
+    let (_market_tx, market_rx) = mpsc::unbounded_channel();
+
+    // Exit fill for a Position that does not exist, so the Portfolio fails to update from it
+    let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+    let mut exit_fill = fill_event();
+    exit_fill.decision = Decision::CloseLong;
+    exit_fill.quantity = dec!(-1.0);
+    fill_tx.send(exit_fill).unwrap();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .fill_rx(fill_rx)
+        .error_policy(ErrorPolicy::uniform(StagePolicy::with_retries(
+            1,
+            ErrorAction::HaltEngine,
+        )))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    let actual = tokio::time::timeout(Duration::from_millis(100), engine.run_async()).await;
+    assert!(
+        actual.is_ok(),
+        "Engine::run_async did not halt after the Trader stage failed"
+    );
+
+    let mut engine_errors = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        if let Event::EngineError(engine_error) = event {
+            engine_errors.push(engine_error);
+        }
+    }
+
+    let actions = engine_errors
+        .iter()
+        .map(|engine_error| {
+            (
+                engine_error.stage,
+                engine_error.attempt,
+                engine_error.action,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        vec![
+            (TraderStage::UpdateFromFill, 1, ErrorAction::Retry),
+            (TraderStage::UpdateFromFill, 2, ErrorAction::HaltEngine),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn trader_actions_terminate_command_whilst_backing_off_a_failed_stage() {
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Live MarketEvent stream that never finishes
+    let (_market_tx, market_rx) = mpsc::unbounded_channel();
+
+    // Exit fill for a Position that does not exist, so the Portfolio fails to update from it
+    let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+    let mut exit_fill = fill_event();
+    exit_fill.decision = Decision::CloseLong;
+    exit_fill.quantity = dec!(-1.0);
+    fill_tx.send(exit_fill).unwrap();
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Retry failed stages after a backoff far longer than the test timeout
+    let trader = Trader::<_, TradingSummary, _, _, _, _>::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio)
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .fill_rx(fill_rx)
+        .error_policy(ErrorPolicy::uniform(StagePolicy {
+            max_retries: 10,
+            on_failure: ErrorAction::SkipEvent,
+            retry_backoff: Duration::from_secs(60),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    // Runs on the same current-thread runtime as the test, so a blocking backoff stalls both
+    let trader = tokio::spawn(trader.run_async());
+
+    // Wait until the Trader is backing off the failed UpdateFromFill stage
+    let engine_error = tokio::time::timeout(Duration::from_secs(1), async {
+        loop {
+            if let Some(Event::EngineError(engine_error)) = event_rx.recv().await {
+                break engine_error;
+            }
+        }
+    })
+    .await
+    .expect("Trader stage did not fail");
+    assert_eq!(engine_error.stage, TraderStage::UpdateFromFill);
+    assert_eq!(engine_error.action, ErrorAction::Retry);
+
+    trader_command_tx
+        .send(Command::Terminate("test".to_owned()))
+        .await
+        .unwrap();
+
+    let actual = tokio::time::timeout(Duration::from_millis(100), trader).await;
+    assert!(
+        actual.is_ok(),
+        "Trader did not action Command::Terminate whilst backing off"
+    );
+}
+
+#[tokio::test]
+async fn engine_actions_commands_whilst_waiting_for_a_removed_trader_to_stop() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Live MarketEvent stream that blocks the Trader thread until the next MarketEvent is sent
+    let (market_tx, market_rx) = mpsc::unbounded_channel();
+
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Trader runs on an OS thread, so it only actions Commands between MarketEvents
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market.clone(), trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    let engine = tokio::spawn(engine.run());
+
+    let (remove_tx, remove_rx) = oneshot::channel();
+    command_tx
+        .send(Command::RemoveMarket(market.clone(), remove_tx))
+        .await
+        .unwrap();
+
+    // Engine actions other Commands whilst the removed Trader is still blocked
+    let (balance_tx, balance_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchBalance(balance_tx))
+        .await
+        .unwrap();
+    let balance = tokio::time::timeout(Duration::from_millis(100), balance_rx)
+        .await
+        .expect("Engine did not action Command::FetchBalance whilst removing a Market");
+    assert!(balance.unwrap().is_ok());
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::RemoveMarket(market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        Err(EngineError::MarketRemovalPending(_))
+    ));
+
+    // Next MarketEvent unblocks the Trader, which then actions the queued Commands & stops
+    market_tx.send(market_event_trade(Side::Buy)).unwrap();
+
+    let removed = tokio::time::timeout(Duration::from_secs(2), remove_rx)
+        .await
+        .expect("Engine did not reply once the removed Trader stopped");
+    assert!(removed.unwrap().is_ok());
+
+    // Removing the last Market does not stop the Engine
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::PauseMarket(market, reply_tx))
+        .await
+        .unwrap();
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        Err(EngineError::MarketNotFound(_))
+    ));
+
+    drop(command_tx);
+    let actual = tokio::time::timeout(Duration::from_millis(100), engine).await;
+    assert!(
+        actual.is_ok(),
+        "Engine did not stop after command_tx dropped"
+    );
+}
+
+#[tokio::test]
+async fn engine_actions_runtime_commands_with_oneshot_replies() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+
+    let eth_market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+    let btc_market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![eth_market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Live MarketEvent stream that stays open until the end of the test
+    let (_market_tx, market_rx) = mpsc::unbounded_channel();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(eth_market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx.clone())
+        .portfolio(Arc::clone(&portfolio))
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    // Constructs Traders for Markets added at runtime, with MarketEvent streams that stay open
+    let factory_portfolio = Arc::clone(&portfolio);
+    let factory_market_txs = Arc::new(Mutex::new(Vec::new()));
+    let trader_factory = TraderFactory::new(move |market, command_rx| {
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        factory_market_txs.lock().push(market_tx);
+
+        Trader::builder()
+            .engine_id(engine_id)
+            .market(market)
+            .command_rx(command_rx)
+            .event_tx(event_tx.clone())
+            .portfolio(Arc::clone(&factory_portfolio))
+            .data(live::MarketFeed::new(market_rx))
+            .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees::default(),
+            }))
+            .build()
+    });
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(
+            eth_market.clone(),
+            trader_command_tx,
+        )]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .trader_factory(trader_factory)
+        .build()
+        .expect("failed to build engine");
+
+    let engine = tokio::spawn(engine.run_async());
+
+    let (balance_tx, balance_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchBalance(balance_tx))
+        .await
+        .unwrap();
+    assert_eq!(balance_rx.await.unwrap().unwrap().total, dec!(10_000.0));
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::PauseMarket(eth_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(reply_rx.await.unwrap().is_ok());
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::ResumeMarket(eth_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(reply_rx.await.unwrap().is_ok());
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::PauseMarket(btc_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        Err(EngineError::MarketNotFound(_))
+    ));
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::ConfigureAllocator(
+            serde_json::json!({ "default_order_value": 250.0 }),
+            reply_tx,
+        ))
+        .await
+        .unwrap();
+    assert!(reply_rx.await.unwrap().is_ok());
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::AddMarket(btc_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(reply_rx.await.unwrap().is_ok());
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::AddMarket(btc_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        Err(EngineError::MarketAlreadyTraded(_))
+    ));
+
+    let (statistics_tx, statistics_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchStatistics(statistics_tx))
+        .await
+        .unwrap();
+    let statistics = statistics_rx.await.unwrap().unwrap();
+    assert!(statistics.contains_key(&eth_market));
+    assert!(statistics.contains_key(&btc_market));
+
+    // Removing every Market replies once each Trader has stopped, & the Engine keeps running
+    for market in [&eth_market, &btc_market] {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        command_tx
+            .send(Command::RemoveMarket(market.clone(), reply_tx))
+            .await
+            .unwrap();
+        assert!(reply_rx.await.unwrap().is_ok());
+    }
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    command_tx
+        .send(Command::PauseMarket(eth_market.clone(), reply_tx))
+        .await
+        .unwrap();
+    assert!(matches!(
+        reply_rx.await.unwrap(),
+        Err(EngineError::MarketNotFound(_))
+    ));
+
+    let (balance_tx, balance_rx) = oneshot::channel();
+    command_tx
+        .send(Command::FetchBalance(balance_tx))
+        .await
+        .unwrap();
+    assert!(balance_rx.await.unwrap().is_ok());
+
+    // Dropping the Command transmitter stops the Engine
+    drop(command_tx);
+    let report = tokio::time::timeout(Duration::from_millis(100), engine)
+        .await
+        .expect("Engine did not stop after command_tx dropped")
+        .unwrap();
+
+    // Removed Markets remain in the trading session summary
+    assert_eq!(report.markets.len(), 2);
+}
+
+#[tokio::test]
+async fn http_api_actions_commands_and_streams_events() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+
+    // Broadcast Events to the HttpApi event stream
+    let event_tx = EventBroadcastTx::new(100);
+
+    // Aggregate Metrics for the HttpApi to serve to Prometheus
+    let (metric_tx, metric_rx) = mpsc::unbounded_channel();
+    let exporter = PrometheusExporter::new();
+    tokio::spawn(exporter.clone().consume(metric_rx));
+
+    let engine_id = Uuid::new_v4();
+
+    let btc_market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let eth_market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![btc_market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let (market_tx, market_rx) = mpsc::unbounded_channel();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(btc_market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx.clone())
+        .metric_tx(metric_tx.clone())
+        .portfolio(Arc::clone(&portfolio))
+        .data(live::MarketFeed::new(market_rx))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(
+            btc_market.clone(),
+            trader_command_tx,
+        )]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .metric_tx(metric_tx)
+        .metric_interval(Duration::from_millis(10))
+        .build()
+        .expect("failed to build engine");
+
+    let engine = tokio::spawn(engine.run_async());
+
+    // Serve the HttpApi on an OS assigned port
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(
+        HttpApi::new(command_tx, event_tx)
+            .metrics(exporter)
+            .serve_listener(listener),
+    );
+
+    let client = reqwest::Client::new();
+
+    let balance: serde_json::Value = client
+        .get(format!("{base_url}/balance"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    // Decimal money values are serialised as strings to preserve their precision
+    assert_eq!(balance["total"], "10000.0");
+
+    let positions: serde_json::Value = client
+        .get(format!("{base_url}/positions"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(positions, serde_json::json!([]));
+
+    let response = client
+        .post(format!("{base_url}/markets/pause"))
+        .json(&btc_market)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let response = client
+        .post(format!("{base_url}/markets/resume"))
+        .json(&eth_market)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    let error: serde_json::Value = response.json().await.unwrap();
+    assert!(error["error"].is_string());
+
+    let response = client
+        .put(format!("{base_url}/config/allocator"))
+        .json(&serde_json::json!({ "unknown": true }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let statistics: serde_json::Value = client
+        .get(format!("{base_url}/statistics"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(statistics.get("binance_btc_usdt_spot").is_some());
+
+    // Subscribe to the Event stream, then feed the Trader a MarketEvent
+    let mut events = client
+        .get(format!("{base_url}/events"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        events.headers()[reqwest::header::CONTENT_TYPE],
+        "text/event-stream"
+    );
+    market_tx.send(market_event_trade(Side::Buy)).unwrap();
+
+    let market_event = tokio::time::timeout(Duration::from_secs(1), async {
+        loop {
+            let chunk = events.chunk().await.unwrap().unwrap();
+            let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+            if let Some(data) = chunk.strip_prefix("data:") {
+                break serde_json::from_str::<serde_json::Value>(data.trim()).unwrap();
+            }
+        }
+    })
+    .await
+    .expect("no Event streamed");
+    assert!(market_event.get("Market").is_some());
+
+    // Trader & Portfolio Metrics are exposed for Prometheus to scrape
+    let metrics = tokio::time::timeout(Duration::from_secs(1), async {
+        loop {
+            let metrics = client
+                .get(format!("{base_url}/metrics"))
+                .send()
+                .await
+                .unwrap()
+                .text()
+                .await
+                .unwrap();
+            if metrics.contains("barter_trader_event_q_depth") {
+                break metrics;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("no Trader Metrics exported");
+    assert!(metrics.contains("# TYPE barter_trader_orders_total counter"));
+    assert!(metrics.contains(&format!(
+        "barter_portfolio_equity{{engine_id=\"{engine_id}\"}} 10000"
+    )));
+
+    let response = client
+        .post(format!("{base_url}/terminate"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::ACCEPTED);
+
+    let actual = tokio::time::timeout(Duration::from_secs(3), engine).await;
+    assert!(actual.is_ok(), "Engine did not stop after POST /terminate");
+}
+
+/// Strategy that advises going long on every [`MarketEvent`].
+struct AlwaysLong;
+
+impl SignalGenerator for AlwaysLong {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta: MarketMeta {
+                close: dec!(1000.0),
+                time: market.exchange_time,
+                trace: None,
+                attribution: None,
+            },
+        })
+    }
+}
+
+#[test]
+fn trader_traces_event_chain_from_market_event_to_fill() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let market_event = market_event_trade(Side::Buy);
+
+    Trader::<_, TradingSummary, _, _, _, _>::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio)
+        .data(historical::MarketFeed::new([market_event.clone()]))
+        .strategy(AlwaysLong)
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader")
+        .run();
+
+    let mut traces = vec![];
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Signal(signal) => traces.push(signal.market_meta.trace.unwrap()),
+            Event::OrderNew(order) => traces.push(order.market_meta.trace.unwrap()),
+            Event::Fill(fill) => traces.push(fill.market_meta.trace.unwrap()),
+            _ => {}
+        }
+    }
+
+    // Signal, OrderEvent & FillEvent share the MarketEvent's correlation id & timestamps
+    let [signal, order, fill] = <[EventTrace; 3]>::try_from(traces).unwrap();
+    assert_eq!(signal.correlation_id, order.correlation_id);
+    assert_eq!(order.correlation_id, fill.correlation_id);
+    assert_eq!(fill.exchange_time, market_event.exchange_time);
+    assert_eq!(fill.received_time, market_event.received_time);
+
+    // Each stage is stamped as it completes
+    assert!(signal.order_time.is_none());
+    assert!(order.fill_time.is_none());
+    assert!(fill.signal_time <= fill.order_time && fill.order_time <= fill.fill_time);
+    for stage in LatencyStage::ALL {
+        assert!(fill.latency(stage).is_some(), "{stage} latency missing");
+    }
+}
+
+/// Pairs trading strategy going long the Market that ticked & short the other leg.
+struct Pairs {
+    legs: [Market; 2],
+}
+
+impl MultiMarketSignalGenerator for Pairs {
+    fn generate_signals(&mut self, market: &MarketEvent<DataKind>) -> Vec<Signal> {
+        let close = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            _ => return vec![],
+        };
+
+        self.legs
+            .iter()
+            .map(|leg| {
+                let decision = if leg.instrument == market.instrument {
+                    Decision::Long
+                } else {
+                    Decision::Short
+                };
+
+                Signal {
+                    time: Utc::now(),
+                    exchange: leg.exchange.clone(),
+                    instrument: leg.instrument.clone(),
+                    signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                    market_meta: MarketMeta {
+                        close,
+                        time: market.exchange_time,
+                        trace: None,
+                        attribution: None,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn engine_routes_multi_market_signals_to_the_trader_of_each_leg() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    let engine_id = Uuid::new_v4();
+    let btc = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+    let eth = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![btc.clone(), eth.clone()])
+            .starting_cash(dec!(10_000.0))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100.0),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Both Traders share one portfolio level strategy
+    let pairs = Arc::new(Mutex::new(Pairs {
+        legs: [btc.clone(), eth.clone()],
+    }));
+    let (signal_tx, signal_rx) = mpsc::unbounded_channel();
+
+    // Only the btc MarketEvent stream ticks, eth remains open & idle
+    let (btc_market_tx, btc_market_rx) = mpsc::unbounded_channel();
+    let (_eth_market_tx, eth_market_rx) = mpsc::unbounded_channel();
+    let mut btc_market = market_event_trade(Side::Buy);
+    btc_market.instrument = btc.instrument.clone();
+    btc_market_tx.send(btc_market).unwrap();
+
+    let mut traders = Vec::new();
+    let mut trader_command_txs = HashMap::new();
+    for (market, market_rx) in [(btc.clone(), btc_market_rx), (eth.clone(), eth_market_rx)] {
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        trader_command_txs.insert(market.clone(), trader_command_tx);
+
+        traders.push(
+            Trader::builder()
+                .engine_id(engine_id)
+                .market(market.clone())
+                .command_rx(trader_command_rx)
+                .event_tx(event_tx.clone())
+                .portfolio(Arc::clone(&portfolio))
+                .data(live::MarketFeed::new(market_rx))
+                .strategy(SharedStrategy::new(
+                    market,
+                    Arc::clone(&pairs),
+                    signal_tx.clone(),
+                ))
+                .execution(SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees::default(),
+                }))
+                .build()
+                .expect("failed to build trader"),
+        );
+    }
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(traders)
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 10_000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .signal_rx(signal_rx)
+        .build()
+        .expect("failed to build engine");
+    let engine = tokio::spawn(engine.run_async());
+
+    // Wait for an OrderEvent for each leg, the eth leg generated by the eth Trader
+    let mut orders = HashMap::new();
+    while orders.len() < 2 {
+        let event = tokio::time::timeout(Duration::from_secs(1), event_rx.recv())
+            .await
+            .expect("timed out waiting for an OrderEvent for each leg")
+            .unwrap();
+
+        if let Event::OrderNew(order) = event {
+            orders.insert(order.instrument.clone(), order);
+        }
+    }
+
+    assert_eq!(orders[&btc.instrument].decision, Decision::Long);
+    assert_eq!(orders[&eth.instrument].decision, Decision::Short);
+    assert!(orders[&eth.instrument].market_meta.trace.is_some());
+
+    command_tx
+        .send(Command::Terminate("test finished".to_owned()))
+        .await
+        .unwrap();
+    // Engine exits open Positions for a second before terminating it's Traders
+    tokio::time::timeout(Duration::from_secs(5), engine)
+        .await
+        .expect("Engine did not stop after Command::Terminate")
+        .unwrap();
 }